@@ -277,34 +277,82 @@ fn make_core_code(central_items: &CentralItems) -> TokenStream {
         ..
     } = central_items;
 
+    // Signal is not yet convertible from Variant, so its enumerator holds the variant itself.
+    let dispatch_payload_tys: Vec<TokenStream> = variant_ty_enumerators_pascal
+        .iter()
+        .zip(variant_ty_enumerators_rust)
+        .map(|(pascal_name, rust_ty)| {
+            if pascal_name == "Signal" {
+                quote! { Variant }
+            } else {
+                rust_ty.clone()
+            }
+        })
+        .collect();
+
     // TODO impl Clone, Debug, PartialEq, PartialOrd, Hash for VariantDispatch
-    // TODO could use try_to().unwrap_unchecked(), since type is already verified. Also directly overload from_variant().
-    // But this requires that all the variant types support this
+    // TODO could use try_to().unwrap_unchecked(), since type is already verified.
     quote! {
         use crate::builtin::*;
         use crate::engine::Object;
         use crate::obj::Gd;
 
-        #[allow(dead_code)]
+        /// Value of a [`Variant`], converted to the Rust type corresponding to its [`VariantType`].
+        ///
+        /// There is exactly one enumerator per `VariantType`. A `match` without `_` arm is thus checked for exhaustiveness:
+        /// if a future Godot version adds a variant type, such code fails to compile instead of silently skipping the new type.
+        /// For matching only a few types, [`variant_match!`][crate::builtin::variant_match] is more concise.
+        ///
+        /// `Signal` is not yet supported as a Rust type, so its enumerator holds the original `Variant`. A variant holding a
+        /// null object pointer is dispatched as `Nil`, like in [`Variant::get_type()`].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use godot::prelude::*;
+        ///
+        /// fn type_tag(value: &Variant) -> u8 {
+        ///     match VariantDispatch::from_variant(value) {
+        ///         VariantDispatch::Nil => 0,
+        ///         VariantDispatch::Bool(_) => 1,
+        ///         VariantDispatch::Int(_) => 2,
+        ///         // ... one arm for every other type.
+        ///         # _ => unimplemented!(),
+        ///     }
+        /// }
+        /// ```
         pub enum VariantDispatch {
             Nil,
             #(
-                #variant_ty_enumerators_pascal(#variant_ty_enumerators_rust),
+                #variant_ty_enumerators_pascal(#dispatch_payload_tys),
             )*
         }
 
-        #[cfg(FALSE)]
-        impl FromVariant for VariantDispatch {
-            fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
-                let dispatch = match variant.get_type() {
+        impl VariantDispatch {
+            /// Converts `variant` to the enumerator of its type.
+            pub fn from_variant(variant: &Variant) -> Self {
+                match variant.get_type() {
                     VariantType::Nil => Self::Nil,
                     #(
                         VariantType::#variant_ty_enumerators_pascal
-                            => Self::#variant_ty_enumerators_pascal(variant.to::<#variant_ty_enumerators_rust>()),
+                            => Self::#variant_ty_enumerators_pascal(variant.to::<#dispatch_payload_tys>()),
                     )*
-                };
+                }
+            }
+
+            /// Returns the type of the dispatched value.
+            pub fn variant_type(&self) -> VariantType {
+                match self {
+                    Self::Nil => VariantType::Nil,
+                    #(
+                        Self::#variant_ty_enumerators_pascal(_) => VariantType::#variant_ty_enumerators_pascal,
+                    )*
+                }
+            }
+        }
 
-                Ok(dispatch)
+        impl From<&Variant> for VariantDispatch {
+            fn from(variant: &Variant) -> Self {
+                Self::from_variant(variant)
             }
         }
 
@@ -1188,6 +1188,15 @@ fn make_method_definition(
 
     let class_name_str = &class_name.godot_ty;
     let class_name_stringname = make_string_name(class_name_str);
+    let instrumentation = if special_cases::is_signal_emission(class_name, &method.name) {
+        quote! {
+            #[cfg(feature = "signal-stats")]
+            crate::profiling::record_signal_emission(self, &signal);
+        }
+    } else {
+        TokenStream::new()
+    };
+
     let init_code = quote! {
         let __class_name = #class_name_stringname;
         let __method_name = #method_name_stringname;
@@ -1204,6 +1213,7 @@ fn make_method_definition(
             #hash
         );
         let __call_fn = sys::interface_fn!(#function_provider);
+        #instrumentation
     };
 
     let receiver_ffi_arg = &receiver.ffi_arg;
@@ -318,6 +318,23 @@ const SELECTED_CLASSES: &[&str] = &[
     "Input",
     "InputEvent",
     "InputEventAction",
+    "InputEventFromWindow",
+    "InputEventGesture",
+    "InputEventJoypadButton",
+    "InputEventJoypadMotion",
+    "InputEventKey",
+    "InputEventMagnifyGesture",
+    "InputEventMIDI",
+    "InputEventMouse",
+    "InputEventMouseButton",
+    "InputEventMouseMotion",
+    "InputEventPanGesture",
+    "InputEventScreenDrag",
+    "InputEventScreenTouch",
+    "InputEventShortcut",
+    "InputEventWithModifiers",
+    "InputMap",
+    "JSON",
     "Label",
     "MainLoop",
     "Marker2D",
@@ -339,11 +356,14 @@ const SELECTED_CLASSES: &[&str] = &[
     "ResourceLoader",
     "RigidBody2D",
     "SceneTree",
+    "SceneTreeTimer",
+    "Script",
     "Sprite2D",
     "SpriteFrames",
     "TextServer",
     "TextServerExtension",
     "Texture",
+    "Texture2D",
     "Texture2DArray",
     "TextureLayered",
     "Time",
@@ -26,12 +26,6 @@ pub(crate) fn is_deleted(class_name: &TyName, godot_method_name: &str) -> bool {
         //| ("Object", "to_string")
         | ("Object", "get_instance_id")
 
-        // Thread APIs
-        | ("ResourceLoader", "load_threaded_get")
-        | ("ResourceLoader", "load_threaded_get_status")
-        | ("ResourceLoader", "load_threaded_request")
-        // also: enum ThreadLoadStatus
-
         => true, _ => false
     }
 }
@@ -58,6 +52,11 @@ pub(crate) fn is_private(class_name: &TyName, godot_method_name: &str) -> bool {
         | ("RefCounted", "unreference")
         | ("Object", "notification")
 
+        // Thread APIs, covered by task::load_async()
+        | ("ResourceLoader", "load_threaded_get")
+        | ("ResourceLoader", "load_threaded_get_status")
+        | ("ResourceLoader", "load_threaded_request")
+
         => true, _ => false
     }
 }
@@ -74,6 +73,16 @@ pub(crate) fn is_excluded_from_default_params(class_name: Option<&TyName>, godot
     }
 }
 
+/// True if the method emits a signal, and should thus be instrumented when the `signal-stats` feature is enabled.
+#[rustfmt::skip]
+pub(crate) fn is_signal_emission(class_name: &TyName, godot_method_name: &str) -> bool {
+    match (class_name.godot_ty.as_str(), godot_method_name) {
+        | ("Object", "emit_signal")
+
+        => true, _ => false
+    }
+}
+
 /// True if builtin type is excluded (`NIL` or scalars)
 pub(crate) fn is_builtin_type_deleted(class_name: &TyName) -> bool {
     let name = class_name.godot_ty.as_str();
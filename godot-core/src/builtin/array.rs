@@ -8,10 +8,11 @@ use godot_ffi as sys;
 
 use crate::builtin::meta::VariantMetadata;
 use crate::builtin::*;
-use crate::obj::Share;
+use crate::obj::{dom, Gd, GodotClass, Share};
 use crate::property::{Export, ExportInfo, Property, TypeStringHint};
 use std::fmt;
 use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
 /// Godot's `Array` type.
@@ -38,6 +39,13 @@ use sys::{ffi_methods, interface_fn, GodotFfi};
 /// to create a copy of the data, use [`duplicate_shallow()`][Self::duplicate_shallow] or
 /// [`duplicate_deep()`][Self::duplicate_deep].
 ///
+/// # Objects
+///
+/// Elements of type [`Gd<T>`][Gd] are returned as independent pointers, not as borrows of the array. If `T` inherits `RefCounted`,
+/// each extracted `Gd` holds its own reference, so the object stays alive after it is removed from the array or the array is cleared.
+/// Manually managed objects (e.g. nodes) are not kept alive by the array, and may be freed while it still contains them. Converting
+/// such a stale element fails; use [`get_object()`][Self::get_object] to check for this without panicking.
+///
 /// # Thread safety
 ///
 /// Usage is safe if the `Array` is used on a single thread only. Concurrent reads on
@@ -142,6 +150,23 @@ impl<T: VariantMetadata> Array<T> {
         self.as_inner().shuffle();
     }
 
+    /// Shuffles the array deterministically, based on `seed`.
+    ///
+    /// Unlike [`shuffle()`][Self::shuffle], this does not use Godot's global random number generator. The same seed and array
+    /// length always produce the same permutation, on every platform and in every Godot version, which makes it suitable for
+    /// replays or lockstep networking. The algorithm is a Fisher-Yates shuffle driven by a SplitMix64 generator.
+    pub fn shuffle_seeded(&mut self, seed: u64) {
+        let mut rng = SplitMix64 { state: seed };
+
+        for i in (1..self.len()).rev() {
+            let j = rng.next_below(i as u64 + 1) as usize;
+
+            // SAFETY: both indices are in bounds. Swapping two elements of the same array keeps its element type intact.
+            // The array is uniquely referenced after the first ptr_mut() call, so the second one does not reallocate.
+            unsafe { std::ptr::swap(self.ptr_mut(i), self.ptr_mut(j)) };
+        }
+    }
+
     /// Asserts that the given index refers to an existing element.
     ///
     /// # Panics
@@ -207,6 +232,24 @@ impl<T: VariantMetadata> Array<T> {
         Variant::ptr_from_sys_mut(variant_ptr)
     }
 
+    /// Returns the object at `index`, or `None` if there is no live object of class `U` (or derived) at that index.
+    ///
+    /// This is the case if `index` is out of bounds, if the element is nil or not an object, if it is an object of a different class,
+    /// or if it refers to an object that has been freed in the meantime. Unlike [`get()`][Self::get], this does not panic and works
+    /// with any array type, including untyped arrays with mixed contents.
+    ///
+    /// For `RefCounted` classes, the returned `Gd` holds its own reference, which keeps the object alive independently of the array.
+    pub fn get_object<U: GodotClass>(&self, index: usize) -> Option<Gd<U>> {
+        let ptr = self.ptr_or_null(index);
+        if ptr.is_null() {
+            return None;
+        }
+
+        // SAFETY: `ptr_or_null()` returned a non-null pointer, so the index is in bounds.
+        let variant = unsafe { &*ptr };
+        variant.try_to::<Gd<U>>().ok()
+    }
+
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerArray {
         // SAFETY: The memory layout of `TypedArray<T>` does not depend on `T`.
@@ -391,6 +434,116 @@ impl<T: VariantMetadata + FromVariant> Array<T> {
         T::from_variant(variant)
     }
 
+    /// Returns the elements in `range` as a Rust vector, e.g. `array.slice_to_vec(2..5)` or `array.slice_to_vec(..=3)`.
+    ///
+    /// # Panics
+    ///
+    /// Like slicing a Rust slice: if the range's start is greater than its end, or its end is out of bounds.
+    pub fn slice_to_vec(&self, range: impl RangeBounds<usize>) -> Vec<T> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start
+                .checked_add(1)
+                .expect("attempted to slice array from after usize::MAX"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end
+                .checked_add(1)
+                .expect("attempted to slice array up to usize::MAX inclusive"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+
+        assert!(
+            start <= end,
+            "array slice index starts at {start} but ends at {end}"
+        );
+        assert!(
+            end <= len,
+            "array range end index {end} out of range for array of length {len}"
+        );
+
+        (start..end).map(|index| self.get(index)).collect()
+    }
+
+    /// Consumes the array and converts its elements into a Rust vector.
+    ///
+    /// Other references to the same array (e.g. from [`share()`][Share::share] or GDScript) are unaffected; only this
+    /// reference is released.
+    ///
+    /// # Panics
+    ///
+    /// If an element cannot be converted to `T`. This can only happen for untyped arrays ([`VariantArray`] always succeeds),
+    /// or for `T` types that are more restrictive than their Godot counterpart, e.g. `u8` from `INT`. See
+    /// [`try_into_vec()`][Self::try_into_vec] for a fallible version.
+    pub fn into_vec(self) -> Vec<T> {
+        self.iter_shared().collect()
+    }
+
+    /// Consumes the array and converts its elements into a Rust vector, or returns the first conversion error.
+    ///
+    /// Stops at the first element that cannot be converted to `T`. See [`into_vec()`][Self::into_vec] for details.
+    pub fn try_into_vec(self) -> Result<Vec<T>, VariantConversionError> {
+        (0..self.len())
+            .map(|index| {
+                // SAFETY: `index` is in bounds, and the array is not modified during iteration.
+                let variant = unsafe { &*self.ptr(index) };
+                T::try_from_variant(variant)
+            })
+            .collect()
+    }
+
+    /// Returns a new array containing the result of `f` applied to each element, in order.
+    ///
+    /// The result is a typed `Array<U>` (or a [`VariantArray`] for `U = Variant`), independent of this array's element type.
+    ///
+    /// `f` may call into Godot, and may even modify this array through another reference. Elements are read one at a time, right
+    /// before `f` is called on them, so such modifications affect the remaining elements. To map a snapshot instead, call
+    /// [`duplicate_shallow()`][Self::duplicate_shallow] first.
+    ///
+    /// # Panics
+    ///
+    /// If an element cannot be converted to `T`, like [`iter_shared()`][Self::iter_shared].
+    pub fn map_collect<U, F>(&self, f: F) -> Array<U>
+    where
+        U: VariantMetadata + ToVariant,
+        F: FnMut(T) -> U,
+    {
+        self.iter_shared().map(f).collect()
+    }
+
+    /// Returns the index of the first element for which `predicate` returns `true`, or `None` if there is no such element
+    /// (in particular, if the array is empty).
+    ///
+    /// This is the equivalent of [`Iterator::position()`], and stops at the first match. To search for a given value, use
+    /// [`find()`][Self::find] instead, which compares elements with Godot's `==` operator.
+    ///
+    /// # Panics
+    ///
+    /// If an element cannot be converted to `T`, like [`iter_shared()`][Self::iter_shared].
+    pub fn position<F>(&self, mut predicate: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter_shared().position(|element| predicate(&element))
+    }
+
+    /// Returns the first element for which `predicate` returns `true`, or `None` if there is no such element.
+    ///
+    /// This is the equivalent of [`Iterator::find()`]; see [`position()`][Self::position] to get the index instead.
+    ///
+    /// # Panics
+    ///
+    /// If an element cannot be converted to `T`, like [`iter_shared()`][Self::iter_shared].
+    pub fn find_by<F>(&self, mut predicate: F) -> Option<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter_shared().find(|element| predicate(element))
+    }
+
     /// Returns the first element in the array, or `None` if the array is empty. Equivalent of
     /// `front()` in GDScript.
     pub fn first(&self) -> Option<T> {
@@ -466,6 +619,40 @@ impl<T: VariantMetadata + FromVariant> Array<T> {
     }
 }
 
+/// _The methods in this impl block are only available for arrays of user-declared classes, that is,
+/// structs with `#[derive(GodotClass)]`._ <br><br>
+impl<T> Array<Gd<T>>
+where
+    T: GodotClass<Declarer = dom::UserDomain>,
+{
+    /// Calls `f` with a shared borrow of each element, in order.
+    ///
+    /// Equivalent to calling [`Gd::bind()`] on every element; each borrow is released before the next element is bound.
+    ///
+    /// # Panics
+    /// * If an element is null.
+    /// * Under the same conditions as [`Gd::bind()`], e.g. if an element is exclusively borrowed elsewhere.
+    pub fn for_each_bind(&self, mut f: impl FnMut(&T)) {
+        for element in self.iter_shared() {
+            element.with_bind(&mut f);
+        }
+    }
+
+    /// Calls `f` with an exclusive borrow of each element, in order.
+    ///
+    /// Equivalent to calling [`Gd::bind_mut()`] on every element; each borrow is released before the next element is bound.
+    /// The array itself is not modified, thus `&self` is enough.
+    ///
+    /// # Panics
+    /// * If an element is null.
+    /// * Under the same conditions as [`Gd::bind_mut()`], e.g. if an element is borrowed elsewhere or bound again inside `f`.
+    pub fn for_each_bind_mut(&self, mut f: impl FnMut(&mut T)) {
+        for mut element in self.iter_shared() {
+            element.with_bind_mut(&mut f);
+        }
+    }
+}
+
 impl<T: VariantMetadata + ToVariant> Array<T> {
     /// Finds the index of an existing value in a sorted array using binary search. Equivalent of
     /// `bsearch` in GDScript.
@@ -570,6 +757,96 @@ impl<T: VariantMetadata + ToVariant> Array<T> {
     pub fn fill(&mut self, value: &T) {
         self.as_inner().fill(value.to_variant());
     }
+
+    /// Resizes the array to `new_len` elements, computing new elements from their index.
+    ///
+    /// If `new_len` is smaller than the current length, the array is truncated and `f` is not called. Otherwise, `f(i)` is
+    /// called for each new index `i` in ascending order. Like all modifications, this affects all references to the array.
+    ///
+    /// See also [`resize()`][Self::resize] and [`fill()`][Self::fill].
+    pub fn resize_with<F>(&mut self, new_len: usize, mut f: F)
+    where
+        F: FnMut(usize) -> T,
+    {
+        let old_len = self.len();
+        self.resize(new_len);
+
+        for i in old_len..new_len {
+            self.set(i, f(i));
+        }
+    }
+
+    /// Sorts the array by a key extracted from each element.
+    ///
+    /// Elements are converted to `T` and sorted on the Rust side, then written back in place; the array keeps its
+    /// identity, so other references to it observe the new order. Empty arrays are left untouched.
+    ///
+    /// Note: The sorting algorithm used is not
+    /// [stable](https://en.wikipedia.org/wiki/Sorting_algorithm#Stability). This means that elements
+    /// with equal keys may have their order changed when using `sort_unstable_by_key`.
+    pub fn sort_unstable_by_key<K, F>(&mut self, f: F)
+    where
+        T: FromVariant,
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        if self.is_empty() {
+            return;
+        }
+
+        let mut elements: Vec<T> = self.iter_shared().collect();
+        elements.sort_unstable_by_key(f);
+
+        for (index, value) in elements.into_iter().enumerate() {
+            self.set(index, value);
+        }
+    }
+
+    /// Removes consecutive repeated elements, like [`Vec::dedup()`].
+    ///
+    /// If the array is sorted, this removes all duplicates. The array is modified in place and keeps its identity, so other
+    /// references to it observe the change. Arrays with fewer than 2 elements are left untouched.
+    pub fn dedup(&mut self)
+    where
+        T: FromVariant + PartialEq,
+    {
+        self.dedup_with(|elements| elements.dedup());
+    }
+
+    /// Removes all but the first of consecutive elements that resolve to the same key, like [`Vec::dedup_by_key()`].
+    ///
+    /// Behaves like [`dedup()`][Self::dedup] otherwise.
+    pub fn dedup_by_key<K, F>(&mut self, key: F)
+    where
+        T: FromVariant,
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        self.dedup_with(|elements| elements.dedup_by_key(key));
+    }
+
+    fn dedup_with(&mut self, dedup: impl FnOnce(&mut Vec<T>))
+    where
+        T: FromVariant,
+    {
+        let len = self.len();
+        if len < 2 {
+            return;
+        }
+
+        let mut elements: Vec<T> = self.iter_shared().collect();
+        dedup(&mut elements);
+
+        if elements.len() == len {
+            return;
+        }
+
+        // Elements before the first removed one are unchanged, but rewriting them is simpler than tracking that.
+        self.resize(elements.len());
+        for (index, value) in elements.into_iter().enumerate() {
+            self.set(index, value);
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -959,3 +1236,27 @@ impl fmt::Debug for TypeInfo {
         write!(f, "{:?}{}", self.variant_type, class_str)
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Minimal deterministic PRNG for [`Array::shuffle_seeded()`], see <https://prng.di.unimi.it/splitmix64.c>.
+///
+/// The output sequence must never change, as users rely on it for reproducibility.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `0..bound`, using multiply-shift reduction instead of a modulo.
+    fn next_below(&mut self, bound: u64) -> u64 {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+}
@@ -168,10 +168,18 @@ impl Basis {
 
     /// Creates a [`Quaternion`] representing the same rotation as this basis.
     ///
+    /// The basis may contain scale. If it is mirrored (negative determinant), the mirroring is attributed to the scale, so that
+    /// the rotation together with [`scale()`][Self::scale] reproduces the basis.
+    ///
     /// _Godot equivalent: `Basis.get_rotation_quaternion()`_
     #[doc(alias = "get_rotation_quaternion")]
     pub fn to_quat(self) -> Quaternion {
-        RQuat::from_mat3(&self.orthonormalized().to_glam()).to_front()
+        let mut rotation = self.orthonormalized();
+        if rotation.determinant() < 0.0 {
+            rotation = rotation.scaled(Vector3::new(-1.0, -1.0, -1.0));
+        }
+
+        RQuat::from_mat3(&rotation.to_glam()).to_front()
     }
 
     const fn to_rows_array(self) -> [real; 9] {
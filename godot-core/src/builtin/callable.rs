@@ -23,6 +23,10 @@ use super::{StringName, VariantArray};
 ///
 /// Currently it is impossible to use `bind` and `unbind` in GDExtension, see [godot-cpp#802].
 ///
+/// Callables implement `Eq` and `Hash`, so they can be stored in sets or used as map keys, e.g. to avoid connecting
+/// the same callable twice. Standard callables are equal if they have the same object and method; custom callables
+/// are compared by identity, unless their implementation provides its own comparison.
+///
 /// [godot-cpp#802]: https://github.com/godotengine/godot-cpp/issues/802
 #[repr(C, align(8))]
 pub struct Callable {
@@ -81,6 +85,17 @@ impl Callable {
         self.as_inner().callv(arguments)
     }
 
+    /// Calls the method represented by this callable in deferred mode, i.e. during idle time at the end of the
+    /// current frame.
+    ///
+    /// The same argument rules as for [`callv()`][Self::callv] apply. If this callable was created with bound
+    /// arguments (GDScript `bind()`), those are appended after `arguments`. The return value of the call is discarded.
+    ///
+    /// _Godot equivalent: `call_deferred`_
+    pub fn call_deferred(&self, arguments: &[Variant]) {
+        self.as_inner().call_deferred(arguments)
+    }
+
     /// Returns the name of the method represented by this callable. If the callable is a lambda function,
     /// returns the function's name.
     ///
@@ -177,13 +192,11 @@ impl_builtin_traits! {
         // Currently no Default::default() to encourage explicit valid initialization.
         //Default => callable_construct_default;
 
-        // Equality for custom callables depend on the equality implementation of that custom callable. This
-        // is from what i can tell currently implemented as total equality in all cases, but i dont believe
-        // there are any guarantees that all implementations of equality for custom callables will be.
-        //
-        // So we cannot implement `Eq` here and be confident equality will be total for all future custom
-        // callables.
-        PartialEq => callable_operator_equal;
+        // Standard callables are equal if they refer to the same object and method. Custom callables (e.g. GDScript lambdas
+        // or bound callables) delegate to their own comparison, which compares by identity unless both sides are of the same
+        // custom kind and that kind defines value equality. Either way, equality is total, so `Eq` and `Hash` are sound.
+        Eq => callable_operator_equal;
+        Hash;
         Clone => callable_construct_copy;
         Drop => callable_destroy;
     }
@@ -364,6 +364,35 @@ impl Default for Color {
     }
 }
 
+/// Constructs a color from `[r, g, b, a]`. No color space conversion is applied.
+impl From<[f32; 4]> for Color {
+    fn from(array: [f32; 4]) -> Self {
+        let [r, g, b, a] = array;
+        Self::from_rgba(r, g, b, a)
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> Self {
+        [color.r, color.g, color.b, color.a]
+    }
+}
+
+/// Constructs a color from a `glam::Vec4`, interpreting `x, y, z, w` as `r, g, b, a`.
+#[cfg(feature = "glam-interop")]
+impl From<glam::Vec4> for Color {
+    fn from(vector: glam::Vec4) -> Self {
+        Self::from_rgba(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+#[cfg(feature = "glam-interop")]
+impl From<Color> for glam::Vec4 {
+    fn from(color: Color) -> Self {
+        glam::Vec4::new(color.r, color.g, color.b, color.a)
+    }
+}
+
 impl ops::Mul<Color> for Color {
     type Output = Color;
     fn mul(mut self, rhs: Color) -> Self::Output {
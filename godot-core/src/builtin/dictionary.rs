@@ -6,10 +6,12 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::{inner, FromVariant, ToVariant, Variant};
-use crate::obj::Share;
+use crate::builtin::{inner, FromVariant, ToVariant, Variant, VariantConversionError};
+use crate::obj::{Gd, GodotClass, Share};
 use crate::property::{Export, ExportInfo, Property};
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::Hash;
 use std::marker::PhantomData;
 use std::ptr::addr_of_mut;
 use sys::types::OpaqueDictionary;
@@ -124,6 +126,37 @@ impl Dictionary {
         self.as_inner().get(key.to_variant(), Variant::nil())
     }
 
+    /// Returns the value for the given key, converted to `T`.
+    ///
+    /// Returns `Ok(None)` if the key is absent, and `Err` if the value is present but cannot be converted to `T`.
+    /// See [`Self::get_or`] if you do not need to distinguish these cases.
+    pub fn get_typed<T: FromVariant>(
+        &self,
+        key: impl ToVariant,
+    ) -> Result<Option<T>, VariantConversionError> {
+        self.get(key).map(|value| value.try_to::<T>()).transpose()
+    }
+
+    /// Returns the value for the given key converted to `T`, or `default` if the key is absent or the value cannot be
+    /// converted to `T`.
+    ///
+    /// See [`Self::get_typed`] to distinguish absent keys from values of the wrong type.
+    pub fn get_or<T: FromVariant>(&self, key: impl ToVariant, default: T) -> T {
+        match self.get_typed(key) {
+            Ok(Some(value)) => value,
+            Ok(None) | Err(_) => default,
+        }
+    }
+
+    /// Returns the object for the given key, or `None` if there is no live object of class `U` (or derived) under that key.
+    ///
+    /// This is the case if the key is absent, if the value is nil or not an object, if it is an object of a different class, or if it
+    /// refers to an object that has been freed in the meantime. Like for [`Array::get_object()`][super::Array::get_object], the
+    /// returned `Gd` holds its own reference for `RefCounted` classes, independent of the dictionary.
+    pub fn get_object<U: GodotClass>(&self, key: impl ToVariant) -> Option<Gd<U>> {
+        self.get_or_nil(key).try_to::<Gd<U>>().ok()
+    }
+
     /// Returns `true` if the dictionary contains the given key.
     ///
     /// _Godot equivalent: `has`_
@@ -162,6 +195,26 @@ impl Dictionary {
         self.as_inner().values()
     }
 
+    /// Returns all keys converted to `K`, in insertion order.
+    ///
+    /// Fails with the error of the first key that cannot be converted, e.g. if the dictionary has keys of different types.
+    pub fn keys_typed<K: FromVariant>(&self) -> Result<Vec<K>, VariantConversionError> {
+        self.keys_array()
+            .iter_shared()
+            .map(|key| key.try_to::<K>())
+            .collect()
+    }
+
+    /// Returns all values converted to `V`, in insertion order of their keys.
+    ///
+    /// Fails with the error of the first value that cannot be converted, e.g. if the dictionary has values of different types.
+    pub fn values_typed<V: FromVariant>(&self) -> Result<Vec<V>, VariantConversionError> {
+        self.values_array()
+            .iter_shared()
+            .map(|value| value.try_to::<V>())
+            .collect()
+    }
+
     /// Returns true if the dictionary is empty.
     pub fn is_empty(&self) -> bool {
         self.as_inner().is_empty()
@@ -334,6 +387,37 @@ impl Export for Dictionary {
     }
 }
 
+/// A `HashMap` is exposed to Godot as a `Dictionary`, which is converted from and to on every access.
+///
+/// Godot 4.1 has no typed dictionaries, so the editor does not know about `K` and `V` and allows entries of any type.
+/// Setting a dictionary with a key or value that cannot be converted panics, which fails the assignment. Since the map
+/// has no order, the order of entries in the returned dictionary (and thus in the inspector) is unspecified.
+impl<K, V> Property for HashMap<K, V>
+where
+    K: ToVariant + FromVariant + Eq + Hash,
+    V: ToVariant + FromVariant,
+{
+    type Intermediate = Dictionary;
+
+    fn get_property(&self) -> Self::Intermediate {
+        Dictionary::from(self)
+    }
+
+    fn set_property(&mut self, value: Self::Intermediate) {
+        *self = value.iter_shared().typed::<K, V>().collect();
+    }
+}
+
+impl<K, V> Export for HashMap<K, V>
+where
+    K: ToVariant + FromVariant + Eq + Hash,
+    V: ToVariant + FromVariant,
+{
+    fn default_export_info() -> ExportInfo {
+        ExportInfo::with_hint_none()
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Conversion traits
 
@@ -366,6 +450,9 @@ impl<K: ToVariant, V: ToVariant> Extend<(K, V)> for Dictionary {
     }
 }
 
+/// Creates a dictionary from an iterator over key-value pairs, e.g. with `collect()`.
+///
+/// Each key and value are converted to a `Variant`. If a key appears multiple times, the last value wins.
 impl<K: ToVariant, V: ToVariant> FromIterator<(K, V)> for Dictionary {
     fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
         let mut dict = Dictionary::new();
@@ -29,8 +29,8 @@ static CACHED_STRING_NAMES: sync::Mutex<Option<HashMap<ClassName, Box<StringName
 ///
 /// Holds the Godot name, not the Rust name (they sometimes differ, e.g. Godot `CSGMesh3D` vs Rust `CsgMesh3D`).
 ///
-/// You cannot construct instances of this type yourself; use [`GodotClass::class_name()`](crate::obj::GodotClass::class_name()).
-/// This struct is very cheap to copy.
+/// For classes known at compile time, use [`GodotClass::class_name()`](crate::obj::GodotClass::class_name()). Names of classes
+/// that are only known at runtime can be created with [`ClassName::new_dynamic()`]. This struct is very cheap to copy.
 #[derive(Copy, Clone, Debug)]
 pub struct ClassName {
     // Could use small-array optimization for common string lengths.
@@ -48,6 +48,20 @@ impl ClassName {
         Self { c_str }
     }
 
+    /// Creates a class name from a string that is only known at runtime, e.g. for
+    /// [`register_class_dynamic()`][crate::register_class_dynamic].
+    ///
+    /// The string is leaked, as class names are required to live until the library is unloaded. Only call this once per class.
+    ///
+    /// # Panics
+    /// If `name` is not ASCII or contains a nul byte.
+    pub fn new_dynamic(name: &str) -> Self {
+        let c_string = std::ffi::CString::new(name).expect("class name must not contain nul bytes");
+        let bytes: &'static [u8] = Box::leak(c_string.into_bytes_with_nul().into_boxed_slice());
+
+        Self::from_ascii_cstr(bytes)
+    }
+
     #[doc(hidden)]
     pub fn none() -> Self {
         // In Godot, an empty class name means "no class".
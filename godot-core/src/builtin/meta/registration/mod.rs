@@ -8,3 +8,4 @@
 
 pub mod constant;
 pub mod method;
+pub mod rpc;
@@ -0,0 +1,106 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::builtin::meta::ClassName;
+use crate::builtin::{dict, Dictionary, StringName, ToVariant};
+use crate::engine::Node;
+use crate::obj::Gd;
+
+// Mutex: needed for global access (Sync). Godot stores the RPC configuration per node, not per class; so it is kept here
+// and applied to each instance on construction.
+static RPC_CONFIGS: Mutex<Option<HashMap<ClassName, Vec<(String, RpcConfig)>>>> = Mutex::new(None);
+
+/// Who may call a remote procedure, see `#[rpc]`.
+///
+/// _Godot equivalent: `MultiplayerAPI.RPCMode`_
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RpcMode {
+    /// Only the multiplayer authority of the node may call the method on other peers.
+    Authority,
+
+    /// Any peer may call the method.
+    AnyPeer,
+}
+
+/// How RPC packets are sent, see `#[rpc]`.
+///
+/// _Godot equivalent: `MultiplayerPeer.TransferMode`_
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TransferMode {
+    /// Packets may be lost or arrive out of order.
+    Unreliable,
+
+    /// Packets may be lost, but older packets arriving after newer ones are dropped.
+    UnreliableOrdered,
+
+    /// Packets are resent until acknowledged, and arrive in order.
+    Reliable,
+}
+
+/// Multiplayer configuration of a `#[func]` callable as remote procedure call, declared with `#[rpc]`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct RpcConfig {
+    pub rpc_mode: RpcMode,
+    pub transfer_mode: TransferMode,
+
+    /// Whether calling the RPC also runs the method on the calling peer.
+    pub call_local: bool,
+    pub channel: u32,
+}
+
+impl RpcConfig {
+    /// Converts to the dictionary format accepted by `Node.rpc_config()`.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let rpc_mode: i64 = match self.rpc_mode {
+            RpcMode::AnyPeer => 1,
+            RpcMode::Authority => 2,
+        };
+        let transfer_mode: i64 = match self.transfer_mode {
+            TransferMode::Unreliable => 0,
+            TransferMode::UnreliableOrdered => 1,
+            TransferMode::Reliable => 2,
+        };
+
+        dict! {
+            "rpc_mode": rpc_mode,
+            "transfer_mode": transfer_mode,
+            "call_local": self.call_local,
+            "channel": i64::from(self.channel),
+        }
+    }
+
+    /// Stores the configuration of `method_name`, to be applied to every new instance of the class `class_name`.
+    pub fn register(self, class_name: ClassName, method_name: &str) {
+        let mut configs = RPC_CONFIGS.lock().unwrap();
+        let methods = configs
+            .get_or_insert_with(HashMap::new)
+            .entry(class_name)
+            .or_default();
+
+        // Registering again (e.g. after hot reload) replaces the previous configuration.
+        methods.retain(|(name, _)| name != method_name);
+        methods.push((method_name.to_string(), self));
+    }
+}
+
+/// Applies the registered RPC configurations of `class_name` to a newly constructed instance.
+pub(crate) fn configure_node(class_name: ClassName, node: &mut Gd<Node>) {
+    let methods = match RPC_CONFIGS.lock().unwrap().as_ref() {
+        Some(configs) => configs.get(&class_name).cloned(),
+        None => None,
+    };
+
+    // Outside the lock, as Godot may call back into Rust.
+    for (method_name, config) in methods.into_iter().flatten() {
+        node.rpc_config(
+            StringName::from(method_name.as_str()),
+            config.to_dictionary().to_variant(),
+        );
+    }
+}
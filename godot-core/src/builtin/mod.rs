@@ -33,7 +33,7 @@
 //!   overloading would become impossible](https://github.com/kvark/mint/issues/75).
 
 // Re-export macros.
-pub use crate::{array, dict, real, reals, varray};
+pub use crate::{array, dict, real, reals, sname, variant_match, varray};
 
 pub use aabb::*;
 pub use array_inner::{Array, VariantArray};
@@ -7,6 +7,8 @@
 use godot_ffi as sys;
 
 use crate::builtin::*;
+use crate::engine::file_access::CompressionMode;
+use crate::obj::EngineEnum;
 use std::fmt;
 use sys::types::*;
 use sys::{ffi_methods, interface_fn, GodotFfi};
@@ -629,3 +631,210 @@ impl_packed_array!(
         PartialEq => packed_color_array_operator_equal;
     },
 );
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Bulk conversions for geometry data
+
+impl PackedVector3Array {
+    /// Creates an array from a slice of vectors, copying all elements at once.
+    ///
+    /// Equivalent to `PackedVector3Array::from(slice)`.
+    pub fn from_slice(slice: &[Vector3]) -> Self {
+        let mut array = Self::new();
+        array.set_from_slice(slice);
+        array
+    }
+
+    /// Creates an array from a slice of `[x, y, z]` component triples, copying all elements at once.
+    ///
+    /// Components use [`real`][type@real], so this is `[f32; 3]` by default, and `[f64; 3]` with the `double-precision` feature.
+    pub fn from_raw(components: &[[real; 3]]) -> Self {
+        // SAFETY: Vector3 is #[repr(C)] with exactly three `real` fields, so it has the same size and alignment as [real; 3].
+        let vectors = unsafe {
+            std::slice::from_raw_parts(components.as_ptr() as *const Vector3, components.len())
+        };
+
+        Self::from_slice(vectors)
+    }
+
+    /// Replaces the contents of this array with `slice`, resizing it as needed.
+    ///
+    /// The elements are copied with a single memory copy, rather than element-wise `push()` calls. If the array
+    /// storage is shared with other instances, it is copied first (copy-on-write), so those are not affected.
+    pub fn set_from_slice(&mut self, slice: &[Vector3]) {
+        self.resize(slice.len());
+        self.as_mut_slice().copy_from_slice(slice);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Gradients
+
+impl PackedColorArray {
+    /// Samples the array as a gradient of evenly spaced colors at position `t`, interpolating linearly between adjacent colors.
+    ///
+    /// `t = 0.0` returns the first color and `t = 1.0` the last one; values outside this range are clamped. An array with a
+    /// single color returns that color for every `t`.
+    ///
+    /// # Panics
+    /// If the array is empty.
+    pub fn sample(&self, t: f32) -> Color {
+        let colors = self.as_slice();
+        assert!(!colors.is_empty(), "sample(): PackedColorArray is empty");
+
+        let last = colors.len() - 1;
+        if last == 0 {
+            return colors[0];
+        }
+
+        let position = t.clamp(0.0, 1.0) * last as f32;
+        let index = (position as usize).min(last - 1);
+        let weight = position - index as f32;
+
+        let (from, to) = (colors[index], colors[index + 1]);
+        let lerp = |a: f32, b: f32| a + (b - a) * weight;
+        Color::from_rgba(
+            lerp(from.r, to.r),
+            lerp(from.g, to.g),
+            lerp(from.b, to.b),
+            lerp(from.a, to.a),
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Bitsets
+
+macro_rules! impl_packed_bitset {
+    ($PackedArray:ident, $Element:ty, $Unsigned:ty) => {
+        /// _Bitset helpers, which treat the array as a sequence of bits: bit `i` is bit `i % N` of element `i / N`, where `N`
+        /// is the number of bits per element._ <br><br>
+        impl $PackedArray {
+            const BITS_PER_ELEMENT: usize = <$Element>::BITS as usize;
+
+            /// Creates a bitset from `bools`, where bit `i` is set if `bools[i]` is `true`.
+            ///
+            /// The array has the minimal length to hold all bits; unused bits of the last element are 0.
+            pub fn from_bools(bools: &[bool]) -> Self {
+                let mut array = Self::new();
+                array.resize((bools.len() + Self::BITS_PER_ELEMENT - 1) / Self::BITS_PER_ELEMENT);
+
+                let elements = array.as_mut_slice();
+                for (bit, _) in bools.iter().enumerate().filter(|(_, &value)| value) {
+                    elements[bit / Self::BITS_PER_ELEMENT] |= Self::bit_mask(bit);
+                }
+                array
+            }
+
+            /// Returns whether bit `bit` is set. Bits beyond the end of the array are not set.
+            pub fn get_bit(&self, bit: usize) -> bool {
+                let index = bit / Self::BITS_PER_ELEMENT;
+                self.as_slice()
+                    .get(index)
+                    .map_or(false, |&element| element & Self::bit_mask(bit) != 0)
+            }
+
+            /// Sets bit `bit` to `value`.
+            ///
+            /// If the bit lies beyond the end of the array and `value` is `true`, the array is grown with zeroed elements.
+            /// Clearing a bit there has no effect, as it is not set anyway.
+            pub fn set_bit(&mut self, bit: usize, value: bool) {
+                let index = bit / Self::BITS_PER_ELEMENT;
+                if index >= self.len() {
+                    if !value {
+                        return;
+                    }
+                    self.resize(index + 1);
+                }
+
+                let element = &mut self.as_mut_slice()[index];
+                if value {
+                    *element |= Self::bit_mask(bit);
+                } else {
+                    *element &= !Self::bit_mask(bit);
+                }
+            }
+
+            /// Returns the number of set bits in the whole array.
+            pub fn count_ones(&self) -> usize {
+                self.as_slice()
+                    .iter()
+                    .map(|&element| element.count_ones() as usize)
+                    .sum()
+            }
+
+            /// Mask of `bit` within its element. The shift happens on the unsigned type, so the sign bit is just another bit.
+            fn bit_mask(bit: usize) -> $Element {
+                (1 as $Unsigned << (bit % Self::BITS_PER_ELEMENT)) as $Element
+            }
+        }
+    };
+}
+
+impl_packed_bitset!(PackedInt32Array, i32, u32);
+impl_packed_bitset!(PackedInt64Array, i64, u64);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Compression
+
+impl PackedByteArray {
+    /// Returns a new array with the contents of this one compressed, using the given `mode`.
+    ///
+    /// Note that the size of the uncompressed data is not stored, so you need to remember it for [`decompress()`][Self::decompress].
+    pub fn compress(&self, mode: CompressionMode) -> PackedByteArray {
+        self.as_inner().compress(mode.ord() as i64)
+    }
+
+    /// Returns a new array with the contents of this one decompressed, using the given `mode`.
+    ///
+    /// `expected_size` must be the exact size of the uncompressed data, and `mode` must be the one used for compression.
+    ///
+    /// # Errors
+    /// If the data cannot be decompressed, e.g. because it is corrupt, or if the result does not have exactly `expected_size`
+    /// bytes. In that case, Godot may additionally print an error.
+    pub fn decompress(
+        &self,
+        expected_size: usize,
+        mode: CompressionMode,
+    ) -> Result<PackedByteArray, DecompressError> {
+        if expected_size == 0 {
+            // Godot rejects a buffer size of 0; the only valid result is empty, regardless of input.
+            return Ok(PackedByteArray::new());
+        }
+
+        let decompressed = self
+            .as_inner()
+            .decompress(to_i64(expected_size), mode.ord() as i64);
+
+        if decompressed.len() == expected_size {
+            Ok(decompressed)
+        } else {
+            Err(DecompressError { expected_size })
+        }
+    }
+}
+
+/// Error returned by [`PackedByteArray::decompress()`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct DecompressError {
+    expected_size: usize,
+}
+
+impl DecompressError {
+    /// The number of bytes that were expected after decompression.
+    pub fn expected_size(&self) -> usize {
+        self.expected_size
+    }
+}
+
+impl fmt::Display for DecompressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to decompress into {} bytes; data may be corrupt, or the size or compression mode may be wrong",
+            self.expected_size
+        )
+    }
+}
+
+impl std::error::Error for DecompressError {}
@@ -288,6 +288,34 @@ impl GlamType for RQuat {
     }
 }
 
+/// Constructs a quaternion from `[x, y, z, w]`, the same component order as `glam::Quat`.
+impl From<[real; 4]> for Quaternion {
+    fn from(array: [real; 4]) -> Self {
+        let [x, y, z, w] = array;
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<Quaternion> for [real; 4] {
+    fn from(quat: Quaternion) -> Self {
+        [quat.x, quat.y, quat.z, quat.w]
+    }
+}
+
+#[cfg(feature = "glam-interop")]
+impl From<RQuat> for Quaternion {
+    fn from(quat: RQuat) -> Self {
+        quat.to_front()
+    }
+}
+
+#[cfg(feature = "glam-interop")]
+impl From<Quaternion> for RQuat {
+    fn from(quat: Quaternion) -> Self {
+        quat.to_glam()
+    }
+}
+
 impl MulAssign<Quaternion> for Quaternion {
     fn mul_assign(&mut self, other: Quaternion) {
         *self = *self * other
@@ -213,3 +213,53 @@ macro_rules! reals {
         arr
     }};
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Layout checks
+
+// The Godot API JSON states the size of each builtin type per build configuration. If `real` and the math types disagree with the
+// configuration selected by the `double-precision` feature, passing them over FFI would silently corrupt memory.
+mod layout_checks {
+    use super::real;
+    use crate::builtin::{Basis, Plane, Quaternion, Vector2, Vector3, Vector4};
+    use godot_ffi as sys;
+    use sys::static_assert_eq_size;
+
+    static_assert_eq_size!(Vector2, [real; 2], "Vector2 must consist of 2 reals");
+    static_assert_eq_size!(Vector3, [real; 3], "Vector3 must consist of 3 reals");
+    static_assert_eq_size!(Vector4, [real; 4], "Vector4 must consist of 4 reals");
+    static_assert_eq_size!(Quaternion, [real; 4], "Quaternion must consist of 4 reals");
+    static_assert_eq_size!(Basis, [real; 9], "Basis must consist of 9 reals");
+    static_assert_eq_size!(Plane, [real; 4], "Plane must consist of 4 reals");
+
+    static_assert_eq_size!(
+        Vector2,
+        sys::types::OpaqueVector2,
+        "Vector2 size differs from Godot's"
+    );
+    static_assert_eq_size!(
+        Vector3,
+        sys::types::OpaqueVector3,
+        "Vector3 size differs from Godot's"
+    );
+    static_assert_eq_size!(
+        Vector4,
+        sys::types::OpaqueVector4,
+        "Vector4 size differs from Godot's"
+    );
+    static_assert_eq_size!(
+        Quaternion,
+        sys::types::OpaqueQuaternion,
+        "Quaternion size differs from Godot's"
+    );
+    static_assert_eq_size!(
+        Basis,
+        sys::types::OpaqueBasis,
+        "Basis size differs from Godot's"
+    );
+    static_assert_eq_size!(
+        Plane,
+        sys::types::OpaquePlane,
+        "Plane size differs from Godot's"
+    );
+}
@@ -10,7 +10,7 @@ use godot_ffi as sys;
 use sys::types::OpaqueString;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
-use crate::builtin::inner;
+use crate::builtin::{inner, ToVariant};
 
 use super::string_chars::validate_unicode_scalar_sequence;
 use super::{NodePath, StringName};
@@ -39,6 +39,124 @@ impl GodotString {
             .expect("Godot hashes are uint32_t")
     }
 
+    /// Returns the number of characters in the string, i.e. Unicode code points (Rust `char`s).
+    ///
+    /// This is what GDScript's `String.length()` returns, and the unit in which Godot's string methods take indices. It is
+    /// neither the number of UTF-8 bytes (see [`len_bytes()`][Self::len_bytes]) nor of user-perceived characters: a letter
+    /// followed by a combining accent counts as 2. Godot stores strings as UTF-32, so there are no surrogate pairs; an
+    /// emoji outside the Basic Multilingual Plane counts as 1 (unlike in UTF-16 based languages).
+    ///
+    /// _Godot equivalent: `length`_
+    pub fn len_chars(&self) -> usize {
+        self.as_inner().length() as usize
+    }
+
+    /// Returns the length of the string in UTF-8 encoding, in bytes.
+    ///
+    /// This is the size of [`to_string()`][ToString::to_string] and of GDScript's `String.to_utf8_buffer()`, so use it when
+    /// dealing with byte-based protocols or buffers. Characters take between 1 and 4 bytes each.
+    pub fn len_bytes(&self) -> usize {
+        unsafe {
+            // With a null buffer, Godot only computes the length.
+            interface_fn!(string_to_utf8_chars)(self.string_sys(), std::ptr::null_mut(), 0) as usize
+        }
+    }
+
+    /// Formats the string by replacing `{key}` placeholders with the values in `args`.
+    ///
+    /// `args` is typically a [`Dictionary`][crate::builtin::Dictionary] (placeholders are its keys) or an
+    /// [`Array`][crate::builtin::Array] (placeholders are indices `{0}`, `{1}`, ...). This follows the semantics of
+    /// GDScript's `String.format()`; in particular, placeholders without a corresponding value are left unreplaced.
+    ///
+    /// See also [`format_with_placeholder`][Self::format_with_placeholder] for custom placeholder syntax.
+    pub fn format(&self, args: impl ToVariant) -> Self {
+        self.format_with_placeholder(args, "{_}")
+    }
+
+    /// Like [`format`][Self::format], but with a custom placeholder pattern, in which `_` stands for the key.
+    ///
+    /// For example, `"$_"` replaces `$name` with the value of key `name`.
+    pub fn format_with_placeholder(
+        &self,
+        args: impl ToVariant,
+        placeholder: impl Into<GodotString>,
+    ) -> Self {
+        self.as_inner()
+            .format(args.to_variant(), placeholder.into())
+    }
+
+    /// Returns the similarity of two strings in the range `0.0..=1.0`, as computed by Godot's `String.similarity()`.
+    ///
+    /// This is the Sørensen–Dice coefficient of the character pairs (bigrams) in both strings: `1.0` means equal, `0.0` means
+    /// no common pairs. Strings with fewer than 2 characters have no pairs, so they only score `1.0` when equal.
+    pub fn similarity(&self, other: &GodotString) -> f32 {
+        self.as_inner().similarity(other.clone()) as f32
+    }
+
+    /// Returns the Levenshtein (edit) distance between two strings.
+    ///
+    /// This is the minimum number of single-character insertions, deletions and substitutions to turn one string into the other.
+    /// Characters are Unicode scalar values (Rust `char`s), not grapheme clusters; so e.g. `"é"` written as `e` + combining
+    /// accent counts as 2 characters. The distance to an empty string is the length of the other string.
+    ///
+    /// # Panics
+    /// If either string contains invalid Unicode scalar values, see [`chars_checked()`][Self::chars_checked].
+    pub fn levenshtein_distance(&self, other: &GodotString) -> usize {
+        let a = self.chars_checked();
+        let b = other.chars_checked();
+
+        // Classic dynamic programming, keeping only one row of the matrix: row[j] = distance(a[..i], b[..j]).
+        let mut row: Vec<usize> = (0..=b.len()).collect();
+        for (i, &a_char) in a.iter().enumerate() {
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+
+            for (j, &b_char) in b.iter().enumerate() {
+                let substitution = diagonal + usize::from(a_char != b_char);
+                diagonal = row[j + 1];
+                row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+            }
+        }
+
+        row[b.len()]
+    }
+
+    /// Returns the string converted to lowercase, using Godot's `String.to_lower()`.
+    ///
+    /// Godot maps each character individually with its own Unicode case tables; there is no locale awareness (e.g. Turkish
+    /// dotted/dotless `i`) and no multi-character mappings. Rust's [`str::to_lowercase()`] can differ for such characters,
+    /// so prefer this method when comparing against strings produced by GDScript.
+    pub fn to_lower(&self) -> Self {
+        self.as_inner().to_lower()
+    }
+
+    /// Returns the string converted to uppercase, using Godot's `String.to_upper()`.
+    ///
+    /// Same caveats as [`to_lower()`][Self::to_lower]: per-character mapping without locale. For example, `"ß"` stays `"ß"`,
+    /// whereas Rust's [`str::to_uppercase()`] yields `"SS"`.
+    pub fn to_upper(&self) -> Self {
+        self.as_inner().to_upper()
+    }
+
+    /// Returns a copy with ASCII letters `A-Z` mapped to `a-z`; all other characters are unchanged.
+    ///
+    /// Equivalent to [`to_lower()`][Self::to_lower] for pure-ASCII strings. Unlike that one, this never touches non-ASCII characters.
+    pub fn to_ascii_lowercase(&self) -> Self {
+        self.map_chars(|c| c.to_ascii_lowercase())
+    }
+
+    /// Returns a copy with ASCII letters `a-z` mapped to `A-Z`; all other characters are unchanged.
+    ///
+    /// Equivalent to [`to_upper()`][Self::to_upper] for pure-ASCII strings. Unlike that one, this never touches non-ASCII characters.
+    pub fn to_ascii_uppercase(&self) -> Self {
+        self.map_chars(|c| c.to_ascii_uppercase())
+    }
+
+    fn map_chars(&self, f: impl Fn(char) -> char) -> Self {
+        let s: String = self.chars_checked().iter().copied().map(f).collect();
+        Self::from(s)
+    }
+
     /// Move `self` into a system pointer. This transfers ownership and thus does not call the destructor.
     ///
     /// # Safety
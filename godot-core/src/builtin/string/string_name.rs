@@ -107,6 +107,15 @@ impl_builtin_traits! {
     }
 }
 
+/// Compares with a Rust string slice, e.g. `node.get_name() == "Player"`.
+///
+/// Converts `other` to a `StringName` first, so prefer comparing two `StringName`s in hot code.
+impl PartialEq<&str> for StringName {
+    fn eq(&self, other: &&str) -> bool {
+        *self == StringName::from(*other)
+    }
+}
+
 impl fmt::Display for StringName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = GodotString::from(self);
@@ -169,3 +178,39 @@ impl From<NodePath> for StringName {
         Self::from(GodotString::from(path))
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Macro
+
+/// Constructs a [`StringName`] from a string literal, creating it only once per call site.
+///
+/// The first evaluation converts the literal and caches the result in a `static`; subsequent evaluations merely clone it, which
+/// increments a reference count instead of looking up the string in Godot's table again. This makes the macro suitable for
+/// hot paths that call methods or access properties by name:
+///
+/// ```no_run
+/// use godot::builtin::sname;
+/// use godot::engine::Node;
+/// use godot::obj::Gd;
+///
+/// fn notify_all(nodes: &mut [Gd<Node>]) {
+///     for node in nodes {
+///         node.call(sname!("on_notified"), &[]);
+///     }
+/// }
+/// ```
+///
+/// The cache is a [`OnceLock`](std::sync::OnceLock), so the macro can be used from multiple threads; if several threads race on the
+/// first evaluation, only one `StringName` is stored. Cached names are never freed, like those of classes. Only evaluate the macro
+/// after Godot has been initialized.
+#[macro_export]
+macro_rules! sname {
+    ($name:literal) => {{
+        static CACHED: ::std::sync::OnceLock<$crate::builtin::StringName> =
+            ::std::sync::OnceLock::new();
+
+        CACHED
+            .get_or_init(|| $crate::builtin::StringName::from($name))
+            .clone()
+    }};
+}
@@ -174,6 +174,9 @@ impl Transform2D {
     /// Returns a transform interpolated between this transform and another by
     /// a given `weight` (on the range of 0.0 to 1.0).
     ///
+    /// Rotation and skew are interpolated as angles along the shortest path (see [`FloatExt::lerp_angle()`]), scale and origin
+    /// linearly. The bases need not be orthonormal.
+    ///
     /// _Godot equivalent: `Transform2D.interpolate_with()`_
     #[must_use]
     pub fn interpolate_with(self, other: Self, weight: real) -> Self {
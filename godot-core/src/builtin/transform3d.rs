@@ -8,7 +8,7 @@ use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
 use crate::builtin::math::{ApproxEq, GlamConv, GlamType};
-use crate::builtin::{real, Aabb, Basis, Plane, Projection, RAffine3, Vector3};
+use crate::builtin::{real, Aabb, Basis, Plane, Projection, Quaternion, RAffine3, Vector3};
 
 use std::fmt::Display;
 use std::ops::Mul;
@@ -119,6 +119,12 @@ impl Transform3D {
 
     /// Returns a transform interpolated between this transform and another by
     /// a given weight (on the range of 0.0 to 1.0).
+    ///
+    /// The bases are decomposed into rotation and scale: rotations are interpolated spherically (see
+    /// [`Quaternion::slerp()`]), while scales and origins are interpolated linearly. The bases need not be orthonormal, but
+    /// shear is lost in the decomposition, see [`decompose()`][Self::decompose].
+    ///
+    /// _Godot equivalent: `Transform3D.interpolate_with()`_
     #[must_use]
     pub fn interpolate_with(self, other: Self, weight: real) -> Self {
         let src_scale = self.basis.scale();
@@ -138,6 +144,40 @@ impl Transform3D {
         }
     }
 
+    /// Splits the transform into translation, rotation and scale.
+    ///
+    /// The result can be recombined with [`compose()`][Self::compose]. A negative determinant (mirroring) is expressed
+    /// as negative scale on all axes, like in `Basis.get_scale()`.
+    ///
+    /// The decomposition is exact only if the basis consists of rotation and scale along its own axes. Shear, or
+    /// non-uniform scale applied _after_ a rotation, cannot be represented this way; in that case the rotation is
+    /// approximated by orthonormalizing the basis, and `compose()` will not reproduce the original transform.
+    ///
+    /// # Panics
+    /// If the determinant of the basis is 0, i.e. the transform is degenerate.
+    pub fn decompose(self) -> (Vector3, Quaternion, Vector3) {
+        let scale = self.basis.scale();
+
+        // Negate mirrored bases, so that the remaining rotation is proper (determinant 1).
+        let rotation_basis = if self.basis.determinant() < 0.0 {
+            self.basis.scaled(Vector3::splat(-1.0))
+        } else {
+            self.basis
+        };
+
+        (self.origin, rotation_basis.to_quat(), scale)
+    }
+
+    /// Creates a transform from translation, rotation and scale, applied in the order scale, rotation, translation.
+    ///
+    /// This is the inverse of [`decompose()`][Self::decompose].
+    pub fn compose(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Self {
+        Self {
+            basis: Basis::from_quat(rotation) * Basis::from_scale(scale),
+            origin: translation,
+        }
+    }
+
     /// Returns true if this transform is finite by calling `is_finite` on the
     /// basis and origin.
     pub fn is_finite(&self) -> bool {
@@ -388,6 +428,7 @@ unsafe impl GodotFfi for Transform3D {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::assert_eq_approx;
 
     // Tests translated from Godot.
 
@@ -455,6 +496,22 @@ mod test {
         );
     }
 
+    #[test]
+    fn decompose_compose() {
+        let translation = Vector3::new(1.0, -2.0, 3.0);
+        let rotation = Quaternion::from_angle_axis(Vector3::new(1.0, 2.0, 3.0).normalized(), 0.7);
+
+        // Non-uniform and mirrored scales are preserved, as long as they are applied before the rotation.
+        for scale in [Vector3::new(2.0, 0.5, 3.0), Vector3::new(-1.5, -1.5, -1.5)] {
+            let transform = Transform3D::compose(translation, rotation, scale);
+            let (t, r, s) = transform.decompose();
+
+            assert_eq_approx!(t, translation);
+            assert_eq_approx!(s, scale);
+            assert_eq_approx!(Transform3D::compose(t, r, s), transform);
+        }
+    }
+
     #[test]
     fn finite_number_checks() {
         let y = Vector3::new(0.0, 1.0, 2.0);
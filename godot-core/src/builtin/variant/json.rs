@@ -0,0 +1,80 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{GodotString, Variant};
+use crate::engine::{global, Json};
+
+impl Variant {
+    /// Parses a JSON string into a variant, using Godot's [`JSON`](crate::engine::Json) class.
+    ///
+    /// Objects become [`Dictionary`][crate::builtin::Dictionary], arrays become
+    /// [`VariantArray`][crate::builtin::VariantArray], and strings become [`GodotString`].
+    ///
+    /// Like in GDScript, **all numbers are parsed as `FLOAT`** (`f64`), even if they have no fractional part. Integers
+    /// beyond 2<sup>53</sup> thus lose precision; transmit them as strings if they must be exact.
+    pub fn from_json(json: &str) -> Result<Variant, JsonError> {
+        let mut parser = Json::new();
+
+        if parser.parse(json.into()) == global::Error::OK {
+            Ok(parser.get_data())
+        } else {
+            Err(JsonError {
+                line: parser.get_error_line(),
+                message: parser.get_error_message(),
+            })
+        }
+    }
+
+    /// Converts the variant to a JSON string, using Godot's [`JSON`](crate::engine::Json) class.
+    ///
+    /// `indent` is repeated for each nesting level; an empty string produces a single line without any whitespace.
+    /// Dictionary keys are sorted, which makes the output deterministic.
+    ///
+    /// Floats are written with full precision, so that `Variant::from_json(&v.to_json(""))` reproduces them exactly.
+    /// Types without a JSON equivalent (e.g. `Vector2` or objects) are written as their string representation, and do not
+    /// round-trip.
+    pub fn to_json(&self, indent: &str) -> GodotString {
+        Json::stringify_ex(self.clone())
+            .indent(indent.into())
+            .full_precision(true)
+            .done()
+    }
+}
+
+/// Error returned by [`Variant::from_json()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct JsonError {
+    line: i32,
+    message: GodotString,
+}
+
+impl JsonError {
+    /// Line at which parsing failed, as reported by Godot.
+    ///
+    /// Godot does not report the column.
+    pub fn line(&self) -> i32 {
+        self.line
+    }
+
+    /// Description of the error, as reported by Godot.
+    pub fn message(&self) -> &GodotString {
+        &self.message
+    }
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "JSON parse error at line {}: {}",
+            self.line, self.message
+        )
+    }
+}
+
+impl std::error::Error for JsonError {}
@@ -4,7 +4,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::{GodotString, StringName};
+use crate::builtin::{
+    Aabb, Basis, Color, GodotString, Plane, Projection, Quaternion, Rect2, StringName, Transform2D,
+    Transform3D, Vector2, Vector3, Vector4,
+};
+use crate::obj::InstanceId;
 use godot_ffi as sys;
 use godot_ffi::GodotFfi;
 use std::{fmt, ptr};
@@ -12,9 +16,13 @@ use sys::types::OpaqueVariant;
 use sys::{ffi_methods, interface_fn};
 
 mod impls;
+mod json;
+mod variant_match;
 mod variant_traits;
 
+pub use crate::gen::central::VariantDispatch;
 pub use impls::*;
+pub use json::JsonError;
 pub use sys::{VariantOperator, VariantType};
 pub use variant_traits::*;
 
@@ -25,6 +33,9 @@ pub struct Variant {
 
 impl Variant {
     /// Create an empty variant (`null` value in GDScript).
+    ///
+    /// Equivalent to `Variant::default()` and `().to_variant()`. This cannot be a `const fn`, as variants are
+    /// initialized through Godot.
     pub fn nil() -> Self {
         Self::default()
     }
@@ -55,12 +66,27 @@ impl Variant {
 
     /// Checks whether the variant is empty (`null` value in GDScript).
     ///
+    /// Shorthand for `self.get_type() == VariantType::Nil`; as such, a variant holding a null object pointer is also nil.
     /// See also [`Self::get_type`].
     pub fn is_nil(&self) -> bool {
         // Use get_type() rather than sys_type(), to also cover nullptr OBJECT as NIL
         self.get_type() == VariantType::Nil
     }
 
+    /// For an object variant, returns the ID of the object without accessing the object itself, which may have been freed.
+    ///
+    /// Returns `None` for other types and for null objects.
+    pub(crate) fn object_id_unchecked(&self) -> Option<InstanceId> {
+        if self.sys_type() != sys::GDEXTENSION_VARIANT_TYPE_OBJECT {
+            return None;
+        }
+
+        // SAFETY: Godot stores an object as `ObjData { ObjectID id; Object *obj; }`, right after the 8-byte type field.
+        // The ID is a plain integer, which remains readable after the object has been freed (unlike the object pointer).
+        let id = unsafe { *(self.var_sys_const() as *const u64).add(1) };
+        InstanceId::try_from_u64(id)
+    }
+
     /// Returns the type that is currently held by this variant.
     ///
     /// If this variant holds a type `Object` but no instance (represented as a null object pointer), then `Nil` will be returned for
@@ -91,6 +117,17 @@ impl Variant {
         }
     }
 
+    /// Returns the ordinal of the type held by this variant, as in Godot's `Variant::Type` enum.
+    ///
+    /// This is the integer with which Godot identifies variant types, e.g. `TYPE_INT` (2) or `TYPE_OBJECT` (24) in GDScript.
+    /// It corresponds to `self.get_type() as i32`, so a null object pointer yields the ordinal of `Nil` (0).
+    ///
+    /// The ordinals are stable within a Godot major version: new types may be appended, but existing ones are not renumbered.
+    /// This makes them suitable as tags for serialization or bridges to other languages.
+    pub fn type_ord(&self) -> i32 {
+        self.get_type() as i32
+    }
+
     /// ⚠️ Calls the specified `method` with the given `args`.
     ///
     /// Supports `Object` as well as built-ins with methods (e.g. `Array`, `Vector3`, `GodotString`, etc).
@@ -158,6 +195,92 @@ impl Variant {
         }
     }
 
+    /// Returns whether `self` and `other` are equal, with a tolerance of `epsilon` for floating-point components.
+    ///
+    /// The rules depend on the types held by both variants:
+    /// * `FLOAT`: the absolute difference is at most `epsilon`.
+    /// * Types composed of floats (`Vector2/3/4`, `Quaternion`, `Color`, `Rect2`, `Aabb`, `Plane`, `Transform2D`, `Basis`,
+    ///   `Transform3D`, `Projection`): each component differs by at most `epsilon`. Both variants must hold the same type.
+    /// * All other types, including integer vectors and `INT` vs. `FLOAT`: strict equality as in [`PartialEq`].
+    ///
+    /// NaN is never approximately equal to anything, not even NaN. Infinities are only equal to the same infinity.
+    /// Containers are compared strictly, i.e. floats nested in `Array` or `Dictionary` are not compared approximately.
+    ///
+    /// Use [`FloatExt::CMP_EPSILON`][crate::builtin::math::FloatExt] as `epsilon` for the tolerance Godot uses in `is_equal_approx()`
+    /// (which however scales the tolerance with the magnitude of the value).
+    pub fn approx_eq(&self, other: &Variant, epsilon: f64) -> bool {
+        if self.get_type() != other.get_type() {
+            return self == other;
+        }
+
+        match (self.float_components(), other.float_components()) {
+            (Some(a), Some(b)) => a
+                .iter()
+                .zip(b.iter())
+                .all(|(a, b)| a == b || (a - b).abs() <= epsilon),
+            _ => self == other,
+        }
+    }
+
+    /// For types composed of floats, returns all components in a fixed order; `None` for other types.
+    fn float_components(&self) -> Option<Vec<f64>> {
+        fn vec2(v: Vector2) -> [f64; 2] {
+            [v.x as f64, v.y as f64]
+        }
+        fn vec3(v: Vector3) -> [f64; 3] {
+            [v.x as f64, v.y as f64, v.z as f64]
+        }
+        fn vec4(v: Vector4) -> [f64; 4] {
+            [v.x as f64, v.y as f64, v.z as f64, v.w as f64]
+        }
+        fn basis(b: Basis) -> Vec<f64> {
+            b.rows.into_iter().flat_map(vec3).collect()
+        }
+
+        let components = match self.get_type() {
+            VariantType::Float => vec![self.to::<f64>()],
+            VariantType::Vector2 => vec2(self.to()).to_vec(),
+            VariantType::Vector3 => vec3(self.to()).to_vec(),
+            VariantType::Vector4 => vec4(self.to()).to_vec(),
+            VariantType::Quaternion => {
+                let q = self.to::<Quaternion>();
+                vec![q.x as f64, q.y as f64, q.z as f64, q.w as f64]
+            }
+            VariantType::Color => {
+                let c = self.to::<Color>();
+                [c.r, c.g, c.b, c.a].map(f64::from).to_vec()
+            }
+            VariantType::Rect2 => {
+                let r = self.to::<Rect2>();
+                [vec2(r.position), vec2(r.size)].concat()
+            }
+            VariantType::Aabb => {
+                let aabb = self.to::<Aabb>();
+                [vec3(aabb.position), vec3(aabb.size)].concat()
+            }
+            VariantType::Plane => {
+                let plane = self.to::<Plane>();
+                [&vec3(plane.normal)[..], &[plane.d as f64]].concat()
+            }
+            VariantType::Transform2D => {
+                let t = self.to::<Transform2D>();
+                [vec2(t.a), vec2(t.b), vec2(t.origin)].concat()
+            }
+            VariantType::Basis => basis(self.to()),
+            VariantType::Transform3D => {
+                let t = self.to::<Transform3D>();
+                [basis(t.basis), vec3(t.origin).to_vec()].concat()
+            }
+            VariantType::Projection => {
+                let p = self.to::<Projection>();
+                p.cols.into_iter().flat_map(vec4).collect()
+            }
+            _ => return None,
+        };
+
+        Some(components)
+    }
+
     /// return a `GodotString` representing the variant
     #[allow(unused_mut)]
     pub fn stringify(&self) -> GodotString {
@@ -175,6 +298,25 @@ impl Variant {
         unsafe { interface_fn!(variant_hash)(self.var_sys()) }
     }
 
+    /// Returns a copy of this variant.
+    ///
+    /// Arrays and dictionaries are copied; if `deep` is true, nested arrays and dictionaries are copied recursively as well.
+    /// Otherwise, nested containers are shared between original and copy. Objects are never copied: an object variant (as well
+    /// as an object nested in a container) refers to the same instance after duplication.
+    ///
+    /// _Godot equivalent : `Variant.duplicate(deep)`_
+    pub fn duplicate(&self, deep: bool) -> Variant {
+        unsafe {
+            Self::from_var_sys_init_default(|variant_ptr| {
+                interface_fn!(variant_duplicate)(
+                    self.var_sys(),
+                    variant_ptr,
+                    deep as sys::GDExtensionBool,
+                )
+            })
+        }
+    }
+
     /// return a false only if the variant is `Variant::NIL`
     /// or an empty `TypedArray` or `Dictionary`.
     pub fn booleanize(&self) -> bool {
@@ -271,6 +413,7 @@ impl Drop for Variant {
     }
 }
 
+/// The default variant is nil, see [`Variant::nil()`].
 impl Default for Variant {
     fn default() -> Self {
         unsafe {
@@ -282,6 +425,15 @@ impl Default for Variant {
 }
 
 // Variant is not Eq because it can contain floats and other types composed of floats.
+/// Compares like GDScript's `==` operator.
+///
+/// * Values of the same type are compared by value; `INT` and `FLOAT` are compared numerically with each other (`1 == 1.0`).
+/// * Floats follow IEEE 754: NaN is not equal to itself, so a variant holding NaN is not equal to a clone of itself.
+/// * Objects are compared by identity, i.e. equal if they refer to the same instance, regardless of their state.
+/// * Arrays and dictionaries are compared element-wise, with the same rules.
+/// * Values of incompatible types are never equal.
+///
+/// For tolerant comparison of floating-point values, see [`Variant::approx_eq()`].
 impl PartialEq for Variant {
     fn eq(&self, other: &Self) -> bool {
         Self::evaluate(self, other, VariantOperator::Equal)
@@ -0,0 +1,93 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// Matches on the type of a [`Variant`], converting the value for the matching arm.
+///
+/// Each arm is named after a [`VariantType`] enumerator, optionally followed by a binding in parentheses, which receives
+/// the converted value. A final `_` arm is required; it handles all types without an arm of their own. The value is
+/// converted to the following Rust types:
+///
+/// | Arm | Type |
+/// |-----|------|
+/// | `Bool` | `bool` |
+/// | `Int` | `i64` |
+/// | `Float` | `f64` |
+/// | `String` | [`GodotString`] |
+/// | `Array` | [`VariantArray`] |
+/// | `Object` | [`Gd<Object>`][crate::obj::Gd] |
+/// | any other | the built-in of the same name, e.g. `Vector2` or `PackedByteArray` |
+///
+/// `Nil` has no value and thus no binding (`Nil => ...`). A variant holding a null object pointer is matched as `Nil`,
+/// like in [`Variant::get_type()`]. Bindings must be irrefutable, e.g. an identifier or `_`; use `if` inside the arm to
+/// check the value further.
+///
+/// # Panics
+/// If the value cannot be converted despite the type having matched. This does not happen for the types above, as the
+/// conversion is the one associated with the respective variant type.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// fn describe(value: &Variant) -> String {
+///     variant_match!(value, {
+///         Nil => "nothing".to_string(),
+///         Int(i) => format!("integer {i}"),
+///         String(s) => format!("string \"{s}\""),
+///         Vector2(v) => format!("vector with length {}", v.length()),
+///         _ => format!("some other {:?}", value.get_type()),
+///     })
+/// }
+/// ```
+///
+/// [`Variant`]: crate::builtin::Variant
+/// [`Variant::get_type()`]: crate::builtin::Variant::get_type
+/// [`VariantType`]: crate::builtin::VariantType
+/// [`GodotString`]: crate::builtin::GodotString
+/// [`VariantArray`]: crate::builtin::VariantArray
+#[macro_export]
+macro_rules! variant_match {
+    ($variant:expr, {
+        $( $Type:ident $( ( $binding:pat ) )? => $arm:expr, )*
+        _ => $fallback:expr $(,)?
+    }) => {{
+        let __variant: &$crate::builtin::Variant = &$variant;
+
+        match __variant.get_type() {
+            $(
+                $crate::builtin::VariantType::$Type => {
+                    $(
+                        let $binding: $crate::__variant_match_type!($Type) =
+                            match $crate::builtin::FromVariant::try_from_variant(__variant) {
+                                Ok(value) => value,
+                                Err(err) => panic!(
+                                    "variant_match!: conversion of {:?} to {} failed: {}",
+                                    __variant,
+                                    stringify!($Type),
+                                    err
+                                ),
+                            };
+                    )?
+                    $arm
+                }
+            )*
+            _ => $fallback,
+        }
+    }};
+}
+
+/// Maps a `VariantType` enumerator to the Rust type of its values, for [`variant_match!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __variant_match_type {
+    (Bool) => { bool };
+    (Int) => { i64 };
+    (Float) => { f64 };
+    (String) => { $crate::builtin::GodotString };
+    (Array) => { $crate::builtin::VariantArray };
+    (Object) => { $crate::obj::Gd<$crate::engine::Object> };
+    ($Builtin:ident) => { $crate::builtin::$Builtin };
+}
@@ -244,6 +244,8 @@ impl_float_vector_glam_fns!(Vector2, real);
 impl_float_vector_component_fns!(Vector2, real, (x, y));
 impl_vector_operators!(Vector2, real, (x, y));
 impl_from_tuple_for_vector2x!(Vector2, real);
+impl_vector_array_conversions!(Vector2, real, 2, (x, y));
+impl_vector_glam_conversions!(Vector2, RVec2);
 
 // SAFETY:
 // This type is represented as `Self` in Godot, so `*mut Self` is sound.
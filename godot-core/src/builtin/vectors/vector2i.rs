@@ -125,6 +125,8 @@ impl_integer_vector_glam_fns!(Vector2i, real);
 impl_integer_vector_component_fns!(Vector2i, real, (x, y));
 impl_vector_operators!(Vector2i, i32, (x, y));
 impl_from_tuple_for_vector2x!(Vector2i, i32);
+impl_vector_array_conversions!(Vector2i, i32, 2, (x, y));
+impl_vector_glam_conversions!(Vector2i, glam::IVec2);
 
 // SAFETY:
 // This type is represented as `Self` in Godot, so `*mut Self` is sound.
@@ -275,6 +275,8 @@ impl_float_vector_glam_fns!(Vector3, real);
 impl_float_vector_component_fns!(Vector3, real, (x, y, z));
 impl_vector_operators!(Vector3, real, (x, y, z));
 impl_from_tuple_for_vector3x!(Vector3, real);
+impl_vector_array_conversions!(Vector3, real, 3, (x, y, z));
+impl_vector_glam_conversions!(Vector3, RVec3);
 
 // SAFETY:
 // This type is represented as `Self` in Godot, so `*mut Self` is sound.
@@ -406,6 +408,24 @@ mod test {
         assert_eq_approx!(vector1.slerp(vector2, 0.5).length(), real!(6.258_311));
     }
 
+    #[test]
+    fn array_conversions() {
+        let vector = Vector3::from([1.0, 2.0, 3.0]);
+        assert_eq!(vector, Vector3::new(1.0, 2.0, 3.0));
+
+        let array: [real; 3] = vector.into();
+        assert_eq!(array, [1.0, 2.0, 3.0]);
+    }
+
+    #[cfg(feature = "glam-interop")]
+    #[test]
+    fn glam_conversions() {
+        let vector = Vector3::new(1.0, 2.0, 3.0);
+        let glam_vector: RVec3 = vector.into();
+        assert_eq!(glam_vector, RVec3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector3::from(glam_vector), vector);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
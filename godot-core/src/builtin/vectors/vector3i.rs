@@ -156,6 +156,8 @@ impl_integer_vector_glam_fns!(Vector3i, real);
 impl_integer_vector_component_fns!(Vector3i, real, (x, y, z));
 impl_vector_operators!(Vector3i, i32, (x, y, z));
 impl_from_tuple_for_vector3x!(Vector3i, i32);
+impl_vector_array_conversions!(Vector3i, i32, 3, (x, y, z));
+impl_vector_glam_conversions!(Vector3i, glam::IVec3);
 
 // SAFETY:
 // This type is represented as `Self` in Godot, so `*mut Self` is sound.
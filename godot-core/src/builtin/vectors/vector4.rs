@@ -43,6 +43,8 @@ impl_common_vector_fns!(Vector4, real);
 impl_float_vector_glam_fns!(Vector4, real);
 impl_float_vector_component_fns!(Vector4, real, (x, y, z, w));
 impl_from_tuple_for_vector4x!(Vector4, real);
+impl_vector_array_conversions!(Vector4, real, 4, (x, y, z, w));
+impl_vector_glam_conversions!(Vector4, RVec4);
 
 impl Vector4 {
     /// Returns a `Vector4` with the given components.
@@ -42,6 +42,8 @@ impl_integer_vector_glam_fns!(Vector4i, real);
 impl_integer_vector_component_fns!(Vector4i, real, (x, y, z, w));
 impl_common_vector_fns!(Vector4i, i32);
 impl_from_tuple_for_vector4x!(Vector4i, i32);
+impl_vector_array_conversions!(Vector4i, i32, 4, (x, y, z, w));
+impl_vector_glam_conversions!(Vector4i, glam::IVec4);
 
 impl Vector4i {
     /// Returns a `Vector4i` with the given components.
@@ -488,3 +488,50 @@ macro_rules! impl_from_tuple_for_vector4x {
         }
     };
 }
+
+/// Implements `From` conversions between a vector and an array of its components, in the order of the fields.
+macro_rules! impl_vector_array_conversions {
+    (
+        $Vector:ty,
+        $Scalar:ty,
+        $len:literal,
+        ($($comp:ident),*)
+    ) => {
+        impl From<[$Scalar; $len]> for $Vector {
+            fn from(array: [$Scalar; $len]) -> Self {
+                let [$($comp),*] = array;
+                Self::new($($comp),*)
+            }
+        }
+
+        impl From<$Vector> for [$Scalar; $len] {
+            fn from(vector: $Vector) -> Self {
+                [$(vector.$comp),*]
+            }
+        }
+    };
+}
+
+/// Implements `From` conversions between a vector and its `glam` counterpart, if the `glam-interop` feature is enabled.
+///
+/// The type must have `from_glam` and `to_glam` functions.
+macro_rules! impl_vector_glam_conversions {
+    (
+        $Vector:ty,
+        $Glam:ty
+    ) => {
+        #[cfg(feature = "glam-interop")]
+        impl From<$Glam> for $Vector {
+            fn from(vector: $Glam) -> Self {
+                Self::from_glam(vector)
+            }
+        }
+
+        #[cfg(feature = "glam-interop")]
+        impl From<$Vector> for $Glam {
+            fn from(vector: $Vector) -> Self {
+                vector.to_glam()
+            }
+        }
+    };
+}
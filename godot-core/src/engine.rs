@@ -6,10 +6,13 @@
 
 //! Godot engine classes and methods.
 
+use std::cell::Cell;
+
 // Re-exports of generated symbols
-use crate::builtin::{GodotString, NodePath};
+use crate::builtin::{GodotString, NodePath, StringName, Variant};
+use crate::obj::closure_relay::{self, OwnedRelay};
 use crate::obj::dom::EngineDomain;
-use crate::obj::{Gd, GodotClass, Inherits};
+use crate::obj::{EngineEnum, Gd, GodotClass, Inherits, InstanceId};
 
 pub use crate::gen::central::global;
 pub use crate::gen::classes::*;
@@ -85,6 +88,70 @@ pub trait NodeExt {
     fn try_get_node_as<T>(&self, path: impl Into<NodePath>) -> Option<Gd<T>>
     where
         T: GodotClass + Inherits<Node>;
+
+    /// Finds the first descendant whose name matches `pattern`, and casts it to type `T`.
+    ///
+    /// `pattern` follows the rules of [`Node::find_child()`]: it does not match full paths, only node names, and may contain
+    /// the wildcards `*` (zero or more characters) and `?` (exactly one character). If multiple nodes match, the first one
+    /// in tree order is taken.
+    ///
+    /// If `recursive` is `false`, only direct children are checked. If `owned` is `true`, only nodes which have an owner
+    /// are considered.
+    ///
+    /// Returns `None` if no node matches, or if the first match does not have type `T` (or inherited). Note that the search
+    /// does not continue after a type mismatch.
+    fn find_child_typed<T>(
+        &self,
+        pattern: impl Into<GodotString>,
+        recursive: bool,
+        owned: bool,
+    ) -> Option<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>;
+
+    /// Sets the name of this node, accepting anything convertible to `StringName` (e.g. `&str`).
+    ///
+    /// Godot sanitizes characters that are not allowed in node names (`.`, `:`, `@`, `/`, `"` and `%`), so `get_name()`
+    /// may return a different name afterwards. If a sibling already has the same name, Godot makes it unique as well.
+    fn set_node_name(&mut self, name: impl Into<StringName>);
+
+    /// Adds `child` as a child of this node, at position `index` among the children.
+    ///
+    /// Unlike [`Node::move_child()`], an `index` past the end is not an error: it is clamped, so the child is appended
+    /// as the last one. To add a node right after a sibling, use [`Node::add_sibling()`] on that sibling.
+    fn add_child_at<C>(&mut self, child: Gd<C>, index: usize)
+    where
+        C: GodotClass + Inherits<Node>;
+
+    /// Moves `child` so that it comes directly after `sibling` in the list of children.
+    ///
+    /// # Panics
+    /// If `child` or `sibling` is not a child of this node.
+    fn move_child_below<C, S>(&mut self, child: Gd<C>, sibling: &Gd<S>)
+    where
+        C: GodotClass + Inherits<Node>,
+        S: GodotClass + Inherits<Node>;
+
+    /// Returns the relative path from this node to `node`, like [`Node::get_path_to()`], for any node type.
+    ///
+    /// Both nodes must be part of the same tree (not necessarily the scene tree); otherwise Godot prints an error and an
+    /// empty path is returned. To avoid recomputing paths between the same nodes, see [`NodePathCache`].
+    fn path_to<T>(&self, node: &Gd<T>) -> NodePath
+    where
+        T: GodotClass + Inherits<Node>;
+
+    /// Returns the position of this node among the children of its parent, or `None` if it has no parent.
+    ///
+    /// Unlike [`Node::get_index()`], internal children are counted as well, so that every child has a valid index. Without
+    /// internal siblings, both return the same value.
+    #[doc(alias = "get_index")]
+    fn sibling_index(&self) -> Option<usize>;
+
+    /// Returns the sibling right after this node, or `None` if this node is the last child or has no parent.
+    fn next_sibling(&self) -> Option<Gd<Node>>;
+
+    /// Returns the sibling right before this node, or `None` if this node is the first child or has no parent.
+    fn prev_sibling(&self) -> Option<Gd<Node>>;
 }
 
 impl NodeExt for Node {
@@ -98,6 +165,96 @@ impl NodeExt for Node {
         self.get_node_or_null(path)
             .and_then(|node| node.try_cast::<T>())
     }
+
+    fn find_child_typed<T>(
+        &self,
+        pattern: impl Into<GodotString>,
+        recursive: bool,
+        owned: bool,
+    ) -> Option<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        self.find_child_ex(pattern.into())
+            .recursive(recursive)
+            .owned(owned)
+            .done()
+            .and_then(|node| node.try_cast::<T>())
+    }
+
+    fn set_node_name(&mut self, name: impl Into<StringName>) {
+        self.set_name(GodotString::from(name.into()));
+    }
+
+    fn add_child_at<C>(&mut self, child: Gd<C>, index: usize)
+    where
+        C: GodotClass + Inherits<Node>,
+    {
+        use crate::obj::Share;
+
+        let child = child.upcast::<Node>();
+        self.add_child(child.share());
+
+        let last = self.get_child_count() as usize - 1;
+        self.move_child(child, index.min(last) as _);
+    }
+
+    fn move_child_below<C, S>(&mut self, child: Gd<C>, sibling: &Gd<S>)
+    where
+        C: GodotClass + Inherits<Node>,
+        S: GodotClass + Inherits<Node>,
+    {
+        use crate::obj::Share;
+
+        let child = child.upcast::<Node>();
+        let sibling = sibling.share().upcast::<Node>();
+
+        let own_id = self.get_instance_id();
+        for node in [&child, &sibling] {
+            let is_child = node
+                .get_parent()
+                .map_or(false, |parent| parent.get_instance_id() == own_id);
+            assert!(
+                is_child,
+                "move_child_below(): `{node}` is not a child of this node"
+            );
+        }
+
+        // Moving the child out from before the sibling shifts the sibling up by one.
+        let child_index = child.get_index() as usize;
+        let sibling_index = sibling.get_index() as usize;
+        let target = if child_index < sibling_index {
+            sibling_index
+        } else {
+            sibling_index + 1
+        };
+
+        self.move_child(child, target as _);
+    }
+
+    fn path_to<T>(&self, node: &Gd<T>) -> NodePath
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        use crate::obj::Share;
+
+        self.get_path_to(node.share().upcast())
+    }
+
+    fn sibling_index(&self) -> Option<usize> {
+        self.get_parent()?;
+
+        let index = self.get_index_ex().include_internal(true).done();
+        Some(index as usize)
+    }
+
+    fn next_sibling(&self) -> Option<Gd<Node>> {
+        sibling_at_offset(self, 1)
+    }
+
+    fn prev_sibling(&self) -> Option<Gd<Node>> {
+        sibling_at_offset(self, -1)
+    }
 }
 
 impl<U> NodeExt for Gd<U>
@@ -119,6 +276,784 @@ where
 
         <Node as NodeExt>::try_get_node_as(&*node, path)
     }
+
+    fn find_child_typed<T>(
+        &self,
+        pattern: impl Into<GodotString>,
+        recursive: bool,
+        owned: bool,
+    ) -> Option<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        use crate::obj::Share;
+
+        let node = self.share().upcast::<Node>();
+
+        <Node as NodeExt>::find_child_typed(&*node, pattern, recursive, owned)
+    }
+
+    fn set_node_name(&mut self, name: impl Into<StringName>) {
+        use crate::obj::Share;
+
+        let mut node = self.share().upcast::<Node>();
+
+        <Node as NodeExt>::set_node_name(&mut *node, name)
+    }
+
+    fn add_child_at<C>(&mut self, child: Gd<C>, index: usize)
+    where
+        C: GodotClass + Inherits<Node>,
+    {
+        use crate::obj::Share;
+
+        let mut node = self.share().upcast::<Node>();
+
+        <Node as NodeExt>::add_child_at(&mut *node, child, index)
+    }
+
+    fn move_child_below<C, S>(&mut self, child: Gd<C>, sibling: &Gd<S>)
+    where
+        C: GodotClass + Inherits<Node>,
+        S: GodotClass + Inherits<Node>,
+    {
+        use crate::obj::Share;
+
+        let mut node = self.share().upcast::<Node>();
+
+        <Node as NodeExt>::move_child_below(&mut *node, child, sibling)
+    }
+
+    fn path_to<T>(&self, node: &Gd<T>) -> NodePath
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        use crate::obj::Share;
+
+        let this = self.share().upcast::<Node>();
+
+        <Node as NodeExt>::path_to(&*this, node)
+    }
+
+    fn sibling_index(&self) -> Option<usize> {
+        use crate::obj::Share;
+
+        let node = self.share().upcast::<Node>();
+        <Node as NodeExt>::sibling_index(&*node)
+    }
+
+    fn next_sibling(&self) -> Option<Gd<Node>> {
+        use crate::obj::Share;
+
+        let node = self.share().upcast::<Node>();
+        <Node as NodeExt>::next_sibling(&*node)
+    }
+
+    fn prev_sibling(&self) -> Option<Gd<Node>> {
+        use crate::obj::Share;
+
+        let node = self.share().upcast::<Node>();
+        <Node as NodeExt>::prev_sibling(&*node)
+    }
+}
+
+/// Returns the child of `node`'s parent at `offset` positions from `node`, counting internal children.
+fn sibling_at_offset(node: &Node, offset: isize) -> Option<Gd<Node>> {
+    let parent = node.get_parent()?;
+
+    let index = node.get_index_ex().include_internal(true).done() as isize + offset;
+    let count = parent.get_child_count_ex().include_internal(true).done() as isize;
+    if index < 0 || index >= count {
+        return None;
+    }
+
+    parent
+        .get_child_ex(index as _)
+        .include_internal(true)
+        .done()
+}
+
+/// Memoizes relative paths between pairs of nodes, as computed by [`NodeExt::path_to()`].
+///
+/// Entries are keyed by the instance IDs of both nodes. The cache does not observe the scene tree: once nodes are moved,
+/// renamed or freed, cached paths may be outdated. Call [`clear()`][Self::clear] in that case, e.g. from a `#[func]`
+/// connected to the `SceneTree::tree_changed` signal, or whenever the structure of the relevant subtree changes.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::NodePathCache;
+///
+/// fn paths_to_target(cache: &mut NodePathCache, nodes: &[Gd<Node>], target: &Gd<Node>) -> Vec<NodePath> {
+///     nodes.iter().map(|node| cache.path_to(node, target)).collect()
+/// }
+/// ```
+#[derive(Default, Debug)]
+pub struct NodePathCache {
+    paths: std::collections::HashMap<(InstanceId, InstanceId), NodePath>,
+}
+
+impl NodePathCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the path from `from` to `to`, computing it only if it is not yet cached.
+    ///
+    /// Paths are not cached if they are empty, i.e. if Godot could not compute them because the nodes are in different trees.
+    pub fn path_to<F, T>(&mut self, from: &Gd<F>, to: &Gd<T>) -> NodePath
+    where
+        F: GodotClass + Inherits<Node>,
+        T: GodotClass + Inherits<Node>,
+    {
+        use crate::obj::Share;
+
+        let key = (from.instance_id(), to.instance_id());
+        if let Some(path) = self.paths.get(&key) {
+            return path.clone();
+        }
+
+        let path = from.share().upcast::<Node>().path_to(to);
+        if !path.as_inner().is_empty() {
+            self.paths.insert(key, path.clone());
+        }
+        path
+    }
+
+    /// Removes all cached paths which start or end at the node with ID `id`.
+    ///
+    /// Useful if only a single node was renamed, moved or freed.
+    pub fn invalidate(&mut self, id: InstanceId) {
+        self.paths.retain(|&(from, to), _| from != id && to != id);
+    }
+
+    /// Removes all cached paths.
+    pub fn clear(&mut self) {
+        self.paths.clear();
+    }
+
+    /// Returns the number of cached paths.
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Returns `true` if no paths are cached.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+}
+
+/// Extension trait for convenience functions on `Object`.
+pub trait ObjectExt {
+    /// Calls the method `method` dynamically like [`Object::call()`], but returns an error instead of panicking.
+    ///
+    /// Failures include a panic in a Rust callee, a method that does not exist, or arguments of the wrong type or count.
+    /// The panic is caught with the same mechanism that is used at all other Rust/Godot boundaries, so it never unwinds
+    /// across FFI. Like there, the error is also printed to the Godot console.
+    ///
+    /// This is useful to probe arbitrary methods, e.g. in tooling, where a single faulty method should not bring down the
+    /// whole process.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    /// use godot::engine::ObjectExt;
+    ///
+    /// let mut obj = Object::new_alloc();
+    /// match obj.call_safe("get_class", &[]) {
+    ///     Ok(class) => godot_print!("class: {class}"),
+    ///     Err(e) => godot_error!("{e}"),
+    /// }
+    /// obj.free();
+    /// ```
+    fn call_safe(
+        &mut self,
+        method: impl Into<StringName>,
+        args: &[Variant],
+    ) -> Result<Variant, CallError>;
+}
+
+impl ObjectExt for Object {
+    fn call_safe(
+        &mut self,
+        method: impl Into<StringName>,
+        args: &[Variant],
+    ) -> Result<Variant, CallError> {
+        let method = method.into();
+        let method_name = method.to_string();
+
+        let result = crate::private::handle_panic(
+            || format!("call_safe() of method `{method_name}`"),
+            std::panic::AssertUnwindSafe(|| self.call(method, args)),
+        );
+
+        result.ok_or(CallError { method_name })
+    }
+}
+
+/// Error returned by [`ObjectExt::call_safe()`] when the call failed.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct CallError {
+    method_name: String,
+}
+
+impl CallError {
+    /// Name of the method that failed to be called.
+    pub fn method_name(&self) -> &str {
+        &self.method_name
+    }
+}
+
+impl std::fmt::Display for CallError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "call to method `{}` failed", self.method_name)
+    }
+}
+
+impl std::error::Error for CallError {}
+
+/// Error returned by [`Gd::try_set_script()`] when the script cannot be attached to the object.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ScriptError {
+    script_base_type: String,
+    object_class: String,
+}
+
+impl ScriptError {
+    pub(crate) fn new(script_base_type: String, object_class: String) -> Self {
+        Self {
+            script_base_type,
+            object_class,
+        }
+    }
+
+    /// Native class which the script extends, e.g. `Node2D`.
+    pub fn script_base_type(&self) -> &str {
+        &self.script_base_type
+    }
+
+    /// Class of the object to which the script was attached.
+    pub fn object_class(&self) -> &str {
+        &self.object_class
+    }
+}
+
+impl std::fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "script extends `{}`, so it cannot be attached to an object of class `{}`",
+            self.script_base_type, self.object_class
+        )
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+/// Error returned by [`Gd::replace_in_tree()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ReplaceError {
+    /// The node to be replaced has no parent.
+    NotInTree,
+
+    /// The replacement is already the child of another node.
+    ReplacementHasParent,
+}
+
+impl std::fmt::Display for ReplaceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotInTree => write!(f, "node to be replaced has no parent"),
+            Self::ReplacementHasParent => write!(f, "replacement node already has a parent"),
+        }
+    }
+}
+
+impl std::error::Error for ReplaceError {}
+
+/// Extension trait for convenience functions on `Engine`.
+pub trait EngineExt {
+    /// Fetches the singleton registered under `name` and casts it to `T` (fallible).
+    ///
+    /// Returns `None` if no singleton with that name is registered -- e.g. because it belongs to an extension or
+    /// module that is not initialized yet at the current init level -- or if it is not of type `T` or inherited.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    /// use godot::engine::{Engine, EngineExt, Os};
+    ///
+    /// let os = Engine::singleton().get_singleton_typed::<Os>("OS");
+    /// ```
+    fn get_singleton_typed<T>(&self, name: impl Into<StringName>) -> Option<Gd<T>>
+    where
+        T: GodotClass + Inherits<Object>;
+}
+
+impl EngineExt for Engine {
+    fn get_singleton_typed<T>(&self, name: impl Into<StringName>) -> Option<Gd<T>>
+    where
+        T: GodotClass + Inherits<Object>,
+    {
+        let name = name.into();
+
+        // Check first, as get_singleton() prints an error for unknown names.
+        if !self.has_singleton(name.clone()) {
+            return None;
+        }
+
+        self.get_singleton(name).and_then(|obj| obj.try_cast::<T>())
+    }
+}
+
+/// Timing of the current physics frame: the `delta` passed to `physics_process()`, together with the frame index.
+///
+/// Bundles what is typically needed in simulation code. Obtain it with [`PhysicsFrame::from_delta()`] at the start of
+/// `physics_process()` and pass it on by value.
+///
+/// The frame index is tracked by observing the `SceneTree`'s `physics_frame` and `process_frame` signals, so the engine is
+/// queried once per physics frame, no matter how many nodes call `from_delta()`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::PhysicsFrame;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Simulation {
+///     last_frame: u64,
+/// }
+///
+/// #[godot_api]
+/// impl NodeVirtual for Simulation {
+///     fn physics_process(&mut self, delta: f64) {
+///         let frame = PhysicsFrame::from_delta(delta).expect("called in physics frame");
+///         self.last_frame = frame.index;
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PhysicsFrame {
+    /// Time elapsed since the previous physics frame, in seconds.
+    pub delta: f64,
+
+    /// Number of physics frames since the engine started, including the current one (`Engine::get_physics_frames()`).
+    pub index: u64,
+}
+
+impl PhysicsFrame {
+    /// Captures the current physics frame, with `delta` as received in `physics_process()`.
+    ///
+    /// Returns `None` outside of physics processing -- e.g. in `process()`, `ready()` or signal handlers called from
+    /// idle code -- as there is no current physics frame whose `delta` could be meant.
+    pub fn from_delta(delta: f64) -> Option<Self> {
+        let index = match PHYSICS_FRAME.with(Cell::get) {
+            TrackedFrame::Physics(index) => index,
+            TrackedFrame::Idle => return None,
+
+            // First call, or no scene tree to observe: ask the engine directly.
+            TrackedFrame::Untracked => {
+                let engine = Engine::singleton();
+                let index = engine
+                    .is_in_physics_frame()
+                    .then(|| engine.get_physics_frames());
+
+                track_physics_frames(index);
+                index?
+            }
+        };
+
+        Some(Self { delta, index })
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum TrackedFrame {
+    Untracked,
+    Idle,
+    Physics(u64),
+}
+
+thread_local! {
+    static PHYSICS_FRAME: Cell<TrackedFrame> = Cell::new(TrackedFrame::Untracked);
+}
+
+/// Starts tracking physics frames, with `index` being the physics frame in progress (if any).
+///
+/// The relays are internal children of the root window, so tracking stops when the scene tree is destroyed.
+fn track_physics_frames(index: Option<u64>) {
+    let Some(mut tree) = Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>())
+    else {
+        return;
+    };
+    let Some(root) = tree.get_root() else {
+        return;
+    };
+
+    // Resets the state when the relay's closure is dropped, i.e. the relay is freed.
+    struct UntrackOnDrop;
+    impl Drop for UntrackOnDrop {
+        fn drop(&mut self) {
+            // Thread-local storage may already be gone during shutdown.
+            let _ = PHYSICS_FRAME.try_with(|frame| frame.set(TrackedFrame::Untracked));
+        }
+    }
+
+    let untrack = UntrackOnDrop;
+    let on_physics = closure_relay::create_relay(move |_args: &[&Variant]| {
+        let _ = &untrack;
+        let index = Engine::singleton().get_physics_frames();
+        PHYSICS_FRAME.with(|frame| frame.set(TrackedFrame::Physics(index)));
+    });
+    let on_process = closure_relay::create_relay(|_args: &[&Variant]| {
+        PHYSICS_FRAME.with(|frame| frame.set(TrackedFrame::Idle));
+    });
+
+    tree.connect("physics_frame".into(), closure_relay::callable(&on_physics));
+    tree.connect("process_frame".into(), closure_relay::callable(&on_process));
+
+    let mut root = root.upcast::<Node>();
+    for relay in [on_physics, on_process] {
+        root.add_child_ex(relay)
+            .internal(node::InternalMode::INTERNAL_MODE_BACK)
+            .done();
+    }
+
+    let state = index.map_or(TrackedFrame::Idle, TrackedFrame::Physics);
+    PHYSICS_FRAME.with(|frame| frame.set(state));
+}
+
+/// Extension trait for convenience functions on `SceneTree`.
+pub trait SceneTreeExt {
+    /// Calls `method` on every node in `group` that is of class `T` (or inherited), like [`SceneTree::call_group()`].
+    ///
+    /// Unlike the engine method, nodes which don't have a method named `method` are skipped instead of causing an error,
+    /// and so are nodes that are not a `T`. Use `T = Node` to include all nodes of the group. The calls happen immediately.
+    ///
+    /// Returns the number of nodes on which the method was called.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    /// use godot::engine::{Node2D, SceneTreeExt};
+    ///
+    /// fn hide_enemies(tree: &mut SceneTree) {
+    ///     let count = tree.call_group_typed::<Node2D>("enemies", "hide", &[]);
+    ///     godot_print!("hid {count} enemies");
+    /// }
+    /// ```
+    fn call_group_typed<T>(
+        &mut self,
+        group: impl Into<StringName>,
+        method: impl Into<StringName>,
+        args: &[Variant],
+    ) -> usize
+    where
+        T: GodotClass + Inherits<Node>;
+}
+
+impl SceneTreeExt for SceneTree {
+    fn call_group_typed<T>(
+        &mut self,
+        group: impl Into<StringName>,
+        method: impl Into<StringName>,
+        args: &[Variant],
+    ) -> usize
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        let method = method.into();
+        let mut count = 0;
+
+        // Iterates over a snapshot of the group. Callees may free nodes that come later in it, so each element is converted
+        // only when it is reached; get_object() returns `None` for freed nodes as well as for nodes of other classes.
+        let nodes = self.get_nodes_in_group(group.into());
+        for i in 0..nodes.len() {
+            let Some(node) = nodes.get_object::<T>(i) else {
+                continue;
+            };
+
+            let mut node = node.upcast::<Node>();
+            if node.has_method(method.clone()) {
+                node.call(method.clone(), args);
+                count += 1;
+            }
+        }
+
+        count
+    }
+}
+
+/// Observes nodes entering and leaving a `SceneTree`, until dropped.
+///
+/// Connects the tree's `node_added` and `node_removed` signals to Rust closures, which receive the affected node. When the
+/// observer is dropped, both connections are removed again. Must be used on the main thread.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::TreeObserver;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct SceneTracker {
+///     observer: Option<TreeObserver>,
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl SceneTracker {
+///     #[func]
+///     fn start(&mut self) {
+///         let tree = self.base.get_tree().unwrap();
+///         self.observer = Some(TreeObserver::new(
+///             tree,
+///             |node| godot_print!("added: {}", node.get_name()),
+///             |node| godot_print!("removed: {}", node.get_name()),
+///         ));
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TreeObserver {
+    // Only kept for their destructors.
+    _on_added: OwnedRelay,
+    _on_removed: OwnedRelay,
+}
+
+impl TreeObserver {
+    /// Calls `on_added(node)` and `on_removed(node)` whenever the tree emits `node_added` and `node_removed`, respectively.
+    pub fn new<A, R>(mut tree: Gd<SceneTree>, on_added: A, on_removed: R) -> Self
+    where
+        A: FnMut(Gd<Node>) + 'static,
+        R: FnMut(Gd<Node>) + 'static,
+    {
+        let on_added = OwnedRelay::new(node_closure(on_added));
+        let on_removed = OwnedRelay::new(node_closure(on_removed));
+
+        tree.connect("node_added".into(), on_added.callable());
+        tree.connect("node_removed".into(), on_removed.callable());
+
+        // Dropping the relays frees them, which disconnects them from the tree (if it still exists).
+        Self {
+            _on_added: on_added,
+            _on_removed: on_removed,
+        }
+    }
+}
+
+/// Adapts a closure taking a node to the relay's argument list, which consists of just that node.
+fn node_closure(mut closure: impl FnMut(Gd<Node>) + 'static) -> impl FnMut(&[&Variant]) + 'static {
+    move |args| {
+        if let Some(node) = args.first().and_then(|arg| arg.try_to::<Gd<Node>>().ok()) {
+            closure(node)
+        }
+    }
+}
+
+/// Connection of a Rust closure to a signal, which is removed when the guard is dropped.
+///
+/// Returned by [`Gd::on_tree_exiting()`] and [`Gd::on_tree_exited()`]. If the connection has already been removed, e.g.
+/// because a one-shot signal was emitted or the object was freed, dropping the guard only drops the closure.
+#[derive(Debug)]
+pub struct ConnectionGuard {
+    object: Gd<Object>,
+    signal: StringName,
+
+    /// Holds the closure; dropping it also removes the connection.
+    relay: OwnedRelay,
+}
+
+impl ConnectionGuard {
+    pub(crate) fn connect_one_shot<F>(mut object: Gd<Object>, signal: &str, closure: F) -> Self
+    where
+        F: FnOnce() + 'static,
+    {
+        let signal = StringName::from(signal);
+        let relay = OwnedRelay::new_once(move |_args: &[&Variant]| closure());
+        let flags = object::ConnectFlags::CONNECT_ONE_SHOT.ord() as u32;
+
+        object
+            .connect_ex(signal.clone(), relay.callable())
+            .flags(flags)
+            .done();
+
+        Self {
+            object,
+            signal,
+            relay,
+        }
+    }
+
+    /// Returns `true` if the closure is still connected, i.e. the signal has not yet been handled.
+    pub fn is_connected(&self) -> bool {
+        self.object.is_instance_valid()
+            && self
+                .object
+                .is_connected(self.signal.clone(), self.relay.callable())
+    }
+}
+
+/// Common subtypes of `InputEvent`, for exhaustive handling in a `match`.
+///
+/// Each variant holds the event, downcast to its dynamic class. Subtypes without a dedicated variant are
+/// returned as [`Other`][Self::Other].
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{InputEvent, InputEventKind};
+///
+/// fn handle_input(event: Gd<InputEvent>) {
+///     match InputEventKind::from_event(event) {
+///         InputEventKind::Key(key) => godot_print!("key {:?}", key.get_keycode()),
+///         InputEventKind::MouseButton(button) => godot_print!("click at {}", button.get_position()),
+///         _ => {}
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub enum InputEventKind {
+    Key(Gd<InputEventKey>),
+    MouseButton(Gd<InputEventMouseButton>),
+    MouseMotion(Gd<InputEventMouseMotion>),
+    JoypadButton(Gd<InputEventJoypadButton>),
+    JoypadMotion(Gd<InputEventJoypadMotion>),
+    ScreenTouch(Gd<InputEventScreenTouch>),
+    ScreenDrag(Gd<InputEventScreenDrag>),
+    Action(Gd<InputEventAction>),
+    MagnifyGesture(Gd<InputEventMagnifyGesture>),
+    PanGesture(Gd<InputEventPanGesture>),
+    Midi(Gd<InputEventMidi>),
+
+    /// Any other subtype, e.g. `InputEventShortcut` or a custom class.
+    Other(Gd<InputEvent>),
+}
+
+impl InputEventKind {
+    /// Downcasts `event` to its dynamic class and wraps it in the corresponding variant.
+    pub fn from_event(event: Gd<InputEvent>) -> Self {
+        // Each attempt hands the event back on failure, so no reference count is lost in between.
+        let event = match event.owned_cast::<InputEventKey>() {
+            Ok(key) => return Self::Key(key),
+            Err(event) => event,
+        };
+        let event = match event.owned_cast::<InputEventMouseButton>() {
+            Ok(button) => return Self::MouseButton(button),
+            Err(event) => event,
+        };
+        let event = match event.owned_cast::<InputEventMouseMotion>() {
+            Ok(motion) => return Self::MouseMotion(motion),
+            Err(event) => event,
+        };
+        let event = match event.owned_cast::<InputEventJoypadButton>() {
+            Ok(button) => return Self::JoypadButton(button),
+            Err(event) => event,
+        };
+        let event = match event.owned_cast::<InputEventJoypadMotion>() {
+            Ok(motion) => return Self::JoypadMotion(motion),
+            Err(event) => event,
+        };
+        let event = match event.owned_cast::<InputEventScreenTouch>() {
+            Ok(touch) => return Self::ScreenTouch(touch),
+            Err(event) => event,
+        };
+        let event = match event.owned_cast::<InputEventScreenDrag>() {
+            Ok(drag) => return Self::ScreenDrag(drag),
+            Err(event) => event,
+        };
+        let event = match event.owned_cast::<InputEventAction>() {
+            Ok(action) => return Self::Action(action),
+            Err(event) => event,
+        };
+        let event = match event.owned_cast::<InputEventMagnifyGesture>() {
+            Ok(gesture) => return Self::MagnifyGesture(gesture),
+            Err(event) => event,
+        };
+        let event = match event.owned_cast::<InputEventPanGesture>() {
+            Ok(gesture) => return Self::PanGesture(gesture),
+            Err(event) => event,
+        };
+        match event.owned_cast::<InputEventMidi>() {
+            Ok(midi) => Self::Midi(midi),
+            Err(event) => Self::Other(event),
+        }
+    }
+}
+
+impl From<Gd<InputEvent>> for InputEventKind {
+    fn from(event: Gd<InputEvent>) -> Self {
+        Self::from_event(event)
+    }
+}
+
+/// Input action, identified by a Rust value instead of its name.
+///
+/// Typically derived with `#[derive(InputAction)]` on an enum with unit variants, which maps each variant to the `snake_case`
+/// version of its name. A different name can be specified with `#[action(name = "...")]`. The actions are then queried
+/// through [`InputExt`].
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::Input;
+///
+/// #[derive(InputAction, Copy, Clone)]
+/// enum PlayerAction {
+///     Jump,      // "jump"
+///     MoveLeft,  // "move_left"
+///     #[action(name = "ui_cancel")]
+///     Pause,
+/// }
+///
+/// fn wants_jump() -> bool {
+///     Input::singleton().is_action_pressed_typed(PlayerAction::Jump)
+/// }
+/// ```
+pub trait InputAction {
+    /// Name of the action in the project's input map.
+    fn action_name(&self) -> StringName;
+}
+
+/// Extension trait to query [`InputAction`]s on the `Input` singleton.
+///
+/// Actions that are not registered in the input map (_Project Settings -> Input Map_) count as not pressed, with strength 0.
+/// In debug builds, a warning is printed in that case.
+pub trait InputExt {
+    /// Like `is_action_pressed()`, for a typed action.
+    fn is_action_pressed_typed(&self, action: impl InputAction) -> bool;
+
+    /// Like `is_action_just_pressed()`, for a typed action.
+    fn is_action_just_pressed_typed(&self, action: impl InputAction) -> bool;
+
+    /// Like `is_action_just_released()`, for a typed action.
+    fn is_action_just_released_typed(&self, action: impl InputAction) -> bool;
+
+    /// Like `get_action_strength()`, for a typed action.
+    fn get_action_strength_typed(&self, action: impl InputAction) -> f32;
+}
+
+impl InputExt for Input {
+    fn is_action_pressed_typed(&self, action: impl InputAction) -> bool {
+        registered_action_name(&action).map_or(false, |name| self.is_action_pressed(name))
+    }
+
+    fn is_action_just_pressed_typed(&self, action: impl InputAction) -> bool {
+        registered_action_name(&action).map_or(false, |name| self.is_action_just_pressed(name))
+    }
+
+    fn is_action_just_released_typed(&self, action: impl InputAction) -> bool {
+        registered_action_name(&action).map_or(false, |name| self.is_action_just_released(name))
+    }
+
+    fn get_action_strength_typed(&self, action: impl InputAction) -> f32 {
+        registered_action_name(&action).map_or(0.0, |name| self.get_action_strength(name))
+    }
 }
 
 /// Loads a resource from the filesystem located at `path`, panicking on error.
@@ -152,7 +1087,7 @@ where
 /// If the resource cannot be loaded, or is not of type `T` or inherited, this method returns `None`.
 ///
 /// This method is a simplified version of [`ResourceLoader::load()`][crate::engine::ResourceLoader::load],
-/// which can be used for more advanced scenarios.
+/// which can be used for more advanced scenarios. To load without blocking, use [`task::load_async()`][crate::task::load_async].
 ///
 /// # Note:
 /// Resource paths can be obtained by right-clicking on a resource in the Godot editor (_FileSystem_ dock) and choosing "Copy Path",
@@ -182,6 +1117,69 @@ where
     load_impl(&path.into())
 }
 
+/// Returns a multi-line description of `node` and its descendants, similar to [`Node::print_tree_pretty()`].
+///
+/// Each node is described by one line with its name and class, indented by two spaces per level. For Rust classes implementing
+/// [`DebugSummary`], the summary is appended after a colon. This makes it easy to include the Rust state in the dump.
+///
+/// Descendants deeper than `max_depth` levels below `node` are not listed individually; instead, a line with the number
+/// of omitted children is added. `max_depth = 0` only describes `node` itself.
+///
+/// # Panics
+/// If a node in the subtree implements `DebugSummary` and is currently bound mutably, e.g. when calling this from one of
+/// its `&mut self` methods.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::debug_dump;
+///
+/// fn log_scene(root: &Gd<Node>) {
+///     godot_print!("{}", debug_dump(root, 8));
+/// }
+/// ```
+pub fn debug_dump<T>(node: &Gd<T>, max_depth: usize) -> GodotString
+where
+    T: GodotClass + Inherits<Node>,
+{
+    use crate::obj::Share;
+
+    let mut out = String::new();
+    dump_node(&node.share().upcast(), 0, max_depth, &mut out);
+    out.pop(); // Trailing newline.
+
+    GodotString::from(out)
+}
+
+/// Short description of a Rust object's state, shown by [`debug_dump()`].
+///
+/// Besides implementing the trait, the class must be registered for it with [`register_dyn!`][crate::obj::register_dyn].
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::DebugSummary;
+/// use godot::obj::register_dyn;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     health: i32,
+/// }
+///
+/// impl DebugSummary for Player {
+///     fn debug_summary(&self) -> String {
+///         format!("health={}", self.health)
+///     }
+/// }
+///
+/// register_dyn!(Player: dyn DebugSummary);
+/// ```
+pub trait DebugSummary {
+    /// Returns a single line describing the object, e.g. the values of its most important fields.
+    fn debug_summary(&self) -> String;
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Utilities for crate
 
@@ -211,6 +1209,47 @@ pub(crate) fn display_string<T: GodotClass>(
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Implementation of this file
 
+/// Returns the action's name if it is registered in the input map; otherwise warns (in debug builds) and returns `None`.
+fn registered_action_name(action: &impl InputAction) -> Option<StringName> {
+    let name = action.action_name();
+    if InputMap::singleton().has_action(name.clone()) {
+        return Some(name);
+    }
+
+    if cfg!(debug_assertions) {
+        crate::log::godot_warn!("input action `{name}` is not registered in the input map");
+    }
+    None
+}
+
+fn dump_node(node: &Gd<Node>, depth: usize, max_depth: usize, out: &mut String) {
+    use std::fmt::Write;
+
+    let indent = "  ".repeat(depth);
+    let name = node.get_name();
+    let class = node.get_class();
+    write!(out, "{indent}{name} ({class})").unwrap();
+
+    if let Some(summary) = node.with_dyn(|d: &dyn DebugSummary| d.debug_summary()) {
+        write!(out, ": {summary}").unwrap();
+    }
+    out.push('\n');
+
+    let child_count = node.get_child_count();
+    if depth >= max_depth {
+        if child_count > 0 {
+            writeln!(out, "{indent}  ... ({child_count} children omitted)").unwrap();
+        }
+        return;
+    }
+
+    for i in 0..child_count {
+        if let Some(child) = node.get_child(i) {
+            dump_node(&child, depth + 1, max_depth, out);
+        }
+    }
+}
+
 // Separate function, to avoid constructing string twice
 // Note that more optimizations than that likely make no sense, as loading is quite expensive
 fn load_impl<T>(path: &GodotString) -> Option<Gd<T>>
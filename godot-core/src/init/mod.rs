@@ -30,6 +30,9 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
 
         sys::initialize(interface_or_get_proc_address, library, config);
 
+        #[cfg(feature = "gd-drop-trace")]
+        crate::obj::drop_trace::set_enabled(true);
+
         let mut handle = InitHandle::new();
 
         let success = E::load_library(&mut handle);
@@ -66,8 +69,13 @@ unsafe extern "C" fn ffi_initialize_layer(
     };
 
     crate::private::handle_panic(ctx, || {
+        let level = InitLevel::from_sys(init_level);
+        if level == InitLevel::Scene {
+            ensure_precision_matches_engine();
+        }
+
         let handle = INIT_HANDLE.as_mut().unwrap();
-        handle.run_init_function(InitLevel::from_sys(init_level));
+        handle.run_init_function(level);
     });
 }
 
@@ -83,11 +91,58 @@ unsafe extern "C" fn ffi_deinitialize_layer(
     };
 
     crate::private::handle_panic(ctx, || {
+        let level = InitLevel::from_sys(init_level);
+        if level == InitLevel::Scene {
+            // Pending futures and cached variants may hold objects, which must be released while the engine is still alive.
+            crate::task::cancel_all_tasks();
+            crate::registry::clear_property_defaults();
+
+            // Before user deinit, so singletons can still access everything they used during their lifetime.
+            crate::registry::unregister_singletons();
+        }
+
         let handle = INIT_HANDLE.as_mut().unwrap();
-        handle.run_deinit_function(InitLevel::from_sys(init_level));
+        handle.run_deinit_function(level);
+
+        // After user deinit, which may still free instances of dynamic classes.
+        if level == InitLevel::Scene {
+            crate::registry::unregister_dynamic_classes();
+        }
+
+        // Last deinit callback; afterwards, Godot can no longer print drop traces.
+        #[cfg(feature = "gd-drop-trace")]
+        if level == handle.lowest_init_level() {
+            crate::obj::drop_trace::set_enabled(false);
+        }
     });
 }
 
+/// Panics if the engine was built with a different floating-point precision than gdext.
+///
+/// The size of [`real`][type@crate::builtin::real] is fixed at compile time through the `double-precision` feature. A Godot binary
+/// built with `precision=double` expects 64-bit math types (or 32-bit ones otherwise), so proceeding with a mismatch would be UB.
+fn ensure_precision_matches_engine() {
+    let engine_double = crate::engine::Os::singleton().has_feature("double".into());
+    let gdext_double = cfg!(feature = "double-precision");
+
+    if engine_double != gdext_double {
+        let describe = |double: bool| {
+            if double {
+                "double (64-bit)"
+            } else {
+                "single (32-bit)"
+            }
+        };
+
+        panic!(
+            "Godot engine uses {} precision for `real`, but gdext was compiled with {} precision.\n\
+            Toggle the `double-precision` feature of the `godot` crate to match the engine build.",
+            describe(engine_double),
+            describe(gdext_double),
+        );
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 // FIXME make safe
@@ -13,6 +13,10 @@ pub mod init;
 pub mod log;
 pub mod obj;
 pub mod property;
+pub mod task;
+
+#[cfg(feature = "signal-stats")]
+pub mod profiling;
 
 pub use godot_ffi as sys;
 #[doc(hidden)]
@@ -51,6 +55,7 @@ pub mod private {
     use std::sync::{Arc, Mutex};
 
     pub use crate::gen::classes::class_macros;
+    pub use crate::obj::dyn_trait::{DynCaster, DynTraitPlugin};
     pub use crate::registry::{callbacks, ClassPlugin, ErasedRegisterFn, PluginComponent};
     pub use crate::storage::as_storage;
     pub use godot_ffi::out;
@@ -58,6 +63,7 @@ pub mod private {
     use crate::{log, sys};
 
     sys::plugin_registry!(pub __GODOT_PLUGIN_REGISTRY: ClassPlugin);
+    sys::plugin_registry!(pub __GODOT_DYN_TRAIT_REGISTRY: DynTraitPlugin);
 
     pub(crate) fn iterate_plugins(mut visitor: impl FnMut(&ClassPlugin)) {
         sys::plugin_foreach!(__GODOT_PLUGIN_REGISTRY; visitor);
@@ -72,12 +78,129 @@ pub mod private {
             return false;
         }
 
+        // SAFETY: only invoked after global library initialization.
+        let global_config = unsafe { sys::config() };
+
+        global_config.tool_only_in_editor && is_editor()
+    }
+
+    /// Whether the code runs in the editor (as opposed to the game), cached after the first call.
+    pub fn is_editor() -> bool {
         // SAFETY: only invoked after global library initialization.
         let global_config = unsafe { sys::config() };
         let is_editor = || crate::engine::Engine::singleton().is_editor_hint();
 
-        global_config.tool_only_in_editor //.
-            && *global_config.is_editor.get_or_init(is_editor)
+        *global_config.is_editor.get_or_init(is_editor)
+    }
+
+    /// Initializes the `OnReady` fields of the instance, right before its `ready()` is called.
+    ///
+    /// Does nothing if the class has no such fields, or is not a node (`OnReady` values are then never initialized).
+    pub fn before_ready<T: crate::obj::cap::GodotBeforeReady>(
+        storage: &crate::storage::InstanceStorage<T>,
+    ) {
+        if !T::HAS_ONREADY_FIELDS {
+            return;
+        }
+
+        let Ok(base) = storage.get_gd().owned_cast::<crate::engine::Node>() else {
+            return;
+        };
+
+        let mut instance = storage.get_mut();
+        instance.__before_ready(&base);
+    }
+
+    /// Used by `#[godot_api] impl XyVirtual for T` to verify at compile time that `Xy` is `T`'s base class or one of its ancestors.
+    ///
+    /// Otherwise, virtual methods would be registered but never invoked by Godot, as the base class doesn't declare them.
+    pub fn virtual_trait_matches_base<T, TraitClass>()
+    where
+        T: crate::obj::GodotClass,
+        TraitClass: crate::obj::GodotClass,
+        T::Base: crate::obj::Inherits<TraitClass>,
+    {
+    }
+
+    /// Completes the arguments of a varcall to a `#[func]` with `#[default]` parameters.
+    ///
+    /// Returns pointers to all `param_count` arguments, of which the ones not passed by the caller point into `defaults` (the
+    /// values of the trailing parameters). If too few or too many arguments were passed, `err` is set and `None` is returned.
+    ///
+    /// # Safety
+    /// `args` must point to `arg_count` valid variant pointers, and `err` must be valid for writes.
+    pub unsafe fn varcall_args_with_defaults(
+        args: *const sys::GDExtensionConstVariantPtr,
+        arg_count: sys::GDExtensionInt,
+        param_count: usize,
+        defaults: &[crate::builtin::Variant],
+        err: *mut sys::GDExtensionCallError,
+    ) -> Option<Vec<sys::GDExtensionConstVariantPtr>> {
+        let arg_count = arg_count as usize;
+        let required_count = param_count - defaults.len();
+
+        if arg_count < required_count {
+            (*err).error = sys::GDEXTENSION_CALL_ERROR_TOO_FEW_ARGUMENTS;
+            (*err).argument = required_count as i32;
+            return None;
+        }
+        if arg_count > param_count {
+            (*err).error = sys::GDEXTENSION_CALL_ERROR_TOO_MANY_ARGUMENTS;
+            (*err).argument = param_count as i32;
+            return None;
+        }
+
+        let mut all_args: Vec<_> = (0..arg_count).map(|i| *args.add(i)).collect();
+        all_args.extend(
+            defaults[arg_count - required_count..]
+                .iter()
+                .map(|value| value.var_sys_const()),
+        );
+        Some(all_args)
+    }
+
+    /// Implementation of `_iter_init` for `#[godot_api] impl ScriptIterable for T`.
+    ///
+    /// The iterator state, stored in `iter[0]`, is an array `[index, item]`; `_iter_get` then only needs to return the item.
+    pub fn iter_init<T: crate::obj::ScriptIterable>(
+        instance: &T,
+        iter: crate::builtin::VariantArray,
+    ) -> bool {
+        iter_advance(instance, iter, 0)
+    }
+
+    /// Implementation of `_iter_next` for `#[godot_api] impl ScriptIterable for T`.
+    pub fn iter_next<T: crate::obj::ScriptIterable>(
+        instance: &T,
+        iter: crate::builtin::VariantArray,
+    ) -> bool {
+        use crate::builtin::VariantArray;
+
+        let state = iter.get(0).to::<VariantArray>();
+        let index = state.get(0).to::<i64>() as usize;
+        iter_advance(instance, iter, index + 1)
+    }
+
+    /// Implementation of `_iter_get` for `#[godot_api] impl ScriptIterable for T`.
+    pub fn iter_get(state: crate::builtin::Variant) -> crate::builtin::Variant {
+        state.to::<crate::builtin::VariantArray>().get(1)
+    }
+
+    fn iter_advance<T: crate::obj::ScriptIterable>(
+        instance: &T,
+        mut iter: crate::builtin::VariantArray,
+        index: usize,
+    ) -> bool {
+        use crate::builtin::ToVariant;
+
+        match instance.item_at(index) {
+            Some(item) => {
+                let state = crate::builtin::varray![index as i64, item];
+                iter.set(0, state.to_variant());
+                true
+            }
+            None => false,
+        }
     }
 
     fn print_panic(err: Box<dyn std::any::Any + Send>) {
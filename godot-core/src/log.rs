@@ -16,9 +16,13 @@ macro_rules! inner_godot_msg {
 
             // Check whether engine is loaded, otherwise fall back to stderr.
             if $crate::sys::is_initialized() {
+                // Path of an item defined in the calling function starts with that function's path.
+                fn __here() {}
+                let function = format!("{}\0", $crate::log::function_name_of(__here));
+
                 $crate::sys::interface_fn!($godot_fn)(
                     $crate::sys::c_str_from_str(&msg),
-                    $crate::sys::c_str(b"<function unset>\0"),
+                    $crate::sys::c_str_from_str(&function),
                     $crate::sys::c_str_from_str(concat!(file!(), "\0")),
                     line!() as i32,
                     false as $crate::sys::GDExtensionBool, // whether to create a toast notification in editor
@@ -32,6 +36,8 @@ macro_rules! inner_godot_msg {
 
 /// Pushes a warning message to Godot's built-in debugger and to the OS terminal.
 ///
+/// The message is annotated with the calling function, file and line, which the debugger shows as its source.
+///
 /// _Godot equivalent: @GlobalScope.push_warning()_
 #[macro_export]
 macro_rules! godot_warn {
@@ -42,6 +48,8 @@ macro_rules! godot_warn {
 
 /// Pushes an error message to Godot's built-in debugger and to the OS terminal.
 ///
+/// The message is annotated with the calling function, file and line, which the debugger shows as its source.
+///
 /// _Godot equivalent: @GlobalScope.push_error()_
 #[macro_export]
 macro_rules! godot_error {
@@ -100,3 +108,65 @@ pub fn print(varargs: &[Variant]) {
     // TODO use generated method, but figure out how print() with zero args can be called
     // crate::engine::utilities::print(head, rest);
 }
+
+/// Returns the path of the function enclosing `item`, given a function item declared inside it.
+///
+/// Used by the `godot_warn!`, `godot_error!` and `godot_script_error!` macros, as Rust has no `function!()` counterpart
+/// to `file!()` and `line!()`. Closures inside the function are skipped, so the named function is reported.
+#[doc(hidden)]
+pub fn function_name_of<F>(_item: F) -> &'static str {
+    let path = std::any::type_name::<F>();
+    let mut path = path
+        .rsplit_once("::")
+        .map_or(path, |(parent, _item)| parent);
+
+    while let Some(parent) = path.strip_suffix("::{{closure}}") {
+        path = parent;
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::function_name_of;
+
+    struct Type;
+
+    impl Type {
+        fn method() -> &'static str {
+            fn here() {}
+            function_name_of(here)
+        }
+    }
+
+    fn free_function() -> &'static str {
+        fn here() {}
+        function_name_of(here)
+    }
+
+    fn nested_closures() -> &'static str {
+        let outer = || {
+            let inner = || {
+                fn here() {}
+                function_name_of(here)
+            };
+            inner()
+        };
+        outer()
+    }
+
+    #[test]
+    fn function_name_of_free_function() {
+        assert_eq!(free_function(), "godot_core::log::tests::free_function");
+    }
+
+    #[test]
+    fn function_name_of_method() {
+        assert_eq!(Type::method(), "godot_core::log::tests::Type::method");
+    }
+
+    #[test]
+    fn function_name_of_closure() {
+        assert_eq!(nested_closures(), "godot_core::log::tests::nested_closures");
+    }
+}
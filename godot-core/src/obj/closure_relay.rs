@@ -0,0 +1,220 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Rust closures as targets of signals and other `Callable`s.
+//!
+//! A `Callable` can currently only refer to a method of an object, not to a Rust closure. This module bridges the gap: each closure
+//! belongs to an instance of the internal class `GdextClosureRelay` (a `Node`, registered dynamically on first use), and is run by
+//! the relay's vararg method `call_closure`, which receives all arguments of the call. That method's callable can be connected to
+//! signals like any other.
+//!
+//! A closure is dropped when its relay is freed; Godot then also removes all connections to the relay. Relays are only used on the
+//! main thread, so closures need not be `Send`. While a closure runs, its relay ignores further (reentrant) calls.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use godot_ffi as sys;
+use sys::interface_fn;
+
+use crate::builtin::meta::registration::method::MethodInfo;
+use crate::builtin::meta::ClassName;
+use crate::builtin::{Callable, StringName, Variant};
+use crate::engine::global::MethodFlags;
+use crate::engine::notify::NodeNotification;
+use crate::engine::{utilities, Node};
+use crate::obj::{Gd, GodotClass, InstanceId};
+use crate::registry::{
+    register_class_dynamic, ClassRegistrationError, DynamicClassDescriptor, ErasedRegisterFn,
+};
+
+pub(crate) type RelayFn = Box<dyn FnMut(&[&Variant])>;
+
+const METHOD_NAME: &str = "call_closure";
+
+thread_local! {
+    // Closures per relay node.
+    static CLOSURES: RefCell<HashMap<InstanceId, RelayFn>> = RefCell::new(HashMap::new());
+}
+
+/// Creates a relay running `closure` whenever its [`callable()`] is called.
+///
+/// The relay is a manually managed node. It must either be freed (see [`OwnedRelay`]) or become the child of another node.
+pub(crate) fn create_relay<F>(closure: F) -> Gd<Node>
+where
+    F: FnMut(&[&Variant]) + 'static,
+{
+    ensure_registered();
+
+    // SAFETY: the class is registered, and its create function returns a valid Node.
+    let relay = unsafe {
+        let object_ptr = interface_fn!(classdb_construct_object)(relay_class_name().string_sys());
+        Gd::<Node>::from_obj_sys(object_ptr)
+    };
+
+    CLOSURES.with(|closures| {
+        closures
+            .borrow_mut()
+            .insert(relay.instance_id(), Box::new(closure))
+    });
+
+    relay
+}
+
+/// Creates a relay that runs `closure` only on the first call, and ignores later ones.
+pub(crate) fn create_relay_once<F>(closure: F) -> Gd<Node>
+where
+    F: FnOnce(&[&Variant]) + 'static,
+{
+    let mut closure = Some(closure);
+    create_relay(move |args| {
+        if let Some(closure) = closure.take() {
+            closure(args)
+        }
+    })
+}
+
+/// Callable running the closure of `relay`, with the arguments of the call.
+pub(crate) fn callable(relay: &Gd<Node>) -> Callable {
+    relay.callable(METHOD_NAME)
+}
+
+/// Relay that is freed when dropped, which drops its closure and disconnects it from all signals.
+#[derive(Debug)]
+pub(crate) struct OwnedRelay {
+    relay: Gd<Node>,
+}
+
+impl OwnedRelay {
+    pub(crate) fn new<F>(closure: F) -> Self
+    where
+        F: FnMut(&[&Variant]) + 'static,
+    {
+        Self {
+            relay: create_relay(closure),
+        }
+    }
+
+    pub(crate) fn new_once<F>(closure: F) -> Self
+    where
+        F: FnOnce(&[&Variant]) + 'static,
+    {
+        Self {
+            relay: create_relay_once(closure),
+        }
+    }
+
+    pub(crate) fn callable(&self) -> Callable {
+        callable(&self.relay)
+    }
+}
+
+impl Drop for OwnedRelay {
+    fn drop(&mut self) {
+        // May have been freed already, e.g. during shutdown.
+        if self.relay.is_instance_valid() {
+            self.relay.share().free();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn relay_class_name() -> ClassName {
+    ClassName::from_ascii_cstr(b"GdextClosureRelay\0")
+}
+
+fn ensure_registered() {
+    let class_name = relay_class_name();
+
+    let mut descriptor = DynamicClassDescriptor::new(class_name, Node::class_name());
+    descriptor.create_fn = Some(create);
+    descriptor.notification_fn = Some(notification);
+    descriptor.register_fn = Some(ErasedRegisterFn {
+        raw: register_methods,
+    });
+
+    match register_class_dynamic(descriptor) {
+        Ok(()) | Err(ClassRegistrationError::NameCollision(_)) => {}
+        Err(err) => panic!("failed to register {class_name}: {err}"),
+    }
+}
+
+fn register_methods(_class_builder: &mut dyn std::any::Any) {
+    // SAFETY: `call_closure` accepts any number of Variant arguments, in line with the vararg flag, and returns nil.
+    // There is no ptrcall function; Godot falls back to the varcall one.
+    let method_info = unsafe {
+        MethodInfo::from_signature::<((),)>(
+            relay_class_name(),
+            StringName::from(METHOD_NAME),
+            Some(call_closure),
+            None,
+            MethodFlags::METHOD_FLAGS_DEFAULT | MethodFlags::METHOD_FLAG_VARARG,
+            &[],
+            vec![],
+        )
+    };
+
+    method_info.register_extension_class_method();
+}
+
+unsafe extern "C" fn create(_class_userdata: *mut std::ffi::c_void) -> sys::GDExtensionObjectPtr {
+    let object_ptr = interface_fn!(classdb_construct_object)(Node::class_name().string_sys());
+
+    // No Rust-side state per instance: the object pointer itself serves as instance pointer (must be non-null).
+    interface_fn!(object_set_instance)(
+        object_ptr,
+        relay_class_name().string_sys(),
+        object_ptr as sys::GDExtensionClassInstancePtr,
+    );
+
+    object_ptr
+}
+
+unsafe extern "C" fn notification(instance: sys::GDExtensionClassInstancePtr, what: i32) {
+    if let NodeNotification::Predelete = NodeNotification::from(what) {
+        let relay = Gd::<Node>::from_obj_sys(instance as sys::GDExtensionObjectPtr);
+        let id = relay.instance_id();
+        CLOSURES.with(|closures| closures.borrow_mut().remove(&id));
+    }
+}
+
+unsafe extern "C" fn call_closure(
+    _method_data: *mut std::ffi::c_void,
+    instance: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    _ret: sys::GDExtensionVariantPtr,
+    err: *mut sys::GDExtensionCallError,
+) {
+    let relay = Gd::<Node>::from_obj_sys(instance as sys::GDExtensionObjectPtr);
+    let args: Vec<&Variant> = (0..arg_count as isize)
+        .map(|i| &*Variant::ptr_from_sys(sys::force_mut_ptr(*args_ptr.offset(i))))
+        .collect();
+
+    crate::private::handle_panic(
+        || "closure relay",
+        std::panic::AssertUnwindSafe(|| run_closure(relay.instance_id(), &args)),
+    );
+
+    // The return value stays nil. A panic has already been reported; signal emitters have no use for an error code.
+    (*err).error = sys::GDEXTENSION_CALL_OK;
+}
+
+fn run_closure(id: InstanceId, args: &[&Variant]) {
+    // Take the closure out while running it, so it can create or free relays (including its own).
+    let Some(mut closure) = CLOSURES.with(|closures| closures.borrow_mut().remove(&id)) else {
+        return;
+    };
+
+    closure(args);
+
+    // If the relay was freed by the closure, the closure is dropped here.
+    if utilities::is_instance_id_valid(id.to_i64()) {
+        CLOSURES.with(|closures| closures.borrow_mut().insert(id, closure));
+    }
+}
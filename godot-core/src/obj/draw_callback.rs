@@ -0,0 +1,56 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Draw callbacks for canvas items, without subclassing.
+//!
+//! Each callback is connected to the canvas item's `draw` signal through a closure relay, which is added as an internal child of
+//! the canvas item, so that it is freed together with it. Godot emits `draw` in the draw phase, on the main thread, right after
+//! the item's own `_draw()`.
+
+use crate::builtin::Variant;
+use crate::engine::{node, CanvasItem, Node};
+use crate::obj::closure_relay;
+use crate::obj::{Gd, Share};
+
+pub(crate) type DrawFn = Box<dyn FnMut(&mut Gd<CanvasItem>)>;
+
+/// Connects `callback` to the `draw` signal of `target`, and requests a redraw.
+pub(crate) fn add_draw_callback(mut target: Gd<CanvasItem>, mut callback: DrawFn) {
+    let mut canvas_item = target.share();
+    let relay = closure_relay::create_relay(move |_args: &[&Variant]| callback(&mut canvas_item));
+
+    target.connect("draw".into(), closure_relay::callable(&relay));
+
+    let mut node = target.share().upcast::<Node>();
+    node.add_child_ex(relay)
+        .internal(node::InternalMode::INTERNAL_MODE_FRONT)
+        .done();
+
+    target.queue_redraw();
+}
+
+/// Disconnects all callbacks added to `target`, and frees their relays. No-op if there are none.
+pub(crate) fn clear_draw_callbacks(target: &Gd<CanvasItem>) {
+    let mut target = target.share();
+    let node = target.share().upcast::<Node>();
+
+    let count = node.get_child_count_ex().include_internal(true).done();
+    let relays: Vec<Gd<Node>> = (0..count)
+        .filter_map(|i| node.get_child_ex(i).include_internal(true).done())
+        .filter(|child| !child.is_queued_for_deletion())
+        .collect();
+
+    for mut relay in relays {
+        let callable = closure_relay::callable(&relay);
+
+        // Other internal children, including relays for other signals, are not connected to `draw`.
+        if target.is_connected("draw".into(), callable.clone()) {
+            // Disconnect right away, as this may run inside a draw callback; the relay itself is freed at the end of the frame.
+            target.disconnect("draw".into(), callable);
+            relay.queue_free();
+        }
+    }
+}
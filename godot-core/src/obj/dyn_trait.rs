@@ -0,0 +1,122 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::Any;
+
+use crate::engine::Object;
+use crate::obj::Gd;
+use crate::sys;
+
+/// Registers a user class as implementor of a Rust trait, enabling [`Gd::with_dyn()`] and [`Gd::with_dyn_mut()`].
+///
+/// The syntax is `register_dyn!(Class: dyn Trait)`. The class must implement the trait, and can be registered for
+/// multiple traits.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::obj::register_dyn;
+///
+/// trait Damageable {
+///     fn take_damage(&mut self, amount: i32);
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Enemy {
+///     health: i32,
+/// }
+///
+/// impl Damageable for Enemy {
+///     fn take_damage(&mut self, amount: i32) {
+///         self.health -= amount;
+///     }
+/// }
+///
+/// register_dyn!(Enemy: dyn Damageable);
+///
+/// fn hit_all(targets: &mut [Gd<Node>]) {
+///     for target in targets {
+///         // Objects whose class doesn't implement Damageable are skipped.
+///         target.with_dyn_mut(|d: &mut dyn Damageable| d.take_damage(10));
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! register_dyn {
+    ($Class:ty : $Dyn:ty) => {
+        $crate::sys::plugin_add!(__GODOT_DYN_TRAIT_REGISTRY in $crate::private;
+            $crate::private::DynTraitPlugin::new($crate::private::DynCaster::<$Dyn> {
+                with_ref: |object, apply| {
+                    match $crate::obj::Share::share(object).try_cast::<$Class>() {
+                        Some(gd) => {
+                            apply(&*gd.bind());
+                            true
+                        }
+                        None => false,
+                    }
+                },
+                with_mut: |object, apply| {
+                    match $crate::obj::Share::share(object).try_cast::<$Class>() {
+                        Some(mut gd) => {
+                            apply(&mut *gd.bind_mut());
+                            true
+                        }
+                        None => false,
+                    }
+                },
+            })
+        );
+    };
+}
+
+pub use crate::register_dyn;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation details
+
+/// Type-erased `DynCaster<D>`, as stored by [`register_dyn!`].
+#[doc(hidden)]
+pub struct DynTraitPlugin {
+    caster: Box<dyn Any + Send>,
+}
+
+impl DynTraitPlugin {
+    pub fn new<D: ?Sized + 'static>(caster: DynCaster<D>) -> Self {
+        Self {
+            caster: Box::new(caster),
+        }
+    }
+}
+
+/// Binds an object as `&D`/`&mut D`, if its class is the one that was registered. Returns whether that is the case.
+#[doc(hidden)]
+pub struct DynCaster<D: ?Sized> {
+    pub with_ref: fn(&Gd<Object>, &mut dyn FnMut(&D)) -> bool,
+    pub with_mut: fn(&Gd<Object>, &mut dyn FnMut(&mut D)) -> bool,
+}
+
+// Manual impls: derives would require `D: Clone`.
+impl<D: ?Sized> Clone for DynCaster<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D: ?Sized> Copy for DynCaster<D> {}
+
+/// Returns all casters registered for trait object type `D`.
+///
+/// The registry is not locked anymore when this returns, so casters may re-enter (e.g. nested `with_dyn()` calls).
+pub(crate) fn casters_for<D: ?Sized + 'static>() -> Vec<DynCaster<D>> {
+    let mut casters = vec![];
+    sys::plugin_foreach!(__GODOT_DYN_TRAIT_REGISTRY in crate::private; |plugin: &DynTraitPlugin| {
+        if let Some(caster) = plugin.caster.downcast_ref::<DynCaster<D>>() {
+            casters.push(*caster);
+        }
+    });
+    casters
+}
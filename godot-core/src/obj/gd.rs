@@ -6,6 +6,7 @@
 
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
 use std::ops::{Deref, DerefMut};
 use std::ptr;
 
@@ -18,12 +19,12 @@ use sys::{
 
 use crate::builtin::meta::{ClassName, VariantMetadata};
 use crate::builtin::{
-    Callable, FromVariant, StringName, ToVariant, Variant, VariantConversionError,
+    Callable, FromVariant, GodotString, StringName, ToVariant, Variant, VariantConversionError,
 };
 use crate::obj::dom::Domain as _;
 use crate::obj::mem::Memory as _;
-use crate::obj::{cap, dom, mem, EngineEnum, GodotClass, Inherits, Share};
-use crate::obj::{GdMut, GdRef, InstanceId};
+use crate::obj::{cap, dom, dyn_trait, mem, EngineEnum, GodotClass, Inherits, Share};
+use crate::obj::{GdMut, GdRef, GdSyncRef, InstanceId};
 use crate::property::{Export, ExportInfo, Property, TypeStringHint};
 use crate::storage::InstanceStorage;
 use crate::{callbacks, engine, out};
@@ -129,6 +130,37 @@ where
         unsafe { Gd::from_obj_sys(object_ptr) }
     }
 
+    /// Converts into a smart pointer to the direct engine base class, e.g. `Gd<MyClass>` into `Gd<Node2D>`.
+    ///
+    /// This is [`upcast::<T::Base>()`][Self::upcast] under a more explicit name. The returned pointer refers to the **same object**,
+    /// including the Rust instance of `T`; nothing is copied or split off. Ownership is moved: the reference count stays the same,
+    /// and a manually managed object must still be freed exactly once, through whichever pointer.
+    ///
+    /// To get the base while keeping `self`, use `self.share().into_base()`. Inside `T`'s methods, the `#[base]` field already
+    /// gives access to the base class API.
+    pub fn into_base(self) -> Gd<T::Base>
+    where
+        T: Inherits<T::Base>,
+    {
+        self.upcast()
+    }
+
+    /// Converts a smart pointer to the direct engine base class back into `Gd<T>`, if it refers to an instance of `T`.
+    ///
+    /// This validates the object's dynamic class: `Some` is only returned if the object was created as `T` (e.g. through
+    /// [`Gd::new()`][Self::new], or `T.new()` in GDScript), and thus has a Rust instance of `T` attached. Ownership is moved
+    /// to the returned pointer, just like in [`into_base()`][Self::into_base].
+    ///
+    /// `None` is returned for pure engine objects, e.g. a `Gd<Node2D>` obtained from `Node2D::new_alloc()`: a `T` can never be
+    /// attached to an existing object after the fact. The same holds for instances of other user classes that share the base.
+    /// In those cases, the reference is dropped; use [`downcast()`][Self::downcast] on the base to get it back.
+    pub fn from_base(base: Gd<T::Base>) -> Option<Self>
+    where
+        T: Inherits<T::Base>,
+    {
+        base.try_cast()
+    }
+
     /// Hands out a guard for a shared borrow, through which the user instance can be read.
     ///
     /// The pattern is very similar to interior mutability with standard [`RefCell`][std::cell::RefCell].
@@ -160,6 +192,96 @@ where
         GdMut::from_cell(self.storage().get_mut())
     }
 
+    /// Hands out a shared guard like [`bind()`][Self::bind], which can additionally be shared with other threads.
+    ///
+    /// `&GdSyncRef` is `Send`, so the instance can be read from worker threads, typically with [`std::thread::scope()`]:
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # #[derive(GodotClass)] #[class(init)] struct Grid { cells: Vec<u8> }
+    /// fn count_filled(grid: &Gd<Grid>) -> usize {
+    ///     let guard = grid.bind_sync();
+    ///     let (left, right) = guard.cells.split_at(guard.cells.len() / 2);
+    ///
+    ///     std::thread::scope(|s| {
+    ///         let left = s.spawn(|| left.iter().filter(|&&c| c != 0).count());
+    ///         let right = s.spawn(|| right.iter().filter(|&&c| c != 0).count());
+    ///         left.join().unwrap() + right.join().unwrap()
+    ///     })
+    /// }
+    /// ```
+    ///
+    /// This requires `T: Sync`. Classes with a `#[base]` field are not `Sync`, because the base must not be used from other
+    /// threads; use [`bind_sync_map()`][Self::bind_sync_map] to share only a part of such instances.
+    ///
+    /// Godot APIs must still not be called from other threads, unless documented as thread-safe. The guard itself is released
+    /// on the thread that created it. While it is alive, the instance cannot be mutably bound: [`bind_mut()`][Self::bind_mut]
+    /// and calls from GDScript to `&mut self` methods panic, so concurrent mutation on the main thread is ruled out.
+    ///
+    /// # Panics
+    /// Under the same conditions as [`bind()`][Self::bind].
+    pub fn bind_sync(&self) -> GdSyncRef<T>
+    where
+        T: Sync,
+    {
+        GdSyncRef::from_guard(self.bind(), |instance| instance)
+    }
+
+    /// Like [`bind_sync()`][Self::bind_sync], but shares only the part of the instance selected by `project`.
+    ///
+    /// This allows classes that are not `Sync` themselves (e.g. due to their `#[base]` field) to share some of their fields:
+    /// `obj.bind_sync_map(|this| &this.cells)`.
+    ///
+    /// # Panics
+    /// Under the same conditions as [`bind()`][Self::bind].
+    pub fn bind_sync_map<U, F>(&self, project: F) -> GdSyncRef<U>
+    where
+        U: ?Sized + Sync,
+        F: FnOnce(&T) -> &U,
+    {
+        GdSyncRef::from_guard(self.bind(), project)
+    }
+
+    /// Runs `f` with a shared borrow of the user instance, and returns its result.
+    ///
+    /// Equivalent to `f(&*self.bind())`, except that the guard cannot outlive the closure. This avoids accidentally holding
+    /// a borrow longer than needed, e.g. across a call that re-enters the same object.
+    ///
+    /// # Panics
+    /// Under the same conditions as [`bind()`][Self::bind].
+    pub fn with_bind<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        let guard = self.bind();
+        f(&guard)
+    }
+
+    /// Runs `f` with an exclusive borrow of the user instance, and returns its result.
+    ///
+    /// Equivalent to `f(&mut *self.bind_mut())`, except that the guard cannot outlive the closure. This avoids accidentally
+    /// holding a borrow longer than needed, e.g. across a call that re-enters the same object.
+    ///
+    /// # Panics
+    /// Under the same conditions as [`bind_mut()`][Self::bind_mut].
+    pub fn with_bind_mut<R, F>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut guard = self.bind_mut();
+        f(&mut guard)
+    }
+
+    /// Returns the typed signals of this object, as declared with `#[signal]` in the class's `#[godot_api]` block.
+    ///
+    /// Each signal is accessed through a method of the same name, e.g. `obj.signals().my_signal().connect(callable)`.
+    /// See [`TypedSignal`][crate::obj::TypedSignal].
+    pub fn signals(&self) -> T::SignalCollection
+    where
+        T: cap::WithSignals,
+    {
+        T::__signals(self)
+    }
+
     /// Storage object associated with the extension instance.
     pub(crate) fn storage(&self) -> &InstanceStorage<T> {
         // SAFETY: instance pointer belongs to this instance. We only get a shared reference, no exclusive access, so even
@@ -298,6 +420,28 @@ impl<T: GodotClass> Gd<T> {
         self.instance_id_or_none().is_some()
     }
 
+    /// Returns the current reference count, or `None` if the object does not inherit `RefCounted`.
+    ///
+    /// The count includes the `Gd` pointer this method is called on, so the result is always at least 1. Every
+    /// [`share()`][Share::share] adds one, and so do references held by Godot (e.g. in GDScript variables or containers).
+    ///
+    /// This is mostly useful for debugging reference leaks, e.g. when a resource is unexpectedly not freed.
+    ///
+    /// # Panics
+    /// If this object is no longer alive.
+    pub fn ref_count(&self) -> Option<u32> {
+        assert!(
+            self.is_instance_valid(),
+            "failed to call ref_count() on destroyed object"
+        );
+
+        let tmp = unsafe { self.ffi_cast::<engine::RefCounted>() }?;
+        let count = tmp.get_reference_count();
+        std::mem::forget(tmp); // no ownership transfer
+
+        Some(u32::try_from(count).expect("reference count is non-negative"))
+    }
+
     /// **Upcast:** convert into a smart pointer to a base class. Always succeeds.
     ///
     /// Moves out of this value. If you want to create _another_ smart pointer instance,
@@ -325,7 +469,8 @@ impl<T: GodotClass> Gd<T> {
     /// If `T`'s dynamic type is not `Derived` or one of its subclasses, `None` is returned
     /// and the reference is dropped. Otherwise, `Some` is returned and the ownership is moved
     /// to the returned value.
-    // TODO consider Result<Gd<Derived>, Self> so that user can still use original object (e.g. to free if manual)
+    ///
+    /// See [`Self::downcast()`] if you need the original object back on failure.
     pub fn try_cast<Derived>(self) -> Option<Gd<Derived>>
     where
         Derived: GodotClass + Inherits<T>,
@@ -333,6 +478,53 @@ impl<T: GodotClass> Gd<T> {
         self.owned_cast().ok()
     }
 
+    /// **Downcast:** try to convert into a smart pointer to a derived class, returning the original on failure.
+    ///
+    /// If `T`'s dynamic type is not `Derived` or one of its subclasses, `Err(self)` is returned, so the pointer is not lost.
+    /// This is useful for manually managed objects, which must still be freed, or to attempt several casts in a row.
+    /// Like [`Box::downcast()`], this is the idiomatic replacement for `TryFrom`, which cannot be implemented here because
+    /// it would overlap with the standard library's blanket `impl TryFrom<U> for T where U: Into<T>`.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let node: Gd<Node> = Node::new_alloc();
+    /// match node.downcast::<Node3D>() {
+    ///     Ok(node3d) => node3d.free(),
+    ///     Err(node) => node.free(), // still accessible
+    /// }
+    /// ```
+    pub fn downcast<Derived>(self) -> Result<Gd<Derived>, Self>
+    where
+        Derived: GodotClass + Inherits<T>,
+    {
+        self.owned_cast()
+    }
+
+    /// **Downcast:** try to convert into a smart pointer to a derived class, with an error describing the failure.
+    ///
+    /// Like [`try_cast()`][Self::try_cast], but the [`CastError`] names the expected class and the object's actual dynamic
+    /// class, e.g. to log "expected Sprite2D, got Node2D". The reference is dropped on failure; use [`downcast()`][Self::downcast]
+    /// to get it back instead.
+    ///
+    /// Unlike the other casts, this does not panic if the object has already been freed, but returns an error.
+    pub fn try_cast_verbose<Derived>(self) -> Result<Gd<Derived>, CastError>
+    where
+        Derived: GodotClass + Inherits<T>,
+    {
+        let error = |actual_class| CastError {
+            from: T::class_name(),
+            to: Derived::class_name(),
+            actual_class,
+        };
+
+        if !self.is_instance_valid() {
+            return Err(error(None));
+        }
+
+        let actual_class = self.as_object(|obj| obj.get_class());
+        self.owned_cast().map_err(|_| error(Some(actual_class)))
+    }
+
     /// ⚠️ **Downcast:** convert into a smart pointer to a derived class. Panics on error.
     ///
     /// # Panics
@@ -350,20 +542,70 @@ impl<T: GodotClass> Gd<T> {
         })
     }
 
+    /// Binds the object as trait object `&D` and passes it to `apply`, if its class is registered for `D`.
+    ///
+    /// This allows dispatching over Rust traits implemented by different user classes, e.g. `dyn Damageable`. Classes opt in
+    /// via [`register_dyn!`][crate::obj::register_dyn]. Returns `None` if the object's class is not registered for `D`.
+    ///
+    /// # Panics
+    /// If the object is already mutably bound, see [`bind()`][Self::bind].
+    pub fn with_dyn<D, R>(&self, apply: impl FnOnce(&D) -> R) -> Option<R>
+    where
+        D: ?Sized + 'static,
+    {
+        let object = self.weak_object();
+        let mut apply = Some(apply);
+        let mut result = None;
+
+        for caster in dyn_trait::casters_for::<D>() {
+            if (caster.with_ref)(&object, &mut |d| result = apply.take().map(|f| f(d))) {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Binds the object as trait object `&mut D` and passes it to `apply`, if its class is registered for `D`.
+    ///
+    /// See [`with_dyn()`][Self::with_dyn] for details.
+    ///
+    /// # Panics
+    /// If the object is already bound, see [`bind_mut()`][Self::bind_mut].
+    pub fn with_dyn_mut<D, R>(&mut self, apply: impl FnOnce(&mut D) -> R) -> Option<R>
+    where
+        D: ?Sized + 'static,
+    {
+        let object = self.weak_object();
+        let mut apply = Some(apply);
+        let mut result = None;
+
+        for caster in dyn_trait::casters_for::<D>() {
+            if (caster.with_mut)(&object, &mut |d| result = apply.take().map(|f| f(d))) {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Returns the same object as `Gd<Object>`, without touching the reference count.
+    fn weak_object(&self) -> ManuallyDrop<Gd<engine::Object>> {
+        let object =
+            unsafe { self.ffi_cast::<engine::Object>() }.expect("Everything inherits object");
+
+        // ManuallyDrop instead of mem::forget(), so the count stays balanced if a user callback panics.
+        ManuallyDrop::new(object)
+    }
+
     // See use-site for explanation.
     fn is_cast_valid<U>(&self) -> bool
     where
         U: GodotClass,
     {
-        let as_obj =
-            unsafe { self.ffi_cast::<engine::Object>() }.expect("Everything inherits object");
-        let cast_is_valid = as_obj.is_class(U::class_name().to_godot_string());
-        std::mem::forget(as_obj);
-        cast_is_valid
+        self.as_object(|obj| obj.is_class(U::class_name().to_godot_string()))
     }
 
     /// Returns `Ok(cast_obj)` on success, `Err(self)` on error
-    fn owned_cast<U>(self) -> Result<Gd<U>, Self>
+    pub(crate) fn owned_cast<U>(self) -> Result<Gd<U>, Self>
     where
         U: GodotClass,
     {
@@ -464,6 +706,101 @@ impl<T: GodotClass> Gd<T> {
     pub fn callable<S: Into<StringName>>(&self, method_name: S) -> Callable {
         Callable::from_object_method(self.share(), method_name)
     }
+
+    /// Returns whether this object is an instance of the class `name`, or of a class derived from it.
+    ///
+    /// Unlike [`try_cast()`][Self::try_cast], this checks against a class name only known at runtime. Both engine classes and
+    /// classes registered from Rust are recognized: a `Gd<MyNode>` is a `"MyNode"` as well as a `"Node"` and an `"Object"`.
+    /// Names of GDScript classes (`class_name`) are not recognized, as they are not part of Godot's class database.
+    ///
+    /// _Godot equivalent: `object.is_class(name)`_
+    pub fn is_class_named(&self, name: &str) -> bool {
+        self.as_object(|obj| obj.is_class(name.into()))
+    }
+
+    /// Attaches `script` to this object, replacing any previously attached script.
+    ///
+    /// Godot requires the script to extend this object's class or one of its base classes (e.g. a script with `extends Node2D`
+    /// can be attached to a `Sprite2D`, but not to a `Node`). Otherwise, an error is returned and the object is left unchanged.
+    ///
+    /// If the script is valid but cannot be instantiated -- for example, it has parse errors, or it is not a `@tool` script
+    /// and this runs in the editor -- Godot still attaches it, but its code does not run.
+    ///
+    /// _Godot equivalent: `object.set_script(script)`_
+    pub fn try_set_script(
+        &mut self,
+        script: Gd<engine::Script>,
+    ) -> Result<(), engine::ScriptError> {
+        let mut object = self.share().upcast::<engine::Object>();
+
+        // An empty base type means that the script extends nothing, which Godot accepts for any object.
+        let base_type = script.get_instance_base_type().to_string();
+        if !base_type.is_empty() && !object.is_class(base_type.as_str().into()) {
+            return Err(engine::ScriptError::new(
+                base_type,
+                object.get_class().to_string(),
+            ));
+        }
+
+        object.set_script(script.to_variant());
+        Ok(())
+    }
+
+    /// Detaches the script from this object, if any.
+    ///
+    /// _Godot equivalent: `object.set_script(null)`_
+    pub fn remove_script(&mut self) {
+        let mut object = self.share().upcast::<engine::Object>();
+        object.set_script(Variant::nil());
+    }
+
+    /// Returns the script attached to this object, or `None` if there is none.
+    ///
+    /// _Godot equivalent: `object.get_script()`_
+    pub fn script(&self) -> Option<Gd<engine::Script>> {
+        let object = self.share().upcast::<engine::Object>();
+        object.get_script().try_to::<Gd<engine::Script>>().ok()
+    }
+
+    /// ⚠️ Returns a future that resolves with the arguments of the next emission of `signal`, like GDScript's `await obj.signal`.
+    ///
+    /// The future must be run by the [`task`][crate::task] executor. It is connected immediately, so the signal may already be
+    /// emitted before the first `.await`. See [`SignalFuture`][crate::task::SignalFuture] for details.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    /// use godot::engine::Button;
+    /// use godot::task;
+    ///
+    /// fn confirm(button: Gd<Button>) {
+    ///     task::spawn(async move {
+    ///         button.await_signal("pressed").await.unwrap();
+    ///         godot_print!("confirmed");
+    ///     });
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// If this object has no signal named `signal`.
+    pub fn await_signal(&self, signal: impl Into<StringName>) -> crate::task::SignalFuture {
+        let signal = signal.into();
+        self.try_await_signal(signal.clone()).unwrap_or_else(|| {
+            panic!(
+                "await_signal(): no signal `{signal}` on {class}",
+                class = T::class_name()
+            )
+        })
+    }
+
+    /// Like [`await_signal()`][Self::await_signal], but returns `None` if the signal does not exist.
+    pub fn try_await_signal(
+        &self,
+        signal: impl Into<StringName>,
+    ) -> Option<crate::task::SignalFuture> {
+        let object = self.share().upcast::<engine::Object>();
+        crate::task::SignalFuture::connect(object, signal.into())
+    }
 }
 
 /// _The methods in this impl block are only available for objects `T` that are manually managed,
@@ -511,6 +848,237 @@ where
     }
 }
 
+/// _The methods in this impl block are only available for resources `T`, i.e. `Resource` or anything inherited from it._ <br><br>
+impl<T> Gd<T>
+where
+    T: GodotClass + Inherits<engine::Resource>,
+{
+    /// Creates a copy of this resource, returned as the same type `T`.
+    ///
+    /// If `subresources` is true, resources stored in properties are duplicated as well (deep copy); otherwise they are shared
+    /// between original and copy. Only properties with the `PROPERTY_USAGE_STORAGE` flag are copied, so for user classes,
+    /// fields not registered as properties keep their `init` values.
+    ///
+    /// _Godot equivalent: `resource.duplicate(subresources)`_
+    pub fn duplicate_with_flags(&self, subresources: bool) -> Gd<T> {
+        let resource = self.share().upcast::<engine::Resource>();
+
+        resource
+            .duplicate(subresources)
+            .expect("Resource::duplicate() returned null")
+            .cast::<T>()
+    }
+}
+
+/// _The methods in this impl block are only available for nodes `T`, i.e. `Node` or anything inherited from it._ <br><br>
+impl<T> Gd<T>
+where
+    T: GodotClass + Inherits<engine::Node>,
+{
+    /// Replaces this node in the tree with `replacement`, which takes over its position among the siblings, its name and all its children.
+    ///
+    /// Ownership is transferred as well: `replacement` gets this node's owner, and nodes owned by this node become owned by
+    /// `replacement`. If `keep_groups` is true, `replacement` is also added to all groups this node is in.
+    ///
+    /// This node is removed from the tree but not freed; call [`free()`][Self::free] or `queue_free()` if it is no longer needed.
+    /// If this node has no parent, or `replacement` already has one, an error is returned and neither node is changed.
+    ///
+    /// _Godot equivalent: `node.replace_by(replacement, keep_groups)`_
+    pub fn replace_in_tree<R>(
+        &mut self,
+        replacement: Gd<R>,
+        keep_groups: bool,
+    ) -> Result<(), engine::ReplaceError>
+    where
+        R: GodotClass + Inherits<engine::Node>,
+    {
+        let mut node = self.share().upcast::<engine::Node>();
+        let replacement = replacement.upcast::<engine::Node>();
+
+        // Godot would only move the children of an orphan node, and report an error for a parented replacement.
+        if node.get_parent().is_none() {
+            return Err(engine::ReplaceError::NotInTree);
+        }
+        if replacement.get_parent().is_some() {
+            return Err(engine::ReplaceError::ReplacementHasParent);
+        }
+
+        node.replace_by_ex(replacement)
+            .keep_groups(keep_groups)
+            .done();
+        Ok(())
+    }
+
+    /// Moves this node from its current parent to `new_parent`, optionally keeping its global transform.
+    ///
+    /// If `keep_global_transform` is true, the local transform is adjusted so that the node stays at the same place in the world.
+    /// This applies to the global transform of `Node2D` and `Node3D`, and to the global position of `Control`. Other nodes have
+    /// no transform, so the flag is ignored for them.
+    ///
+    /// A node without parent is simply added to `new_parent`, keeping its local transform (it has no global one). If
+    /// `new_parent` is already the parent, nothing happens.
+    ///
+    /// Unlike the engine method [`Node::reparent()`][engine::Node::reparent], this also accepts nodes without parent, and ignores
+    /// the flag for nodes that have no transform.
+    ///
+    /// _Godot equivalent: `node.reparent(new_parent, keep_global_transform)`_
+    ///
+    /// # Panics
+    /// If `new_parent` is this node or one of its descendants. Also if `keep_global_transform` is true, this node is a
+    /// `CanvasItem` or `Node3D` with a parent, and this node or `new_parent` are not inside the scene tree: global transforms
+    /// are only available inside the tree.
+    pub fn reparent_to<P>(&mut self, new_parent: Gd<P>, keep_global_transform: bool)
+    where
+        P: GodotClass + Inherits<engine::Node>,
+    {
+        let mut node = self.share().upcast::<engine::Node>();
+        let mut new_parent = new_parent.upcast::<engine::Node>();
+
+        assert!(
+            new_parent != node && !node.is_ancestor_of(new_parent.share()),
+            "reparent_to(): `{new_parent}` is `{node}` itself or one of its descendants"
+        );
+
+        match node.get_parent() {
+            // Godot would fail with an error for an orphan node.
+            None => new_parent.add_child(node),
+            Some(parent) if parent == new_parent => {}
+            Some(_) => {
+                // Only canvas items and 3D nodes have a global transform.
+                let keep_global_transform = keep_global_transform
+                    && (node.is_class("CanvasItem".into()) || node.is_class("Node3D".into()));
+
+                // Godot would read the global transforms anyway, and silently use a wrong one.
+                assert!(
+                    !keep_global_transform
+                        || (node.is_inside_tree() && new_parent.is_inside_tree()),
+                    "reparent_to(): cannot keep global transform of `{node}` outside the scene tree"
+                );
+
+                node.reparent_ex(new_parent)
+                    .keep_global_transform(keep_global_transform)
+                    .done();
+            }
+        }
+    }
+
+    /// Calls `callback` once, when this node is about to leave the scene tree (`tree_exiting` signal).
+    ///
+    /// At that point, the node and its children are still inside the tree. The connection is removed again when the
+    /// returned guard is dropped, so keep it as long as the callback is needed, e.g. in a field of the object owning the resource.
+    ///
+    /// If this node is not inside the tree, `callback` runs the next time it leaves the tree, i.e. after it has been added.
+    /// Must be called on the main thread.
+    pub fn on_tree_exiting<F>(&self, callback: F) -> engine::ConnectionGuard
+    where
+        F: FnOnce() + 'static,
+    {
+        self.connect_tree_exit("tree_exiting", callback)
+    }
+
+    /// Calls `callback` once, after this node has left the scene tree (`tree_exited` signal).
+    ///
+    /// Like [`on_tree_exiting()`][Self::on_tree_exiting], but the node is already outside the tree when `callback` runs.
+    pub fn on_tree_exited<F>(&self, callback: F) -> engine::ConnectionGuard
+    where
+        F: FnOnce() + 'static,
+    {
+        self.connect_tree_exit("tree_exited", callback)
+    }
+
+    /// Enables or disables the per-frame `process()` callback of this node.
+    ///
+    /// Godot enables processing by itself right before `ready()` if the class overrides `process()`. To start with processing
+    /// disabled, call `set_process(false)` in `ready()`, not in `init()` -- it would be re-enabled in between.
+    ///
+    /// This only sets Godot's flag. In the editor, `process()` of classes without `#[class(tool)]` is not run anyway with
+    /// the default [`EditorRunBehavior`][crate::init::EditorRunBehavior], regardless of this flag.
+    ///
+    /// _Godot equivalent: `node.set_process(enable)`_
+    pub fn set_process(&mut self, enable: bool) {
+        let mut node = self.share().upcast::<engine::Node>();
+        node.set_process(enable);
+    }
+
+    /// Returns whether the per-frame `process()` callback is enabled, see [`set_process()`][Self::set_process].
+    ///
+    /// _Godot equivalent: `node.is_processing()`_
+    pub fn is_processing(&self) -> bool {
+        let node = self.share().upcast::<engine::Node>();
+        node.is_processing()
+    }
+
+    /// Enables or disables the `physics_process()` callback of this node, which runs at the fixed physics rate.
+    ///
+    /// The same considerations as for [`set_process()`][Self::set_process] apply, with `physics_process()` instead of
+    /// `process()`.
+    ///
+    /// _Godot equivalent: `node.set_physics_process(enable)`_
+    pub fn set_physics_process(&mut self, enable: bool) {
+        let mut node = self.share().upcast::<engine::Node>();
+        node.set_physics_process(enable);
+    }
+
+    /// Returns whether the `physics_process()` callback is enabled, see [`set_physics_process()`][Self::set_physics_process].
+    ///
+    /// _Godot equivalent: `node.is_physics_processing()`_
+    pub fn is_physics_processing(&self) -> bool {
+        let node = self.share().upcast::<engine::Node>();
+        node.is_physics_processing()
+    }
+
+    fn connect_tree_exit<F>(&self, signal: &str, callback: F) -> engine::ConnectionGuard
+    where
+        F: FnOnce() + 'static,
+    {
+        let node = self.share().upcast::<engine::Node>();
+        engine::ConnectionGuard::connect_one_shot(node.upcast(), signal, callback)
+    }
+}
+
+/// _The methods in this impl block are only available for canvas items `T`, i.e. `CanvasItem` or anything inherited from it,
+/// such as `Node2D` and `Control`._ <br><br>
+impl<T> Gd<T>
+where
+    T: GodotClass + Inherits<engine::CanvasItem>,
+{
+    /// Requests that this canvas item is redrawn in the next frame.
+    ///
+    /// This runs the `draw()` callback of the class as well as all closures added with
+    /// [`add_draw_callback()`][Self::add_draw_callback]. Multiple requests within a frame result in a single redraw.
+    ///
+    /// _Godot equivalent: `canvas_item.queue_redraw()`_
+    pub fn queue_redraw(&mut self) {
+        let mut canvas_item = self.share().upcast::<engine::CanvasItem>();
+        canvas_item.queue_redraw();
+    }
+
+    /// Registers a closure that draws onto this canvas item, without overriding `draw()`.
+    ///
+    /// The closure is connected to the `draw` signal, so it runs whenever the canvas item is redrawn, in the draw phase on the
+    /// main thread. It receives this canvas item, on which the `draw_*` methods can be called. Drawing happens after the item's
+    /// own `draw()` and before its children, so the callbacks can be used to add overlays to existing nodes, e.g. in tools.
+    ///
+    /// Each closure is held by an internal child node (visible with `get_child_ex().include_internal(true)`), which is freed
+    /// together with `self`. A redraw is requested immediately; afterwards, call [`queue_redraw()`][Self::queue_redraw]
+    /// whenever the drawn content changes. Must be called on the main thread.
+    pub fn add_draw_callback<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut Gd<engine::CanvasItem>) + 'static,
+    {
+        let canvas_item = self.share().upcast::<engine::CanvasItem>();
+        super::draw_callback::add_draw_callback(canvas_item, Box::new(callback));
+    }
+
+    /// Removes all closures registered with [`add_draw_callback()`][Self::add_draw_callback].
+    ///
+    /// Their drawing disappears with the next frame.
+    pub fn clear_draw_callbacks(&mut self) {
+        let canvas_item = self.share().upcast::<engine::CanvasItem>();
+        super::draw_callback::clear_draw_callbacks(&canvas_item);
+    }
+}
+
 impl<T: GodotClass> Deref for Gd<T> {
     // Target is always an engine class:
     // * if T is an engine class => T
@@ -669,6 +1237,50 @@ pub unsafe fn raw_object_init(
     object_ptr
 }
 
+/// Error returned by [`Gd::try_cast_verbose()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct CastError {
+    from: ClassName,
+    to: ClassName,
+    actual_class: Option<GodotString>,
+}
+
+impl CastError {
+    /// Static class of the `Gd` that was cast, i.e. `T` in `Gd<T>`.
+    pub fn from_class(&self) -> ClassName {
+        self.from
+    }
+
+    /// Class that was requested as the cast target.
+    pub fn to_class(&self) -> ClassName {
+        self.to
+    }
+
+    /// Dynamic class of the object, or `None` if the object had already been freed.
+    pub fn actual_class(&self) -> Option<&GodotString> {
+        self.actual_class.as_ref()
+    }
+}
+
+impl Display for CastError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.actual_class {
+            Some(actual) => write!(
+                f,
+                "downcast from {} failed: expected {}, got {actual}",
+                self.from, self.to
+            ),
+            None => write!(
+                f,
+                "downcast from {} to {} failed: object has been freed",
+                self.from, self.to
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CastError {}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Trait impls
 
@@ -684,8 +1296,16 @@ impl<T: GodotClass> Drop for Gd<T> {
         // No-op for manually managed objects
 
         out!("Gd::drop   <{}>", std::any::type_name::<T>());
+        #[cfg(feature = "gd-drop-trace")]
+        let trace = drop_trace::begin(self);
+
         // SAFETY: This `Gd` wont be dropped again after this.
         let is_last = unsafe { T::Mem::maybe_dec_ref(self) }; // may drop
+
+        #[cfg(feature = "gd-drop-trace")]
+        if let Some(trace) = trace {
+            drop_trace::end(self, trace, is_last);
+        }
         if is_last {
             unsafe {
                 interface_fn!(object_destroy)(self.obj_sys());
@@ -709,6 +1329,59 @@ impl<T: GodotClass> Drop for Gd<T> {
     }
 }
 
+/// Logging of `Gd<T>` drops, for hunting reference leaks (feature `gd-drop-trace`).
+#[cfg(feature = "gd-drop-trace")]
+pub(crate) mod drop_trace {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    // Atomic: drops can happen on any thread. Only set while Godot can print, i.e. between library load and the last deinit.
+    static ENABLED: AtomicBool = AtomicBool::new(false);
+
+    pub(super) struct DropTrace {
+        instance_id: InstanceId,
+        class_name: String,
+    }
+
+    /// Called on library initialization and deinitialization.
+    pub(crate) fn set_enabled(enabled: bool) {
+        ENABLED.store(enabled, Ordering::Release);
+    }
+
+    /// Captures the object's identity before the reference is released. Returns `None` if not ref-counted, or if tracing
+    /// is not active.
+    pub(super) fn begin<T: GodotClass>(gd: &Gd<T>) -> Option<DropTrace> {
+        if !ENABLED.load(Ordering::Acquire) || T::Mem::is_ref_counted(gd) != Some(true) {
+            return None;
+        }
+
+        Some(DropTrace {
+            instance_id: gd.instance_id(),
+            class_name: gd.as_object(|obj| obj.get_class().to_string()),
+        })
+    }
+
+    /// Logs the drop with the reference count after decrementing. If this was the last reference, the object is
+    /// about to be destroyed and must not be accessed anymore.
+    pub(super) fn end<T: GodotClass>(gd: &Gd<T>, trace: DropTrace, is_last: bool) {
+        let remaining = if is_last {
+            0
+        } else {
+            gd.as_ref_counted(|refc| refc.get_reference_count())
+        };
+
+        let DropTrace {
+            instance_id,
+            class_name,
+        } = trace;
+
+        crate::godot_print!(
+            "Gd::drop: {class_name} (id {instance_id}), ref-count after drop: {remaining}{}",
+            if is_last { " (destroyed)" } else { "" }
+        );
+    }
+}
+
 impl<T: GodotClass> Share for Gd<T> {
     fn share(&self) -> Self {
         out!("Gd::share");
@@ -765,10 +1438,21 @@ impl<T: GodotClass> Export for Gd<T> {
     }
 }
 
-// Trait impls Property, Export and TypeStringHint for Option<Gd<T>> are covered by blanket impl for Option<T>
+// Trait impls Property, Export and TypeStringHint for Option<Gd<T>> are covered by blanket impl for Option<T>.
+// They reuse the resource/node hints from Gd<T>, while `None` maps to null via ToVariant/FromVariant. This makes
+// `Option<Gd<T>>` the type of choice for exported object slots that may be left empty in the inspector.
 
 impl<T: GodotClass> FromVariant for Gd<T> {
     fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+        // A variant does not keep manually managed objects alive, e.g. a node stored in an array may be freed in the meantime.
+        // The variant's object pointer then dangles, so look up the instance ID stored alongside it before converting.
+        if let Some(id) = variant.object_id_unchecked() {
+            let object_ptr = unsafe { interface_fn!(object_get_instance_from_id)(id.to_u64()) };
+            if object_ptr.is_null() {
+                return Err(VariantConversionError::BadValue);
+            }
+        }
+
         let result_or_none = unsafe {
             // TODO(#234) replace Gd::<Object> with Self when Godot stops allowing illegal conversions
             // See https://github.com/godot-rust/gdext/issues/158
@@ -847,6 +1531,21 @@ impl<T: GodotClass> PartialEq for Gd<T> {
 
 impl<T: GodotClass> Eq for Gd<T> {}
 
+impl<T: GodotClass> std::hash::Hash for Gd<T> {
+    /// ⚠️ Hashes this object based on its instance ID, consistent with `PartialEq`.
+    ///
+    /// This allows using `Gd` as a key in `HashMap` or `HashSet`, e.g. for side tables of per-object data.
+    ///
+    /// Note that Godot may reuse the instance ID of a freed object for a new one. Entries keyed by objects that
+    /// have since been destroyed should thus be removed, rather than relied upon to stay distinct.
+    ///
+    /// # Panics
+    /// When `self` is dead.
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.instance_id().hash(state);
+    }
+}
+
 impl<T: GodotClass> Display for Gd<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         engine::display_string(self, f)
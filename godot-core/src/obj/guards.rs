@@ -84,3 +84,52 @@ impl<T> DerefMut for GdMut<'_, T> {
         self.cell_ref.deref_mut()
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Shared bound reference guard, which can be shared with other threads for read-only access.
+///
+/// See [`Gd::bind_sync`][crate::obj::Gd::bind_sync] for usage.
+pub struct GdSyncRef<'a, U: ?Sized> {
+    // Keeps the instance borrowed. Not accessed through `&self`, so it does not need to be `Sync`; being `!Send`, it is
+    // released on the thread that created it.
+    _guard: Box<dyn ErasedGuard + 'a>,
+
+    // Points into the instance storage, which the guard keeps borrowed (and thus alive and unmodified).
+    value: *const U,
+}
+
+impl<'a, U: ?Sized> GdSyncRef<'a, U> {
+    pub(crate) fn from_guard<T: 'a>(guard: GdRef<'a, T>, project: impl FnOnce(&T) -> &U) -> Self {
+        let value: *const U = project(&guard);
+
+        Self {
+            _guard: Box::new(guard),
+            value,
+        }
+    }
+}
+
+impl<U: ?Sized> Deref for GdSyncRef<'_, U> {
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        // SAFETY: `value` points into the bound instance, which cannot be modified or destroyed while `_guard` is alive.
+        unsafe { &*self.value }
+    }
+}
+
+impl<U: ?Sized + Debug> Debug for GdSyncRef<'_, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("GdSyncRef").field(&self.deref()).finish()
+    }
+}
+
+// SAFETY: shared access only yields `&U`, which may be used from other threads if `U: Sync`. The guard itself is not reachable
+// through `&GdSyncRef`.
+unsafe impl<U: ?Sized + Sync> Sync for GdSyncRef<'_, U> {}
+
+/// Type-erased `GdRef`, only kept for its destructor.
+trait ErasedGuard {}
+
+impl<T> ErasedGuard for GdRef<'_, T> {}
@@ -11,13 +11,21 @@
 //! * [`Gd`], a smart pointer that manages instances of Godot classes.
 
 mod base;
+pub(crate) mod closure_relay;
+mod draw_callback;
+pub(crate) mod dyn_trait;
 mod gd;
 mod guards;
 mod instance_id;
+mod onready;
 mod traits;
+mod typed_signal;
 
 pub use base::*;
+pub use dyn_trait::register_dyn;
 pub use gd::*;
 pub use guards::*;
 pub use instance_id::*;
+pub use onready::*;
 pub use traits::*;
+pub use typed_signal::*;
@@ -0,0 +1,170 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+use crate::builtin::NodePath;
+use crate::engine::{Node, NodeExt};
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// Field which is initialized when the node becomes ready, right before `ready()` is called.
+///
+/// Many fields can only be initialized in `ready()`, e.g. references to child nodes, which don't exist yet when `init()`
+/// runs. Instead of an `Option` that needs to be unwrapped at every use, such a field can be declared as `OnReady<T>`.
+/// It dereferences to `T`, and panics if accessed before initialization.
+///
+/// There are two ways to initialize it:
+/// * **Automatically**, with [`OnReady::new()`], [`OnReady::node()`] or the `#[init(node = "path")]` attribute on the
+///   field: the value is computed right before the class's `ready()` runs (also if the class does not override it).
+/// * **Manually**, with [`OnReady::manual()`] and a later call to [`init()`][Self::init], typically inside `ready()`.
+///
+/// Automatic initialization requires `#[derive(GodotClass)]` to recognize the field, so the type must be spelled
+/// `OnReady<...>` (possibly with a path prefix), not through a type alias.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::Label;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Hud {
+///     #[init(node = "Score")]
+///     score_label: OnReady<Gd<Label>>,
+///
+///     #[init(default = OnReady::new(|| vec![0; 10]))]
+///     history: OnReady<Vec<i64>>,
+/// }
+///
+/// #[godot_api]
+/// impl NodeVirtual for Hud {
+///     fn ready(&mut self) {
+///         // Already initialized here.
+///         self.score_label.set_text("0".into());
+///         self.history[0] = 1;
+///     }
+/// }
+/// ```
+pub struct OnReady<T> {
+    state: InitState<T>,
+}
+
+impl<T> OnReady<T> {
+    /// Initializes the field automatically with the result of `init_fn`, right before `ready()` is called.
+    pub fn new(init_fn: impl FnOnce() -> T + 'static) -> Self {
+        Self::from_base_fn(move |_base| init_fn())
+    }
+
+    /// Like [`new()`][Self::new], but `init_fn` receives the node itself, e.g. to look up other nodes.
+    pub fn from_base_fn(init_fn: impl FnOnce(&Gd<Node>) -> T + 'static) -> Self {
+        Self {
+            state: InitState::AutoPrepared {
+                init_fn: Box::new(init_fn),
+            },
+        }
+    }
+
+    /// Leaves the field uninitialized until [`init()`][Self::init] is called.
+    pub fn manual() -> Self {
+        Self {
+            state: InitState::ManualUninitialized,
+        }
+    }
+
+    /// Initializes a field created with [`manual()`][Self::manual].
+    ///
+    /// # Panics
+    /// If the field is initialized already, or was set up for automatic initialization.
+    pub fn init(&mut self, value: T) {
+        match &self.state {
+            InitState::ManualUninitialized => self.state = InitState::Initialized { value },
+            InitState::AutoPrepared { .. } => {
+                panic!("OnReady::init(): field is initialized automatically, cannot be initialized manually")
+            }
+            InitState::Initialized { .. } => {
+                panic!("OnReady::init(): field is already initialized")
+            }
+        }
+    }
+
+    /// Returns whether the value is available, i.e. the field can be dereferenced.
+    pub fn is_initialized(&self) -> bool {
+        matches!(self.state, InitState::Initialized { .. })
+    }
+
+    /// Runs the automatic initialization, if any. Called by generated code before the class's `ready()`.
+    #[doc(hidden)]
+    pub fn init_auto(&mut self, base: &Gd<Node>) {
+        // Temporarily leave the field in the manual state, which is not observable as `init_fn` has no access to `self`.
+        match std::mem::replace(&mut self.state, InitState::ManualUninitialized) {
+            InitState::AutoPrepared { init_fn } => {
+                let value = init_fn(base);
+                self.state = InitState::Initialized { value };
+            }
+
+            // Manual fields are initialized by the user; ready() may also run again after re-entering the tree.
+            state => self.state = state,
+        }
+    }
+}
+
+impl<T: GodotClass + Inherits<Node>> OnReady<Gd<T>> {
+    /// Initializes the field automatically with the node at `path`, relative to this node.
+    ///
+    /// # Panics
+    /// When the node becomes ready, if there is no node at `path` or it does not have type `T` (or inherited).
+    pub fn node(path: impl Into<NodePath>) -> Self {
+        let path = path.into();
+        Self::from_base_fn(move |base| base.get_node_as::<T>(path))
+    }
+}
+
+impl<T> Deref for OnReady<T> {
+    type Target = T;
+
+    /// # Panics
+    /// If the value is not initialized yet.
+    fn deref(&self) -> &Self::Target {
+        match &self.state {
+            InitState::Initialized { value } => value,
+            _ => panic!("OnReady field accessed before initialization; value is only available from ready() on"),
+        }
+    }
+}
+
+impl<T> DerefMut for OnReady<T> {
+    /// # Panics
+    /// If the value is not initialized yet.
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match &mut self.state {
+            InitState::Initialized { value } => value,
+            _ => panic!("OnReady field accessed before initialization; value is only available from ready() on"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for OnReady<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.state {
+            InitState::Initialized { value } => f.debug_tuple("OnReady").field(value).finish(),
+            _ => f.write_str("OnReady(<uninitialized>)"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+enum InitState<T> {
+    ManualUninitialized,
+    AutoPrepared {
+        init_fn: Box<dyn FnOnce(&Gd<Node>) -> T>,
+    },
+    Initialized {
+        value: T,
+    },
+}
@@ -5,7 +5,7 @@
  */
 
 use crate::builder::ClassBuilder;
-use crate::builtin::GodotString;
+use crate::builtin::{GodotString, ToVariant};
 use crate::obj::Base;
 
 use crate::builtin::meta::ClassName;
@@ -127,6 +127,43 @@ impl<T: GodotClass> Inherits<T> for T {}
 /// Those are the only objects you can export to the editor.
 pub trait ExportableObject: GodotClass {}
 
+/// Lets GDScript iterate over instances of a Rust class, as in `for item in object`.
+///
+/// Implement this trait in a `#[godot_api]` block; this registers Godot's iteration protocol (`_iter_init`, `_iter_next`
+/// and `_iter_get`) for the class. Items are looked up by position, which has a few consequences:
+/// * Every `for` loop starts again at index 0, so an object can be iterated multiple times, also in nested loops.
+/// * No borrow of the object is held between two steps. The loop body may modify the object; the next step then sees
+///   the modified state, as with `Array` (e.g. removing the current item skips the following one).
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::obj::ScriptIterable;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=RefCounted)]
+/// struct Inventory {
+///     items: Vec<GodotString>,
+/// }
+///
+/// #[godot_api]
+/// impl ScriptIterable for Inventory {
+///     type Item = GodotString;
+///
+///     fn item_at(&self, index: usize) -> Option<GodotString> {
+///         self.items.get(index).cloned()
+///     }
+/// }
+/// ```
+/// In GDScript: `for item in inventory: print(item)`.
+pub trait ScriptIterable: GodotClass {
+    /// Type of the values produced in GDScript.
+    type Item: ToVariant;
+
+    /// Returns the item at position `index`, or `None` to end the iteration.
+    fn item_at(&self, index: usize) -> Option<Self::Item>;
+}
+
 /// Auto-implemented for all engine-provided classes.
 pub trait EngineClass: GodotClass {
     fn as_object_ptr(&self) -> sys::GDExtensionObjectPtr;
@@ -197,12 +234,28 @@ pub mod cap {
     /// Trait that's implemented for user-defined classes that provide a `#[base]` field.
     ///
     /// Gives direct access to the base pointer without going through upcast FFI.
+    ///
+    /// The methods are available through the prelude, so `self.base()` works in any method of such a class, independently of
+    /// the name of the `#[base]` field. The base is accessible as soon as the struct is constructed, i.e. also inside `init()`
+    /// after creating `Self`.
     pub trait WithBaseField: GodotClass {
         #[doc(hidden)]
         fn __godot_base(&self) -> &Gd<Self::Base>;
 
         #[doc(hidden)]
         fn __godot_base_mut(&mut self) -> &mut Gd<Self::Base>;
+
+        /// Returns the engine object this instance is based on, as a pointer to the base class.
+        ///
+        /// This does not change the reference count. Use [`share()`][crate::obj::Share::share] to obtain an owned `Gd`.
+        fn base(&self) -> &Gd<Self::Base> {
+            self.__godot_base()
+        }
+
+        /// Like [`base()`][Self::base], but allows calling `&mut self` methods of the base class.
+        fn base_mut(&mut self) -> &mut Gd<Self::Base> {
+            self.__godot_base_mut()
+        }
     }
 
     // TODO Evaluate whether we want this public or not
@@ -219,6 +272,17 @@ pub mod cap {
         fn __godot_notification(&mut self, what: i32);
     }
 
+    /// Auto-implemented by `#[derive(GodotClass)]`, to initialize [`OnReady`][crate::obj::OnReady] fields.
+    #[doc(hidden)]
+    pub trait GodotBeforeReady: GodotClass {
+        /// Whether the class has fields that are initialized automatically before `ready()`.
+        #[doc(hidden)]
+        const HAS_ONREADY_FIELDS: bool;
+
+        #[doc(hidden)]
+        fn __before_ready(&mut self, base: &Gd<crate::engine::Node>);
+    }
+
     // TODO Evaluate whether we want this public or not
     #[doc(hidden)]
     pub trait GodotRegisterClass: GodotClass {
@@ -234,9 +298,27 @@ pub mod cap {
         fn __register_constants();
     }
 
+    /// Auto-implemented for `#[godot_api] impl MyClass` blocks that declare `#[signal]`s.
+    ///
+    /// Provides [`Gd::signals()`][crate::obj::Gd::signals].
+    pub trait WithSignals: GodotClass {
+        /// Struct generated by `#[godot_api]`, with one accessor per signal.
+        type SignalCollection;
+
+        #[doc(hidden)]
+        fn __signals(this: &Gd<Self>) -> Self::SignalCollection;
+    }
+
     pub trait ImplementsGodotExports: GodotClass {
         #[doc(hidden)]
         fn __register_exports();
+
+        /// Default value of an `#[export]` property, which the editor can revert to. Only known for `#[class(init)]`, where it
+        /// is the field initializer.
+        #[doc(hidden)]
+        fn __exported_property_default(_property: &str) -> Option<crate::builtin::Variant> {
+            None
+        }
     }
 
     /// Auto-implemented for `#[godot_api] impl XyVirtual for MyClass` blocks
@@ -0,0 +1,139 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::marker::PhantomData;
+
+use crate::builtin::{Callable, StringName, ToVariant, Variant};
+use crate::engine::{global, Object};
+use crate::obj::{Gd, Share};
+
+/// Signal of a specific object, with parameter types `Ps` (a tuple).
+///
+/// Obtained through the signal accessors that `#[signal]` generates, e.g. `obj.signals().my_signal()`. As the accessor is
+/// generated from the declaration, the signal name is checked at compile time, and [`emit()`][Self::emit] only accepts
+/// arguments of the declared types.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {}
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn health_changed(new_health: i64);
+/// }
+///
+/// fn connect_hud(player: &Gd<Player>, hud: &Gd<Node>) {
+///     let mut signal = player.signals().health_changed();
+///     assert_eq!(signal.arity(), 1);
+///
+///     signal.connect(hud.callable("on_health_changed"));
+///     signal.emit((100,));
+/// }
+/// ```
+pub struct TypedSignal<Ps> {
+    object: Gd<Object>,
+    name: StringName,
+    _params: PhantomData<fn(Ps)>,
+}
+
+impl<Ps: SignalParams> TypedSignal<Ps> {
+    #[doc(hidden)]
+    pub fn __new(object: Gd<Object>, name: &str) -> Self {
+        Self {
+            object,
+            name: StringName::from(name),
+            _params: PhantomData,
+        }
+    }
+
+    /// Name of the signal, as registered with Godot.
+    pub fn name(&self) -> StringName {
+        self.name.clone()
+    }
+
+    /// Number of parameters the signal is declared with, which connected callables must accept.
+    pub fn arity(&self) -> usize {
+        Ps::ARITY
+    }
+
+    /// Connects `callable` to this signal. Equivalent to `Object::connect()`.
+    pub fn connect(&mut self, callable: Callable) -> global::Error {
+        self.object.connect(self.name.clone(), callable)
+    }
+
+    /// Disconnects `callable` from this signal. Equivalent to `Object::disconnect()`.
+    pub fn disconnect(&mut self, callable: Callable) {
+        self.object.disconnect(self.name.clone(), callable)
+    }
+
+    /// Whether `callable` is connected to this signal. Equivalent to `Object::is_connected()`.
+    pub fn is_connected(&self, callable: Callable) -> bool {
+        self.object.is_connected(self.name.clone(), callable)
+    }
+
+    /// Emits the signal with the given arguments, e.g. `signal.emit((1, "text".into()))`. Equivalent to
+    /// `Object::emit_signal()`.
+    pub fn emit(&mut self, params: Ps) -> global::Error {
+        self.object
+            .emit_signal(self.name.clone(), &params.into_variants())
+    }
+
+    /// Emits the signal at idle time (the end of the current frame), instead of immediately. Equivalent to
+    /// `Object::call_deferred("emit_signal", ...)`.
+    ///
+    /// Use this to avoid re-entrancy, e.g. when connected callables could modify a collection that is currently being
+    /// iterated. If the object is freed before idle time (or already is), the emission is silently dropped.
+    pub fn emit_deferred(&self, params: Ps) {
+        if !self.object.is_instance_valid() {
+            return;
+        }
+
+        let mut args = vec![self.name.to_variant()];
+        args.extend(params.into_variants());
+
+        let mut object = self.object.share();
+        object.call_deferred("emit_signal".into(), &args);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Parameter list of a signal, implemented for tuples of [`ToVariant`] types.
+pub trait SignalParams {
+    /// Number of parameters.
+    const ARITY: usize;
+
+    /// Converts the parameters to variants, in order.
+    fn into_variants(self) -> Vec<Variant>;
+}
+
+macro_rules! impl_signal_params_for_tuple {
+    ($arity:literal; $( $P:ident : $n:tt ),*) => {
+        impl<$( $P: ToVariant ),*> SignalParams for ($( $P, )*) {
+            const ARITY: usize = $arity;
+
+            #[allow(unused_variables)]
+            fn into_variants(self) -> Vec<Variant> {
+                vec![ $( self.$n.to_variant() ),* ]
+            }
+        }
+    };
+}
+
+impl_signal_params_for_tuple!(0;);
+impl_signal_params_for_tuple!(1; P0: 0);
+impl_signal_params_for_tuple!(2; P0: 0, P1: 1);
+impl_signal_params_for_tuple!(3; P0: 0, P1: 1, P2: 2);
+impl_signal_params_for_tuple!(4; P0: 0, P1: 1, P2: 2, P3: 3);
+impl_signal_params_for_tuple!(5; P0: 0, P1: 1, P2: 2, P3: 3, P4: 4);
+impl_signal_params_for_tuple!(6; P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5);
+impl_signal_params_for_tuple!(7; P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6);
+impl_signal_params_for_tuple!(8; P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6, P7: 7);
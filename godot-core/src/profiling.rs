@@ -0,0 +1,141 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Runtime statistics for profiling (requires the `signal-stats` feature).
+//!
+//! Counters are gathered for every signal emitted from Rust via `Object::emit_signal()`. Emissions that happen purely
+//! inside Godot (e.g. GDScript's `emit_signal()` or engine-internal signals) do not pass through Rust and are not counted.
+
+use std::collections::HashMap;
+use std::sync;
+
+use godot_ffi as sys;
+use sys::interface_fn;
+
+use crate::builtin::StringName;
+use crate::engine::Object;
+use crate::obj::{EngineClass, Gd, InstanceId};
+
+// Mutex: needed for global access (Sync). Option: HashMap::new() is not const.
+static SIGNAL_STATS: sync::Mutex<Option<HashMap<EmitterKey, Emitter>>> = sync::Mutex::new(None);
+
+/// Signal of one specific object. Counting per object allows to look up connections lazily, see [`signal_stats()`].
+#[derive(Clone, Eq, PartialEq, Hash)]
+struct EmitterKey {
+    instance_id: InstanceId,
+    signal_name: String,
+}
+
+#[derive(Clone)]
+struct Emitter {
+    class_name: String,
+    emit_count: u64,
+}
+
+/// Statistics about one signal, as returned by [`signal_stats()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignalStats {
+    /// Godot class of the emitting object (the dynamic class, not the one declaring the signal).
+    pub class_name: String,
+
+    /// Name of the signal.
+    pub signal_name: String,
+
+    /// How many times the signal was emitted.
+    pub emit_count: u64,
+
+    /// Estimated total number of connected callbacks over all emissions.
+    ///
+    /// To keep emissions cheap, connections are not counted on every emission, but when the statistics are read: for each
+    /// emitting object, its emit count is multiplied by its number of connections at that time. Objects that have been freed in
+    /// the meantime contribute no callbacks.
+    pub callback_count: u64,
+}
+
+/// Returns statistics about all signals emitted since startup or the last [`reset_signal_stats()`].
+///
+/// The result is sorted by descending emit count, so the most frequently emitted signals come first.
+///
+/// This looks up the current connections of every object that emitted a signal, so it is considerably more expensive than
+/// the emissions themselves.
+pub fn signal_stats() -> Vec<SignalStats> {
+    // Copy out, so the lock is not held while calling into Godot.
+    let emitters: Vec<(EmitterKey, Emitter)> = SIGNAL_STATS
+        .lock()
+        .unwrap()
+        .iter()
+        .flatten()
+        .map(|(key, emitter)| (key.clone(), emitter.clone()))
+        .collect();
+
+    let mut merged: HashMap<(String, String), SignalStats> = HashMap::new();
+    for (key, emitter) in emitters {
+        let connection_count =
+            Gd::<Object>::try_from_instance_id(key.instance_id).map_or(0, |object| {
+                let signal = StringName::from(key.signal_name.as_str());
+                object.get_signal_connection_list(signal).len() as u64
+            });
+
+        let stats = merged
+            .entry((emitter.class_name.clone(), key.signal_name.clone()))
+            .or_insert_with(|| SignalStats {
+                class_name: emitter.class_name,
+                signal_name: key.signal_name,
+                emit_count: 0,
+                callback_count: 0,
+            });
+
+        stats.emit_count += emitter.emit_count;
+        stats.callback_count += emitter.emit_count * connection_count;
+    }
+
+    let mut stats: Vec<SignalStats> = merged.into_values().collect();
+    stats.sort_by(|a, b| b.emit_count.cmp(&a.emit_count));
+    stats
+}
+
+/// Clears all signal statistics gathered so far.
+pub fn reset_signal_stats() {
+    *SIGNAL_STATS.lock().unwrap() = None;
+}
+
+/// Invoked by generated code in `Object::emit_signal()`, before the signal is emitted.
+pub(crate) fn record_signal_emission(object: &Object, signal: &StringName) {
+    // SAFETY: `object` is alive, as a method is being called on it. Unlike get_instance_id(), this needs no method call.
+    let id = unsafe { interface_fn!(object_get_instance_id)(object.as_object_ptr()) };
+    let Some(instance_id) = InstanceId::try_from_u64(id) else {
+        return;
+    };
+
+    let key = EmitterKey {
+        instance_id,
+        signal_name: signal.to_string(),
+    };
+
+    if let Some(emitter) = SIGNAL_STATS
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .get_mut(&key)
+    {
+        emitter.emit_count += 1;
+        return;
+    }
+
+    // First emission of this signal by this object. Query the class outside the lock, in case Godot calls back into Rust.
+    let class_name = object.get_class().to_string();
+
+    let mut guard = SIGNAL_STATS.lock().unwrap();
+    let emitter = guard
+        .get_or_insert_with(HashMap::new)
+        .entry(key)
+        .or_insert(Emitter {
+            class_name,
+            emit_count: 0,
+        });
+
+    emitter.emit_count += 1;
+}
@@ -26,6 +26,15 @@ pub trait Export: Property {
     fn default_export_info() -> ExportInfo;
 }
 
+/// Trait implemented for types that can be used with `#[export(color_no_alpha)]`.
+///
+/// This is `Color`, for which the inspector hides the alpha channel, as well as `PackedColorArray`, for which it applies to
+/// each element. Using the attribute on other field types is a compile error, whereas GDScript reports it when parsing the script.
+pub trait ExportColorNoAlpha: Export {
+    /// The export info for the field, replacing [`Export::default_export_info()`].
+    fn color_no_alpha_export_info() -> ExportInfo;
+}
+
 /// Trait for types that can be represented as a type string for use with
 /// [`PropertyHint::PROPERTY_HINT_TYPE_STRING`].
 pub trait TypeStringHint {
@@ -79,6 +88,15 @@ where
     }
 }
 
+impl<T> ExportColorNoAlpha for Option<T>
+where
+    T: ExportColorNoAlpha + From<<T as Property>::Intermediate>,
+{
+    fn color_no_alpha_export_info() -> ExportInfo {
+        T::color_no_alpha_export_info()
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Export machinery
 
@@ -147,6 +165,7 @@ pub mod export_info_functions {
     pub fn export_range(
         min: f64,
         max: f64,
+        step: Option<f64>,
         or_greater: bool,
         or_less: bool,
         exp: bool,
@@ -154,7 +173,10 @@ pub mod export_info_functions {
         degrees: bool,
         hide_slider: bool,
     ) -> ExportInfo {
-        let min_max = format!("{},{}", min, max);
+        let min_max = match step {
+            Some(step) => format!("{min},{max},{step}"),
+            None => format!("{min},{max}"),
+        };
 
         let rest =
             comma_separate_boolean_idents!(or_greater, or_less, exp, radians, degrees, hide_slider);
@@ -270,6 +292,10 @@ pub mod export_info_functions {
         }
     }
 
+    pub fn export_color_no_alpha<T: super::ExportColorNoAlpha>() -> ExportInfo {
+        T::color_no_alpha_export_info()
+    }
+
     pub fn export_placeholder<S: AsRef<str>>(placeholder: S) -> ExportInfo {
         ExportInfo {
             hint: PropertyHint::PROPERTY_HINT_PLACEHOLDER_TEXT,
@@ -305,7 +331,6 @@ pub mod export_info_functions {
         export_dir => PROPERTY_HINT_DIR,
         export_global_dir => PROPERTY_HINT_GLOBAL_DIR,
         export_multiline => PROPERTY_HINT_MULTILINE_TEXT,
-        export_color_no_alpha => PROPERTY_HINT_COLOR_NO_ALPHA,
     );
 }
 
@@ -421,4 +446,31 @@ mod export_impls {
     impl_property_by_clone!(Rid => Rid, no_export);
 
     // impl_property_by_clone!(Signal => Signal);
+
+    impl ExportColorNoAlpha for Color {
+        fn color_no_alpha_export_info() -> ExportInfo {
+            ExportInfo {
+                hint: PropertyHint::PROPERTY_HINT_COLOR_NO_ALPHA,
+                hint_string: GodotString::new(),
+            }
+        }
+    }
+
+    impl ExportColorNoAlpha for PackedColorArray {
+        fn color_no_alpha_export_info() -> ExportInfo {
+            use crate::obj::EngineEnum;
+
+            // Element hint, in the format `type/hint:hint_string`.
+            let hint_string = format!(
+                "{}/{}:",
+                VariantType::Color as i32,
+                PropertyHint::PROPERTY_HINT_COLOR_NO_ALPHA.ord()
+            );
+
+            ExportInfo {
+                hint: PropertyHint::PROPERTY_HINT_TYPE_STRING,
+                hint_string: hint_string.into(),
+            }
+        }
+    }
 }
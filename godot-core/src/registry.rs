@@ -14,9 +14,10 @@ use godot_ffi as sys;
 use sys::interface_fn;
 
 use crate::builtin::meta::ClassName;
-use crate::builtin::StringName;
+use crate::builtin::{StringName, Variant};
 use crate::out;
 use std::any::Any;
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::{fmt, ptr};
 
@@ -53,6 +54,19 @@ pub enum PluginComponent {
     ClassDef {
         base_class_name: ClassName,
 
+        /// Whether Godot (e.g. GDScript `MyClass.new()`) may construct instances of this class.
+        ///
+        /// `false` for `#[class(no_init)]`; in that case, no `create` function may be registered.
+        is_instantiable: bool,
+
+        /// Whether an instance is registered as engine singleton, named like the class (`#[class(singleton)]`).
+        is_singleton: bool,
+
+        /// Whether the class is excluded from the editor's "Create New Node/Resource" dialogs (`#[class(hidden)]`).
+        ///
+        /// Registered as virtual class; Godot still allows instantiating it from code and loading it from scenes.
+        is_hidden: bool,
+
         /// Godot low-level`create` function, wired up to library-generated `init`
         generated_create_fn: Option<
             unsafe extern "C" fn(
@@ -64,6 +78,27 @@ pub enum PluginComponent {
             _class_user_data: *mut std::ffi::c_void,
             instance: sys::GDExtensionClassInstancePtr,
         ),
+
+        /// Whether a property has a default value, to which the editor can revert it.
+        property_can_revert_fn: unsafe extern "C" fn(
+            instance: sys::GDExtensionClassInstancePtr,
+            name: sys::GDExtensionConstStringNamePtr,
+        ) -> sys::GDExtensionBool,
+
+        /// Default value of a property, as produced by `init`.
+        property_get_revert_fn: unsafe extern "C" fn(
+            instance: sys::GDExtensionClassInstancePtr,
+            name: sys::GDExtensionConstStringNamePtr,
+            ret: sys::GDExtensionVariantPtr,
+        ) -> sys::GDExtensionBool,
+
+        /// Callback for virtuals, used if there is no `#[godot_api] impl GodotExt for MyClass`.
+        ///
+        /// Needed to initialize `OnReady` fields even if the class does not override `ready()`.
+        default_get_virtual_fn: unsafe extern "C" fn(
+            p_userdata: *mut std::os::raw::c_void,
+            p_name: sys::GDExtensionConstStringNamePtr,
+        ) -> sys::GDExtensionClassCallVirtual,
     },
 
     /// Collected from `#[godot_api] impl MyClass`
@@ -74,6 +109,12 @@ pub enum PluginComponent {
         generated_register_fn: ErasedRegisterFn,
     },
 
+    /// Collected from `#[godot_api] impl ScriptIterable for MyClass`
+    UserIteration {
+        /// Callback to library-generated function which registers the GDScript iteration methods
+        generated_register_fn: ErasedRegisterFn,
+    },
+
     /// Collected from `#[godot_api] impl GodotExt for MyClass`
     UserVirtuals {
         /// Callback to user-defined `register_class` function
@@ -117,7 +158,10 @@ pub enum PluginComponent {
 struct ClassRegistrationInfo {
     class_name: ClassName,
     parent_class_name: Option<ClassName>,
+    is_instantiable: bool,
+    is_singleton: bool,
     generated_register_fn: Option<ErasedRegisterFn>,
+    iteration_register_fn: Option<ErasedRegisterFn>,
     user_register_fn: Option<ErasedRegisterFn>,
     godot_params: sys::GDExtensionClassCreationInfo,
 }
@@ -150,7 +194,10 @@ pub fn register_class<
     register_class_raw(ClassRegistrationInfo {
         class_name: T::class_name(),
         parent_class_name: Some(T::Base::class_name()),
+        is_instantiable: true,
+        is_singleton: false,
         generated_register_fn: None,
+        iteration_register_fn: None,
         user_register_fn: Some(ErasedRegisterFn {
             raw: callbacks::register_class_by_builder::<T>,
         }),
@@ -158,6 +205,202 @@ pub fn register_class<
     });
 }
 
+/// Description of a class that is not known at compile time, for registration with [`register_class_dynamic()`].
+///
+/// This is a low-level API: the callbacks are passed as-is to Godot, and have to deal with raw GDExtension pointers. The
+/// `create_fn` is responsible to construct the base object and attach an instance to it (`object_set_instance`), while
+/// `free_fn` destroys that instance again. `class_userdata` is passed to both, as well as to `get_virtual_fn`; it can be
+/// used to tell apart multiple classes sharing the same callbacks.
+#[derive(Clone, Debug)]
+pub struct DynamicClassDescriptor {
+    /// Name of the new class. Use [`ClassName::new_dynamic()`] for names determined at runtime.
+    pub class_name: ClassName,
+
+    /// Name of the base class, which must already be registered (engine class or previously registered extension class).
+    pub base_class_name: ClassName,
+
+    /// Godot low-level `create` function. If `None`, the class cannot be instantiated (like `#[class(no_init)]`).
+    pub create_fn: Option<
+        unsafe extern "C" fn(
+            class_userdata: *mut std::ffi::c_void, //
+        ) -> sys::GDExtensionObjectPtr,
+    >,
+
+    /// Godot low-level `free` function, destroying the instance created by `create_fn`.
+    pub free_fn: Option<
+        unsafe extern "C" fn(
+            class_userdata: *mut std::ffi::c_void,
+            instance: sys::GDExtensionClassInstancePtr,
+        ),
+    >,
+
+    /// Callback returning the implementation of a virtual method, or `None` if the class does not override it.
+    pub get_virtual_fn: Option<
+        unsafe extern "C" fn(
+            class_userdata: *mut std::os::raw::c_void,
+            name: sys::GDExtensionConstStringNamePtr,
+        ) -> sys::GDExtensionClassCallVirtual,
+    >,
+
+    /// Custom `to_string` implementation.
+    pub to_string_fn: Option<
+        unsafe extern "C" fn(
+            instance: sys::GDExtensionClassInstancePtr,
+            is_valid: *mut sys::GDExtensionBool,
+            out: sys::GDExtensionStringPtr,
+        ),
+    >,
+
+    /// Custom `_notification` implementation.
+    pub notification_fn: Option<
+        unsafe extern "C" fn(
+            instance: sys::GDExtensionClassInstancePtr, //
+            what: i32,
+        ),
+    >,
+
+    /// Opaque pointer passed to `create_fn`, `free_fn` and `get_virtual_fn`; global per class.
+    pub class_userdata: *mut std::ffi::c_void,
+
+    /// Called after the class is registered, e.g. to register methods and properties through `ClassDb`.
+    pub register_fn: Option<ErasedRegisterFn>,
+}
+
+impl DynamicClassDescriptor {
+    /// Creates a descriptor for a class without any callbacks, which can be filled in afterwards.
+    pub fn new(class_name: ClassName, base_class_name: ClassName) -> Self {
+        Self {
+            class_name,
+            base_class_name,
+            create_fn: None,
+            free_fn: None,
+            get_virtual_fn: None,
+            to_string_fn: None,
+            notification_fn: None,
+            class_userdata: ptr::null_mut(),
+            register_fn: None,
+        }
+    }
+}
+
+/// Error returned by [`register_class_dynamic()`] and [`unregister_class_dynamic()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ClassRegistrationError {
+    /// A class with this name is already registered, either by the engine, by another extension or by this library.
+    NameCollision(ClassName),
+
+    /// The base class is not registered with Godot.
+    UnknownBaseClass(ClassName),
+
+    /// The class was not registered through [`register_class_dynamic()`], or has already been unregistered.
+    NotDynamic(ClassName),
+
+    /// The class cannot be unregistered, because other dynamic classes still inherit from it.
+    HasSubclasses(ClassName),
+}
+
+impl fmt::Display for ClassRegistrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NameCollision(name) => write!(f, "class `{name}` is already registered"),
+            Self::UnknownBaseClass(name) => write!(f, "base class `{name}` is not registered"),
+            Self::NotDynamic(name) => write!(f, "class `{name}` was not registered dynamically"),
+            Self::HasSubclasses(name) => {
+                write!(
+                    f,
+                    "class `{name}` still has dynamically registered subclasses"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClassRegistrationError {}
+
+/// Registers a class with type information only known at runtime, e.g. loaded from a mod.
+///
+/// The class is available to Godot (`ClassDB`, GDScript, the editor) as soon as this function returns. It stays registered until
+/// [`unregister_class_dynamic()`] is called, or until the `Scene` init level is deinitialized, at which point all remaining dynamic
+/// classes are unregistered automatically (subclasses before their base classes).
+///
+/// This uses the same code path as [`auto_register_classes()`], but validates the names up front instead of panicking.
+pub fn register_class_dynamic(
+    descriptor: DynamicClassDescriptor,
+) -> Result<(), ClassRegistrationError> {
+    use crate::engine::ClassDb;
+
+    let class_name = descriptor.class_name;
+    let base_class_name = descriptor.base_class_name;
+
+    let class_db = ClassDb::singleton();
+    if class_db.class_exists(class_name.to_string_name()) {
+        return Err(ClassRegistrationError::NameCollision(class_name));
+    }
+    if !class_db.class_exists(base_class_name.to_string_name()) {
+        return Err(ClassRegistrationError::UnknownBaseClass(base_class_name));
+    }
+
+    let godot_params = sys::GDExtensionClassCreationInfo {
+        create_instance_func: descriptor.create_fn,
+        free_instance_func: descriptor.free_fn,
+        get_virtual_func: descriptor.get_virtual_fn,
+        to_string_func: descriptor.to_string_fn,
+        notification_func: descriptor.notification_fn,
+        class_userdata: descriptor.class_userdata,
+        ..default_creation_info()
+    };
+
+    out!("Register dynamic class: {class_name}");
+    register_class_raw(ClassRegistrationInfo {
+        parent_class_name: Some(base_class_name),
+        is_instantiable: descriptor.create_fn.is_some(),
+        user_register_fn: descriptor.register_fn,
+        godot_params,
+        ..default_registration_info(class_name)
+    });
+
+    DYNAMIC_CLASSES
+        .lock()
+        .unwrap()
+        .push((class_name, base_class_name));
+    Ok(())
+}
+
+/// Unregisters a class previously registered with [`register_class_dynamic()`].
+///
+/// All instances of the class must have been freed before. Subclasses that were registered dynamically must be unregistered first.
+pub fn unregister_class_dynamic(class_name: ClassName) -> Result<(), ClassRegistrationError> {
+    let mut classes = DYNAMIC_CLASSES.lock().unwrap();
+
+    let index = classes
+        .iter()
+        .position(|(name, _)| *name == class_name)
+        .ok_or(ClassRegistrationError::NotDynamic(class_name))?;
+
+    if classes.iter().any(|(_, base)| *base == class_name) {
+        return Err(ClassRegistrationError::HasSubclasses(class_name));
+    }
+
+    classes.remove(index);
+    drop(classes);
+
+    unregister_class_raw(class_name);
+    Ok(())
+}
+
+/// Unregisters all remaining dynamic classes, in reverse order of registration (so subclasses come before base classes).
+pub(crate) fn unregister_dynamic_classes() {
+    let classes = std::mem::take(&mut *DYNAMIC_CLASSES.lock().unwrap());
+
+    for (class_name, _) in classes.into_iter().rev() {
+        unregister_class_raw(class_name);
+    }
+}
+
+// Mutex: needed for global access (Sync). Stores (class, base class) pairs, in order of registration.
+static DYNAMIC_CLASSES: std::sync::Mutex<Vec<(ClassName, ClassName)>> =
+    std::sync::Mutex::new(Vec::new());
+
 /// Lets Godot know about all classes that have self-registered through the plugin system.
 pub fn auto_register_classes() {
     out!("Auto-register classes...");
@@ -182,14 +425,73 @@ pub fn auto_register_classes() {
 
     //out!("Class-map: {map:#?}");
 
+    let mut singleton_classes = Vec::new();
     for info in map.into_values() {
         out!("Register class:   {}", info.class_name);
+        if info.is_singleton {
+            singleton_classes.push(info.class_name);
+        }
         register_class_raw(info);
     }
 
     out!("All classes auto-registered.");
+
+    // Singletons may depend on any other class, so they are instantiated only once all classes are known to Godot.
+    for class_name in singleton_classes {
+        register_singleton(class_name);
+    }
 }
 
+/// Instantiates the class and registers the instance as engine singleton of the same name.
+fn register_singleton(class_name: ClassName) {
+    use crate::engine::{ClassDb, Engine, Object};
+
+    let name = class_name.to_string_name();
+    let mut engine = Engine::singleton();
+    assert!(
+        !engine.has_singleton(name.clone()),
+        "cannot register #[class(singleton)] `{class_name}`: a singleton with that name already exists"
+    );
+
+    let instance = ClassDb::singleton()
+        .instantiate(name.clone())
+        .try_to::<Gd<Object>>()
+        .unwrap_or_else(|_| panic!("failed to instantiate #[class(singleton)] `{class_name}`"));
+
+    out!("Register singleton: {class_name}");
+    let instance_id = instance.instance_id();
+    engine.register_singleton(name, instance);
+
+    REGISTERED_SINGLETONS
+        .lock()
+        .unwrap()
+        .push((class_name.to_string(), instance_id));
+}
+
+/// Unregisters and frees all `#[class(singleton)]` instances, in reverse order of registration.
+pub(crate) fn unregister_singletons() {
+    use crate::engine::{Engine, Object};
+
+    let singletons = std::mem::take(&mut *REGISTERED_SINGLETONS.lock().unwrap());
+    if singletons.is_empty() {
+        return;
+    }
+
+    let mut engine = Engine::singleton();
+    for (name, instance_id) in singletons.into_iter().rev() {
+        out!("Unregister singleton: {name}");
+        engine.unregister_singleton(name.as_str().into());
+
+        if let Some(instance) = Gd::<Object>::try_from_instance_id(instance_id) {
+            instance.free();
+        }
+    }
+}
+
+// Mutex: needed for global access (Sync). Instance IDs instead of Gd, because the latter is not Send.
+static REGISTERED_SINGLETONS: std::sync::Mutex<Vec<(String, InstanceId)>> =
+    std::sync::Mutex::new(Vec::new());
+
 /// Populate `c` with all the relevant data from `component` (depending on component type).
 fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
     // out!("|   reg (before):    {c:?}");
@@ -197,15 +499,31 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
     match component {
         PluginComponent::ClassDef {
             base_class_name,
+            is_instantiable,
+            is_singleton,
+            is_hidden,
             generated_create_fn,
             free_fn,
+            property_can_revert_fn,
+            property_get_revert_fn,
+            default_get_virtual_fn,
         } => {
             c.parent_class_name = Some(base_class_name);
+            c.is_instantiable = is_instantiable;
+            c.is_singleton = is_singleton;
+            c.godot_params.is_virtual = is_hidden as u8;
             fill_into(
                 &mut c.godot_params.create_instance_func,
                 generated_create_fn,
             );
             c.godot_params.free_instance_func = Some(free_fn);
+            c.godot_params.property_can_revert_func = Some(property_can_revert_fn);
+            c.godot_params.property_get_revert_func = Some(property_get_revert_fn);
+
+            // Plugins arrive in unspecified order; the virtual callback from UserVirtuals takes precedence.
+            if c.godot_params.get_virtual_func.is_none() {
+                c.godot_params.get_virtual_func = Some(default_get_virtual_fn);
+            }
         }
 
         PluginComponent::UserMethodBinds {
@@ -214,6 +532,12 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
             c.generated_register_fn = Some(generated_register_fn);
         }
 
+        PluginComponent::UserIteration {
+            generated_register_fn,
+        } => {
+            c.iteration_register_fn = Some(generated_register_fn);
+        }
+
         PluginComponent::UserVirtuals {
             user_register_fn,
             user_create_fn,
@@ -250,6 +574,16 @@ fn register_class_raw(info: ClassRegistrationInfo) {
         .parent_class_name
         .expect("class defined (parent_class_name)");
 
+    // Plugins are collected in unspecified order, so this can only be validated once all components are known.
+    assert!(
+        info.is_instantiable || info.godot_params.create_instance_func.is_none(),
+        "class `{class_name}` is declared with #[class(no_init)], but provides an `init` constructor",
+    );
+    assert!(
+        !info.is_singleton || info.godot_params.create_instance_func.is_some(),
+        "class `{class_name}` is declared with #[class(singleton)], but has no `init` constructor",
+    );
+
     unsafe {
         // Try to register class...
         #[allow(clippy::let_unit_value)] // notifies us if Godot API ever adds a return type.
@@ -279,11 +613,26 @@ fn register_class_raw(info: ClassRegistrationInfo) {
     if let Some(register_fn) = info.generated_register_fn {
         (register_fn.raw)(&mut class_builder);
     }
+    if let Some(register_fn) = info.iteration_register_fn {
+        (register_fn.raw)(&mut class_builder);
+    }
     if let Some(register_fn) = info.user_register_fn {
         (register_fn.raw)(&mut class_builder);
     }
 }
 
+fn unregister_class_raw(class_name: ClassName) {
+    out!("Unregister class: {class_name}");
+
+    unsafe {
+        #[allow(clippy::let_unit_value)]
+        let _: () = interface_fn!(classdb_unregister_extension_class)(
+            sys::get_library(),
+            class_name.string_sys(),
+        );
+    }
+}
+
 /// Callbacks that are passed as function pointers to Godot upon class registration.
 ///
 /// Re-exported to `crate::private`
@@ -315,7 +664,7 @@ pub mod callbacks {
         let base = unsafe { Base::from_sys(base_ptr) };
         let user_instance = make_user_instance(base);
 
-        let instance = InstanceStorage::<T>::construct(user_instance);
+        let instance = InstanceStorage::<T>::construct(user_instance, base_ptr);
         let instance_ptr = instance.into_raw();
         let instance_ptr = instance_ptr as sys::GDExtensionClassInstancePtr;
 
@@ -330,6 +679,12 @@ pub mod callbacks {
             );
         }
 
+        // Godot stores RPC configurations per node, so apply those from #[rpc] to every instance.
+        if T::inherits::<crate::engine::Node>() {
+            let mut node = unsafe { Gd::<crate::engine::Node>::from_obj_sys(base_ptr) };
+            crate::builtin::meta::registration::rpc::configure_node(class_name, &mut node);
+        }
+
         // std::mem::forget(class_name);
         // std::mem::forget(base_class_name);
         base_ptr
@@ -347,18 +702,72 @@ pub mod callbacks {
         crate::storage::destroy_storage::<T>(instance);
     }
 
-    pub unsafe extern "C" fn get_virtual<T: cap::ImplementsGodotVirtual>(
-        _class_user_data: *mut std::ffi::c_void,
+    pub unsafe extern "C" fn property_can_revert<T: cap::ImplementsGodotExports>(
+        _instance: sys::GDExtensionClassInstancePtr,
         name: sys::GDExtensionConstStringNamePtr,
-    ) -> sys::GDExtensionClassCallVirtual {
+    ) -> sys::GDExtensionBool {
+        let property = borrowed_string_name(name);
+        super::property_default::<T>(&property).is_some() as sys::GDExtensionBool
+    }
+
+    pub unsafe extern "C" fn property_get_revert<T: cap::ImplementsGodotExports>(
+        _instance: sys::GDExtensionClassInstancePtr,
+        name: sys::GDExtensionConstStringNamePtr,
+        ret: sys::GDExtensionVariantPtr,
+    ) -> sys::GDExtensionBool {
+        let property = borrowed_string_name(name);
+
+        match super::property_default::<T>(&property) {
+            Some(value) => {
+                // Godot passes an initialized variant, which is overwritten (and thus destroyed).
+                *(ret as *mut Variant) = value;
+                true as sys::GDExtensionBool
+            }
+            None => false as sys::GDExtensionBool,
+        }
+    }
+
+    /// Converts a string name owned by Godot, without taking ownership.
+    unsafe fn borrowed_string_name(name: sys::GDExtensionConstStringNamePtr) -> String {
         // This string is not ours, so we cannot call the destructor on it.
         let borrowed_string = StringName::from_string_sys(sys::force_mut_ptr(name));
-        let method_name = borrowed_string.to_string();
+        let string = borrowed_string.to_string();
         std::mem::forget(borrowed_string);
+        string
+    }
 
+    pub unsafe extern "C" fn get_virtual<T: cap::ImplementsGodotVirtual>(
+        _class_user_data: *mut std::ffi::c_void,
+        name: sys::GDExtensionConstStringNamePtr,
+    ) -> sys::GDExtensionClassCallVirtual {
+        let method_name = borrowed_string_name(name);
         T::__virtual_call(method_name.as_str())
     }
 
+    /// Only provides `_ready`, if needed to initialize `OnReady` fields.
+    pub unsafe extern "C" fn get_virtual_default<T: cap::GodotBeforeReady>(
+        _class_user_data: *mut std::ffi::c_void,
+        name: sys::GDExtensionConstStringNamePtr,
+    ) -> sys::GDExtensionClassCallVirtual {
+        if T::HAS_ONREADY_FIELDS && borrowed_string_name(name) == "_ready" {
+            Some(before_ready::<T>)
+        } else {
+            None
+        }
+    }
+
+    /// Virtual `_ready` for classes that do not override `ready()`, but have `OnReady` fields.
+    pub unsafe extern "C" fn before_ready<T: cap::GodotBeforeReady>(
+        instance: sys::GDExtensionClassInstancePtr,
+        _args: *const sys::GDExtensionConstTypePtr,
+        _ret: sys::GDExtensionTypePtr,
+    ) {
+        crate::private::handle_panic(
+            || format!("{}::ready", T::class_name()),
+            || crate::private::before_ready(as_storage::<T>(instance)),
+        );
+    }
+
     pub unsafe extern "C" fn to_string<T: cap::GodotToString>(
         instance: sys::GDExtensionClassInstancePtr,
         _is_valid: *mut sys::GDExtensionBool,
@@ -434,6 +843,34 @@ pub mod callbacks {
     }
 }
 
+thread_local! {
+    // Variant is not Send, so the cache is thread-local; the editor queries defaults on the main thread.
+    // Absent defaults are cached as well, as the editor queries every property whenever the inspector is redrawn.
+    static PROPERTY_DEFAULTS: RefCell<HashMap<(ClassName, String), Option<Variant>>> = RefCell::new(HashMap::new());
+}
+
+/// Returns the default value of the exported `property`, i.e. its field initializer in `#[class(init)]`.
+///
+/// No instance is constructed, so `init` side effects do not run, and this also works for singletons or `no_init` classes
+/// (which have no defaults). The value is computed once and stored as variant, so fields need not be `Clone`.
+fn property_default<T: cap::ImplementsGodotExports>(property: &str) -> Option<Variant> {
+    let key = (T::class_name(), property.to_string());
+    if let Some(value) = PROPERTY_DEFAULTS.with(|defaults| defaults.borrow().get(&key).cloned()) {
+        return value;
+    }
+
+    // Outside the borrow, as the initializer may call into Godot.
+    let value = T::__exported_property_default(property);
+    PROPERTY_DEFAULTS.with(|defaults| defaults.borrow_mut().insert(key, value.clone()));
+    value
+}
+
+/// Releases cached property defaults, while the engine is still alive.
+pub(crate) fn clear_property_defaults() {
+    let defaults = PROPERTY_DEFAULTS.with(|defaults| std::mem::take(&mut *defaults.borrow_mut()));
+    drop(defaults);
+}
+
 // Substitute for Default impl
 // Yes, bindgen can implement Default, but only for _all_ types (with single exceptions).
 // For FFI types, it's better to have explicit initialization in the general case though.
@@ -441,7 +878,10 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
     ClassRegistrationInfo {
         class_name,
         parent_class_name: None,
+        is_instantiable: true,
+        is_singleton: false,
         generated_register_fn: None,
+        iteration_register_fn: None,
         user_register_fn: None,
         godot_params: default_creation_info(),
     }
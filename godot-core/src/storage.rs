@@ -4,7 +4,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::obj::GodotClass;
+use crate::obj::{Gd, GodotClass};
 use crate::out;
 use godot_ffi as sys;
 
@@ -31,12 +31,14 @@ mod single_threaded {
 
     use crate::obj::GodotClass;
     use crate::out;
+    use godot_ffi as sys;
 
     use super::Lifecycle;
 
     /// Manages storage and lifecycle of user's extension class instances.
     pub struct InstanceStorage<T: GodotClass> {
         user_instance: cell::RefCell<T>,
+        pub(super) base_ptr: sys::GDExtensionObjectPtr,
 
         // Declared after `user_instance`, is dropped last
         pub lifecycle: cell::Cell<Lifecycle>,
@@ -45,11 +47,12 @@ mod single_threaded {
 
     /// For all Godot extension classes
     impl<T: GodotClass> InstanceStorage<T> {
-        pub fn construct(user_instance: T) -> Self {
+        pub fn construct(user_instance: T, base_ptr: sys::GDExtensionObjectPtr) -> Self {
             out!("    Storage::construct             <{}>", type_name::<T>());
 
             Self {
                 user_instance: cell::RefCell::new(user_instance),
+                base_ptr,
                 lifecycle: cell::Cell::new(Lifecycle::Alive),
                 godot_ref_count: cell::Cell::new(1),
             }
@@ -115,6 +118,7 @@ mod multi_threaded {
 
     use crate::obj::GodotClass;
     use crate::out;
+    use godot_ffi as sys;
 
     use super::Lifecycle;
 
@@ -146,6 +150,7 @@ mod multi_threaded {
     /// Manages storage and lifecycle of user's extension class instances.
     pub struct InstanceStorage<T: GodotClass> {
         user_instance: sync::RwLock<T>,
+        pub(super) base_ptr: sys::GDExtensionObjectPtr,
 
         // Declared after `user_instance`, is dropped last
         pub lifecycle: AtomicLifecycle,
@@ -154,11 +159,12 @@ mod multi_threaded {
 
     /// For all Godot extension classes
     impl<T: GodotClass> InstanceStorage<T> {
-        pub fn construct(user_instance: T) -> Self {
+        pub fn construct(user_instance: T, base_ptr: sys::GDExtensionObjectPtr) -> Self {
             out!("    Storage::construct             <{}>", type_name::<T>());
 
             Self {
                 user_instance: sync::RwLock::new(user_instance),
+                base_ptr,
                 lifecycle: AtomicLifecycle::new(Lifecycle::Alive),
                 godot_ref_count: AtomicU32::new(1),
             }
@@ -233,6 +239,14 @@ impl<T: GodotClass> InstanceStorage<T> {
         Box::into_raw(Box::new(self))
     }
 
+    /// Returns a new smart pointer to the Godot object that owns this instance.
+    ///
+    /// Does not borrow the user instance, so this can be called while a `GdRef` or `GdMut` guard is live.
+    pub fn get_gd(&self) -> Gd<T> {
+        // SAFETY: the storage is owned by the object behind `base_ptr`, so the object is alive as long as the storage is.
+        unsafe { Gd::from_obj_sys(self.base_ptr) }
+    }
+
     pub fn mark_destroyed_by_godot(&self) {
         out!(
             "    Storage::mark_destroyed_by_godot", // -- {:?}",
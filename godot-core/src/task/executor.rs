@@ -0,0 +1,211 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use crate::engine::utilities;
+use crate::obj::{Gd, GodotClass, InstanceId};
+
+thread_local! {
+    // Futures are not Send, so tasks live on the thread that spawned them (the main thread) and are polled there.
+    static TASKS: RefCell<Vec<Task>> = RefCell::new(Vec::new());
+}
+
+/// Starts running `future` on the main thread.
+///
+/// The future is polled immediately, so everything up to the first `.await` that is not ready runs before this returns.
+/// Afterwards, it advances whenever it has been woken and [`poll_tasks()`] is called.
+///
+/// Dropping the returned handle does not cancel the task; use [`TaskHandle::cancel()`] for that. Tasks still pending when
+/// the library is unloaded are dropped without completing.
+pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
+    spawn_task(future, None)
+}
+
+/// Like [`spawn()`], but the task is cancelled once the object `owner` is freed.
+///
+/// The future is dropped without being polled again; this is checked before each poll, and on every [`poll_tasks()`], so
+/// the future is also released if it is not woken anymore. This is what `async` `#[func]` methods use, so they never
+/// resume on a destroyed object.
+///
+/// If the future itself holds a `Gd` to a reference-counted `owner`, the object is only freed once the future completes.
+pub fn spawn_bound<T: GodotClass>(
+    owner: &Gd<T>,
+    future: impl Future<Output = ()> + 'static,
+) -> TaskHandle {
+    spawn_task(future, Some(owner.instance_id()))
+}
+
+fn spawn_task(future: impl Future<Output = ()> + 'static, owner: Option<InstanceId>) -> TaskHandle {
+    let task = Task {
+        future: Box::pin(future),
+        waker: Arc::new(TaskWaker {
+            woken: AtomicBool::new(false),
+        }),
+        state: Rc::new(Cell::new(TaskState::Pending)),
+        owner,
+    };
+
+    let handle = TaskHandle {
+        state: task.state.clone(),
+    };
+
+    if let Some(task) = task.poll() {
+        TASKS.with(|tasks| tasks.borrow_mut().push(task));
+    }
+    handle
+}
+
+/// Polls all tasks that have been woken since the last call, i.e. those which can make progress.
+///
+/// Must be called regularly on the main thread, usually once per frame; see the [module docs](super) for an example.
+/// Tasks spawned or woken during this call are polled on the next one.
+pub fn poll_tasks() {
+    // Take tasks out of the list while polling, so they can spawn or cancel other tasks.
+    // Tasks whose owner was freed are taken as well; poll() then drops them.
+    let woken: Vec<Task> = TASKS.with(|tasks| {
+        let mut tasks = tasks.borrow_mut();
+        let (woken, sleeping) = tasks
+            .drain(..)
+            .partition(|task| task.is_owner_freed() || task.take_woken());
+        *tasks = sleeping;
+        woken
+    });
+
+    let still_pending: Vec<Task> = woken.into_iter().filter_map(Task::poll).collect();
+
+    TASKS.with(|tasks| tasks.borrow_mut().extend(still_pending));
+}
+
+/// Drops all pending tasks, before the engine shuts down.
+pub(crate) fn cancel_all_tasks() {
+    // Futures' destructors may spawn follow-up tasks (e.g. to release a resource request), so repeat until none are left.
+    loop {
+        let tasks = TASKS.with(|tasks| std::mem::take(&mut *tasks.borrow_mut()));
+        if tasks.is_empty() {
+            break;
+        }
+
+        for task in tasks.iter() {
+            task.state.set(TaskState::Cancelled);
+        }
+
+        // Drop outside of borrow, as futures' destructors may touch the task list.
+        drop(tasks);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Handle to a task started with [`spawn()`].
+#[derive(Clone, Debug)]
+pub struct TaskHandle {
+    state: Rc<Cell<TaskState>>,
+}
+
+impl TaskHandle {
+    /// Returns `true` if the task has neither completed nor been cancelled.
+    pub fn is_pending(&self) -> bool {
+        self.state.get() == TaskState::Pending
+    }
+
+    /// Returns `true` if the task has run to completion.
+    pub fn is_finished(&self) -> bool {
+        self.state.get() == TaskState::Finished
+    }
+
+    /// Stops the task and drops its future, unless it has already finished. Has no effect if called repeatedly.
+    pub fn cancel(self) {
+        if !self.is_pending() {
+            return;
+        }
+        self.state.set(TaskState::Cancelled);
+
+        // If the task is currently being polled (i.e. cancels itself), it is dropped after returning from poll().
+        let cancelled: Vec<Task> = TASKS.with(|tasks| {
+            let mut tasks = tasks.borrow_mut();
+            let (cancelled, remaining) = tasks
+                .drain(..)
+                .partition(|task| Rc::ptr_eq(&task.state, &self.state));
+            *tasks = remaining;
+            cancelled
+        });
+        drop(cancelled);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation details
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum TaskState {
+    Pending,
+    Finished,
+    Cancelled,
+}
+
+struct Task {
+    future: Pin<Box<dyn Future<Output = ()>>>,
+    waker: Arc<TaskWaker>,
+    state: Rc<Cell<TaskState>>,
+
+    /// Object whose destruction cancels the task, for [`spawn_bound()`].
+    owner: Option<InstanceId>,
+}
+
+impl Task {
+    fn take_woken(&self) -> bool {
+        self.waker.woken.swap(false, Ordering::AcqRel)
+    }
+
+    fn is_owner_freed(&self) -> bool {
+        self.owner
+            .map_or(false, |id| !utilities::is_instance_id_valid(id.to_i64()))
+    }
+
+    /// Polls the future once; returns the task if it is still pending afterwards.
+    fn poll(mut self) -> Option<Self> {
+        if self.is_owner_freed() {
+            self.state.set(TaskState::Cancelled);
+            return None;
+        }
+
+        let waker = Waker::from(self.waker.clone());
+        let mut context = Context::from_waker(&waker);
+
+        match self.future.as_mut().poll(&mut context) {
+            Poll::Ready(()) => {
+                if self.state.get() == TaskState::Pending {
+                    self.state.set(TaskState::Finished);
+                }
+                None
+            }
+            Poll::Pending if self.state.get() == TaskState::Cancelled => None,
+            Poll::Pending => Some(self),
+        }
+    }
+}
+
+/// Wakers may be sent to other threads, so waking only sets a flag, which is checked by [`poll_tasks()`].
+struct TaskWaker {
+    woken: AtomicBool,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::Release);
+    }
+}
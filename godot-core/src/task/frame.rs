@@ -0,0 +1,86 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::engine::{Engine, SceneTree};
+use crate::task::TaskError;
+
+/// Returns a future that resolves in the next process frame, i.e. once the `SceneTree` has emitted `process_frame`.
+///
+/// Resolves to [`TaskError::NoSceneTree`] if the main loop is not a `SceneTree`.
+pub fn next_process_frame() -> NextFrame {
+    NextFrame::new(FrameKind::Process)
+}
+
+/// Returns a future that resolves in the next physics frame, i.e. once the `SceneTree` has emitted `physics_frame`.
+///
+/// Resolves to [`TaskError::NoSceneTree`] if the main loop is not a `SceneTree`.
+pub fn next_physics_frame() -> NextFrame {
+    NextFrame::new(FrameKind::Physics)
+}
+
+/// Future returned by [`next_process_frame()`] and [`next_physics_frame()`].
+///
+/// The frame is counted from the time the future is created, not when it is first awaited.
+#[must_use = "futures do nothing unless awaited"]
+#[derive(Debug)]
+pub struct NextFrame {
+    kind: FrameKind,
+
+    /// Frame counter at creation, or `None` if there is no scene tree.
+    start_frame: Option<u64>,
+}
+
+impl NextFrame {
+    fn new(kind: FrameKind) -> Self {
+        let has_tree = Engine::singleton()
+            .get_main_loop()
+            .and_then(|main_loop| main_loop.try_cast::<SceneTree>())
+            .is_some();
+
+        Self {
+            kind,
+            start_frame: has_tree.then(|| kind.current_frame()),
+        }
+    }
+}
+
+impl Future for NextFrame {
+    type Output = Result<(), TaskError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Some(start_frame) = self.start_frame else {
+            return Poll::Ready(Err(TaskError::NoSceneTree));
+        };
+
+        if self.kind.current_frame() > start_frame {
+            Poll::Ready(Ok(()))
+        } else {
+            // Frame counters change without notification, so check again on the next poll_tasks().
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum FrameKind {
+    Process,
+    Physics,
+}
+
+impl FrameKind {
+    fn current_frame(self) -> u64 {
+        let engine = Engine::singleton();
+        match self {
+            Self::Process => engine.get_process_frames(),
+            Self::Physics => engine.get_physics_frames(),
+        }
+    }
+}
@@ -0,0 +1,192 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::builtin::meta::ClassName;
+use crate::builtin::GodotString;
+use crate::engine::resource_loader::ThreadLoadStatus;
+use crate::engine::{global, Resource, ResourceLoader};
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// Loads a resource in the background, returning a future that resolves once it is available.
+///
+/// This is the async equivalent of [`try_load()`][crate::engine::try_load], based on `ResourceLoader.load_threaded_request()`.
+/// Loading starts immediately and runs on Godot's worker threads, so the main thread is not blocked, even for large scenes.
+/// The future checks the loader status on every [`poll_tasks()`][super::poll_tasks], and resolves on the first one after
+/// loading has finished.
+///
+/// Resolves to [`LoadError::Failed`] if the resource cannot be loaded, and to [`LoadError::WrongType`] if it is not of type `T`
+/// or inherited.
+///
+/// Dropping the future before it resolves does not abort loading, as Godot does not support that. The result is discarded once
+/// loading has finished, so it is not kept in the loader.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::task;
+///
+/// task::spawn(async {
+///     match task::load_async::<PackedScene>("res://levels/Level2.tscn").await {
+///         Ok(scene) => godot_print!("loaded {}", scene.get_path()),
+///         Err(err) => godot_error!("{err}"),
+///     }
+/// });
+/// ```
+pub fn load_async<T>(path: impl Into<GodotString>) -> LoadFuture<T>
+where
+    T: GodotClass + Inherits<Resource>,
+{
+    let path = path.into();
+    let error = ResourceLoader::singleton()
+        .load_threaded_request_ex(path.clone())
+        .type_hint(T::class_name().to_godot_string())
+        .done();
+
+    LoadFuture {
+        path,
+        requested: error == global::Error::OK,
+        _marker: PhantomData,
+    }
+}
+
+/// Future returned by [`load_async()`].
+#[must_use = "futures do nothing unless awaited"]
+pub struct LoadFuture<T> {
+    path: GodotString,
+
+    /// Whether the request is still registered in the loader, i.e. the result has not been collected yet.
+    requested: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Future for LoadFuture<T>
+where
+    T: GodotClass + Inherits<Resource>,
+{
+    type Output = Result<Gd<T>, LoadError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let failed = |path: &GodotString| LoadError::Failed { path: path.clone() };
+
+        if !self.requested {
+            return Poll::Ready(Err(failed(&self.path)));
+        }
+
+        let status = ResourceLoader::singleton().load_threaded_get_status(self.path.clone());
+        if status == ThreadLoadStatus::THREAD_LOAD_IN_PROGRESS {
+            // Loading progresses on other threads without notification; check again on the next poll_tasks().
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        // Collecting the result also releases the request, both on success and failure.
+        let resource = if status == ThreadLoadStatus::THREAD_LOAD_INVALID_RESOURCE {
+            None
+        } else {
+            ResourceLoader::singleton().load_threaded_get(self.path.clone())
+        };
+        self.requested = false;
+
+        let Some(resource) = resource else {
+            return Poll::Ready(Err(failed(&self.path)));
+        };
+
+        let actual = resource.get_class();
+        let result = resource
+            .try_cast::<T>()
+            .ok_or_else(|| LoadError::WrongType {
+                path: self.path.clone(),
+                expected: T::class_name(),
+                actual,
+            });
+
+        Poll::Ready(result)
+    }
+}
+
+impl<T> Drop for LoadFuture<T> {
+    fn drop(&mut self) {
+        if self.requested {
+            super::spawn(Release {
+                path: std::mem::take(&mut self.path),
+            });
+        }
+    }
+}
+
+impl<T> fmt::Debug for LoadFuture<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoadFuture")
+            .field("path", &self.path)
+            .field("requested", &self.requested)
+            .finish()
+    }
+}
+
+/// Error returned by [`load_async()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum LoadError {
+    /// The resource could not be loaded, e.g. because the file does not exist or is invalid. Godot prints the details.
+    Failed { path: GodotString },
+
+    /// The resource was loaded, but its class is neither `expected` nor derived from it.
+    WrongType {
+        path: GodotString,
+        expected: ClassName,
+        actual: GodotString,
+    },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Failed { path } => write!(f, "failed to load resource at `{path}`"),
+            Self::WrongType {
+                path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "resource at `{path}` has class {actual}, expected {expected}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation details
+
+/// Task collecting the result of an abandoned request once loading has finished, so the loader does not keep it forever.
+struct Release {
+    path: GodotString,
+}
+
+impl Future for Release {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut loader = ResourceLoader::singleton();
+
+        let status = loader.load_threaded_get_status(self.path.clone());
+        if status == ThreadLoadStatus::THREAD_LOAD_IN_PROGRESS {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        if status != ThreadLoadStatus::THREAD_LOAD_INVALID_RESOURCE {
+            loader.load_threaded_get(self.path.clone());
+        }
+        Poll::Ready(())
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Async tasks running on the main thread, advanced once per frame.
+//!
+//! Futures are started with [`spawn()`] and make progress whenever [`poll_tasks()`] is called. Polling is not automatic, so
+//! you decide at which point of the frame tasks run. Typically, it is called from the `process()` and `physics_process()`
+//! callbacks of a node that stays in the tree, such as an autoload:
+//!
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::task;
+//!
+//! #[derive(GodotClass)]
+//! #[class(init, base=Node)]
+//! struct TaskDriver;
+//!
+//! #[godot_api]
+//! impl NodeVirtual for TaskDriver {
+//!     fn ready(&mut self) {
+//!         task::spawn(async {
+//!             task::next_process_frame().await.unwrap();
+//!             godot_print!("one frame later");
+//!         });
+//!     }
+//!
+//!     fn process(&mut self, _delta: f64) {
+//!         task::poll_tasks();
+//!     }
+//!
+//!     fn physics_process(&mut self, _delta: f64) {
+//!         task::poll_tasks();
+//!     }
+//! }
+//! ```
+
+mod executor;
+mod frame;
+mod load;
+mod signal;
+mod timer;
+
+pub use executor::{poll_tasks, spawn, spawn_bound, TaskHandle};
+pub use frame::{next_physics_frame, next_process_frame, NextFrame};
+pub use load::{load_async, LoadError, LoadFuture};
+pub use signal::SignalFuture;
+pub use timer::{timeout, Timeout};
+
+pub(crate) use executor::cancel_all_tasks;
+
+use std::fmt;
+
+/// Error returned by the futures in this module.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TaskError {
+    /// The engine's main loop is not a `SceneTree`, e.g. when running a custom `MainLoop` or during startup/shutdown.
+    NoSceneTree,
+
+    /// The object that was awaited has been freed, e.g. before emitting the signal of a [`SignalFuture`].
+    ObjectFreed,
+}
+
+impl fmt::Display for TaskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSceneTree => write!(f, "no SceneTree available as main loop"),
+            Self::ObjectFreed => write!(f, "awaited object has been freed"),
+        }
+    }
+}
+
+impl std::error::Error for TaskError {}
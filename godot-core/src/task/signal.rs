@@ -0,0 +1,85 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+use crate::builtin::{StringName, Variant};
+use crate::engine::{object, Object};
+use crate::obj::closure_relay::OwnedRelay;
+use crate::obj::{EngineEnum, Gd, InstanceId};
+use crate::task::TaskError;
+
+/// Future returned by [`Gd::await_signal()`], resolving with the arguments of the next emission of a signal.
+///
+/// Like the other futures in this module, it is checked on every [`poll_tasks()`][super::poll_tasks], and resolves on the first
+/// one after the signal has been emitted. Signals with any number of parameters are supported.
+///
+/// The connection is created immediately, so emissions between creating and first awaiting the future are not missed. Only the
+/// first emission is recorded. Dropping the future removes the connection.
+///
+/// Resolves to [`TaskError::ObjectFreed`] if the object is freed before emitting the signal.
+#[must_use = "futures do nothing unless awaited"]
+#[derive(Debug)]
+pub struct SignalFuture {
+    source_id: InstanceId,
+
+    /// Arguments of the first emission, once recorded by the relay.
+    emission: Rc<RefCell<Option<Vec<Variant>>>>,
+
+    /// Connected to the signal; dropping it removes the connection.
+    _relay: OwnedRelay,
+}
+
+impl SignalFuture {
+    /// Connects to `signal` of `source`, or returns `None` if there is no such signal.
+    pub(crate) fn connect(mut source: Gd<Object>, signal: StringName) -> Option<Self> {
+        if !source.has_signal(signal.clone()) {
+            return None;
+        }
+
+        let emission = Rc::new(RefCell::new(None));
+        let relay = {
+            let emission = emission.clone();
+            OwnedRelay::new_once(move |args: &[&Variant]| {
+                *emission.borrow_mut() = Some(args.iter().map(|&arg| arg.clone()).collect());
+            })
+        };
+
+        let flags = object::ConnectFlags::CONNECT_ONE_SHOT.ord() as u32;
+        source
+            .connect_ex(signal, relay.callable())
+            .flags(flags)
+            .done();
+
+        Some(Self {
+            source_id: source.instance_id(),
+            emission,
+            _relay: relay,
+        })
+    }
+}
+
+impl Future for SignalFuture {
+    type Output = Result<Vec<Variant>, TaskError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(args) = self.emission.borrow_mut().take() {
+            return Poll::Ready(Ok(args));
+        }
+
+        if Gd::<Object>::try_from_instance_id(self.source_id).is_none() {
+            return Poll::Ready(Err(TaskError::ObjectFreed));
+        }
+
+        // Emissions are recorded by the relay without notifying Rust; check again on the next poll_tasks().
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
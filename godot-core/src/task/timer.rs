@@ -0,0 +1,75 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::engine::{Engine, SceneTree, SceneTreeTimer};
+use crate::obj::Gd;
+use crate::task::TaskError;
+
+/// Returns a future that resolves once `seconds` have passed, measured by a `SceneTreeTimer`.
+///
+/// This is the Rust equivalent of GDScript's `await get_tree().create_timer(seconds).timeout`. The timer is created immediately,
+/// so the time is counted from this call, not from the first `.await`. Like GDScript's default, it is affected by
+/// `Engine.time_scale` and keeps running while the tree is paused. It is processed in idle frames, so the future resolves on the
+/// first [`poll_tasks()`][super::poll_tasks] after the process frame in which the time ran out. A duration of 0 or less resolves
+/// immediately.
+///
+/// No node or signal connection is needed: the timer is owned by the `SceneTree`, which releases it once it has fired. Dropping
+/// the future before that is fine as well.
+///
+/// Resolves to [`TaskError::NoSceneTree`] if the main loop is not a `SceneTree`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::task;
+///
+/// task::spawn(async {
+///     task::timeout(1.5).await.unwrap();
+///     godot_print!("1.5 seconds later");
+/// });
+/// ```
+pub fn timeout(seconds: f64) -> Timeout {
+    let timer = Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>())
+        .map(|mut tree| {
+            tree.create_timer(seconds)
+                .expect("SceneTree::create_timer() returned null")
+        });
+
+    Timeout { timer }
+}
+
+/// Future returned by [`timeout()`].
+#[must_use = "futures do nothing unless awaited"]
+#[derive(Debug)]
+pub struct Timeout {
+    /// Running timer, or `None` if there is no scene tree.
+    timer: Option<Gd<SceneTreeTimer>>,
+}
+
+impl Future for Timeout {
+    type Output = Result<(), TaskError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Some(timer) = &self.timer else {
+            return Poll::Ready(Err(TaskError::NoSceneTree));
+        };
+
+        // The remaining time drops to 0 or below in the frame in which the timer fires.
+        if timer.get_time_left() <= 0.0 {
+            Poll::Ready(Ok(()))
+        } else {
+            // Like frame counters, the timer is not observed; check again on the next poll_tasks().
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
@@ -4,7 +4,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::class::{FieldExport, FieldVar};
+use crate::class::{FieldExport, FieldVar, UsageFlags};
 use proc_macro2::{Ident, TokenStream};
 
 pub struct Field {
@@ -13,6 +13,15 @@ pub struct Field {
     pub default: Option<TokenStream>,
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
+
+    /// Inspector group started at this field, from `#[export(group = ...)]`.
+    pub group: Option<TokenStream>,
+
+    /// Inspector subgroup started at this field, from `#[export(subgroup = ...)]`.
+    pub subgroup: Option<TokenStream>,
+
+    /// Explicit usage flags overriding the inferred ones, from `#[export(usage = ...)]`.
+    pub export_usage: Option<UsageFlags>,
 }
 
 impl Field {
@@ -23,6 +32,9 @@ impl Field {
             default: None,
             var: None,
             export: None,
+            group: None,
+            subgroup: None,
+            export_usage: None,
         }
     }
 }
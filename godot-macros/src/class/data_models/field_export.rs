@@ -31,6 +31,7 @@ pub enum FieldExport {
     Range {
         min: TokenStream,
         max: TokenStream,
+        step: Option<TokenStream>,
         or_greater: bool,
         or_less: bool,
         exp: bool,
@@ -266,6 +267,10 @@ impl FieldExport {
         let min = parser.next_expr()?;
         let max = parser.next_expr()?;
 
+        // Like in GDScript, the step is optional and precedes the options. Options are identifiers, so any other
+        // expression is taken as step.
+        let step = parser.try_next_non_ident_expr()?;
+
         let mut options = HashSet::new();
 
         while let Some(option) = parser.next_any_ident(&ALLOWED_OPTIONS[..])? {
@@ -277,6 +282,7 @@ impl FieldExport {
         Ok(FieldExport::Range {
             min,
             max,
+            step,
             or_greater: options.contains("or_greater"),
             or_less: options.contains("or_less"),
             exp: options.contains("exp"),
@@ -353,22 +359,31 @@ macro_rules! quote_export_func {
 }
 
 impl FieldExport {
-    pub fn to_field_hint(&self) -> FieldHint {
+    /// `field_type` is needed for hints which depend on the type, to validate it at compile time.
+    pub fn to_field_hint(&self, field_type: &venial::TyExpr) -> FieldHint {
         match self {
             FieldExport::Default => FieldHint::Inferred,
 
             FieldExport::Range {
                 min,
                 max,
+                step,
                 or_greater,
                 or_less,
                 exp,
                 radians,
                 degrees,
                 hide_slider,
-            } => quote_export_func! {
-                export_range(#min, #max, #or_greater, #or_less, #exp, #radians, #degrees, #hide_slider)
-            },
+            } => {
+                let step = match step {
+                    Some(step) => quote! { Some(#step) },
+                    None => quote! { None },
+                };
+
+                quote_export_func! {
+                    export_range(#min, #max, #step, #or_greater, #or_less, #exp, #radians, #degrees, #hide_slider)
+                }
+            }
 
             FieldExport::Enum { variants } => {
                 let variants = variants.iter().map(ValueWithKey::to_tuple_expression);
@@ -447,7 +462,10 @@ impl FieldExport {
             FieldExport::PlaceholderText { placeholder } => quote_export_func! {
                 export_placeholder(#placeholder)
             },
-            FieldExport::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
+
+            FieldExport::ColorNoAlpha => quote_export_func! {
+                export_color_no_alpha::<#field_type>()
+            },
         }
     }
 }
@@ -4,13 +4,13 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::{format_ident, quote};
 
 use crate::class::{
     make_existence_check, make_method_registration, Field, FieldHint, FuncDefinition,
 };
-use crate::util::KvParser;
+use crate::util::{bail, KvParser};
 use crate::{util, ParseResult};
 
 /// Store info from `#[var]` attribute.
@@ -71,6 +71,31 @@ impl FieldVar {
             usage_flags,
         })
     }
+
+    /// Parse a `#[serialize]` attribute to a `FieldVar` struct, which is only stored, but not shown in the editor.
+    ///
+    /// Possible keys:
+    /// - `get = expr`
+    /// - `set = expr`
+    pub(crate) fn new_serialized_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
+        let mut getter = GetterSetter::parse(parser, "get")?;
+        let mut setter = GetterSetter::parse(parser, "set")?;
+
+        // Saving needs the getter and loading the setter, so neither can be omitted.
+        if getter.is_omitted() {
+            getter = GetterSetter::Generated;
+        }
+        if setter.is_omitted() {
+            setter = GetterSetter::Generated;
+        }
+
+        Ok(FieldVar {
+            getter,
+            setter,
+            hint: FieldHint::Inferred,
+            usage_flags: UsageFlags::Custom(vec![format_ident!("PROPERTY_USAGE_STORAGE")]),
+        })
+    }
 }
 
 #[derive(Default, Clone, Eq, PartialEq, Debug)]
@@ -87,7 +112,7 @@ pub enum GetterSetter {
 }
 
 impl GetterSetter {
-    pub(super) fn parse(parser: &mut KvParser, key: &str) -> ParseResult<Self> {
+    pub(crate) fn parse(parser: &mut KvParser, key: &str) -> ParseResult<Self> {
         let getter_setter = match parser.handle_any(key) {
             // No `get` argument
             None => GetterSetter::Omitted,
@@ -195,6 +220,12 @@ impl GetterSetterImpl {
             FuncDefinition {
                 func: signature,
                 rename: None,
+                has_gd_self: false,
+                is_virtual: false,
+                is_async: false,
+                vis_marker: None,
+                default_params: Vec::new(),
+                rpc_config: None,
             },
         );
 
@@ -231,4 +262,41 @@ impl UsageFlags {
     pub fn is_inferred(&self) -> bool {
         matches!(self, Self::Inferred)
     }
+
+    /// Parses a bitmask expression like `STORAGE | EDITOR`, from `#[export(usage = ...)]`.
+    ///
+    /// The `PROPERTY_USAGE_` prefix is optional. Flag names are not checked here; unknown ones fail to compile when referring to
+    /// the `PropertyUsageFlags` constant, with the error pointing to the flag.
+    pub(crate) fn parse_bitmask(expr: TokenStream) -> ParseResult<Self> {
+        let mut flags = Vec::new();
+        let mut expect_flag = true;
+
+        for tree in expr.clone() {
+            match tree {
+                TokenTree::Ident(ident) if expect_flag => {
+                    let name = ident.to_string();
+                    let name = name.strip_prefix("PROPERTY_USAGE_").unwrap_or(&name);
+                    flags.push(format_ident!(
+                        "PROPERTY_USAGE_{}",
+                        name,
+                        span = ident.span()
+                    ));
+                }
+                TokenTree::Punct(punct) if !expect_flag && punct.as_char() == '|' => {}
+                other => {
+                    return bail!(
+                        &other,
+                        "expected usage flags separated by `|`, e.g. `usage = STORAGE | EDITOR`"
+                    )
+                }
+            }
+            expect_flag = !expect_flag;
+        }
+
+        if expect_flag {
+            return bail!(&expr, "expected usage flag after `|`");
+        }
+
+        Ok(Self::Custom(flags))
+    }
 }
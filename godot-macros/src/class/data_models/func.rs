@@ -14,6 +14,18 @@ pub struct FuncDefinition {
     pub func: venial::Function,
     /// The name the function will be exposed as in Godot. If `None`, the Rust function name is used.
     pub rename: Option<String>,
+    /// Whether the first parameter is `Gd<Self>` standing in for the receiver (`#[func(gd_self)]`).
+    pub has_gd_self: bool,
+    /// Whether scripts can override the function (`#[func(virtual)]`).
+    pub is_virtual: bool,
+    /// Whether the function is `async`, i.e. its future is spawned as a task instead of being awaited.
+    pub is_async: bool,
+    /// Visibility of the Rust function, reused for generated companion functions.
+    pub vis_marker: Option<venial::VisMarker>,
+    /// Expressions evaluating to the `Variant` default values of the trailing `#[default]` parameters, in order.
+    pub default_params: Vec<TokenStream>,
+    /// Expression evaluating to the `RpcConfig`, if the function is a remote procedure call (`#[rpc]`).
+    pub rpc_config: Option<TokenStream>,
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
@@ -24,10 +36,17 @@ pub fn make_virtual_method_callback(
     class_name: &Ident,
     method_signature: &venial::Function,
 ) -> TokenStream {
-    let signature_info = get_signature_info(method_signature);
+    let signature_info = get_signature_info(method_signature, false);
     let method_name = &method_signature.name;
 
-    let wrapped_method = make_forwarding_closure(class_name, &signature_info);
+    // OnReady fields are initialized before the user's ready() runs.
+    let before_call = if method_name == "ready" {
+        quote! { ::godot::private::before_ready(storage); }
+    } else {
+        TokenStream::new()
+    };
+
+    let wrapped_method = make_forwarding_closure(class_name, &signature_info, before_call, false);
     let sig_tuple =
         util::make_signature_tuple_type(&signature_info.ret_type, &signature_info.param_types);
 
@@ -54,18 +73,28 @@ pub fn make_method_registration(
     class_name: &Ident,
     func_definition: FuncDefinition,
 ) -> TokenStream {
-    let signature_info = get_signature_info(&func_definition.func);
+    let signature_info = get_signature_info(&func_definition.func, func_definition.has_gd_self);
     let sig_tuple =
         util::make_signature_tuple_type(&signature_info.ret_type, &signature_info.param_types);
 
     let method_name = &signature_info.method_name;
     let param_idents = &signature_info.param_idents;
 
-    let method_flags = make_method_flags(signature_info.receiver_type);
+    let method_flags = make_method_flags(signature_info.receiver_type, func_definition.is_virtual);
 
-    let forwarding_closure = make_forwarding_closure(class_name, &signature_info);
+    let forwarding_closure = make_forwarding_closure(
+        class_name,
+        &signature_info,
+        TokenStream::new(),
+        func_definition.is_async,
+    );
 
-    let varcall_func = make_varcall_func(method_name, &sig_tuple, &forwarding_closure);
+    let varcall_func = make_varcall_func(
+        method_name,
+        &sig_tuple,
+        &forwarding_closure,
+        &func_definition.default_params,
+    );
     let ptrcall_func = make_ptrcall_func(method_name, &sig_tuple, &forwarding_closure);
 
     // String literals
@@ -76,6 +105,14 @@ pub fn make_method_registration(
         method_name.to_string()
     };
     let param_ident_strs = param_idents.iter().map(|ident| ident.to_string());
+    let default_params = &func_definition.default_params;
+
+    let rpc_registration = func_definition.rpc_config.map(|rpc_config| {
+        quote! {
+            let rpc_config = #rpc_config;
+            rpc_config.register(#class_name::class_name(), #method_name_str);
+        }
+    });
 
     quote! {
         {
@@ -104,7 +141,9 @@ pub fn make_method_registration(
                 &[
                     #( #param_ident_strs ),*
                 ],
-                Vec::new()
+                vec![
+                    #( #default_params ),*
+                ]
                 )
             };
 
@@ -116,10 +155,94 @@ pub fn make_method_registration(
 
 
             method_info.register_extension_class_method();
+            #rpc_registration
         };
     }
 }
 
+/// Generates `call_<name>(this: &Gd<Self>, ...)` for a `#[func(virtual)]`, which calls the method dynamically through
+/// Godot, so that a script override takes precedence over the Rust function.
+pub fn make_virtual_dispatcher(func_definition: &FuncDefinition) -> TokenStream {
+    let signature_info = get_signature_info(&func_definition.func, func_definition.has_gd_self);
+
+    let method_name = &signature_info.method_name;
+    let param_idents = &signature_info.param_idents;
+    let param_types = &signature_info.param_types;
+    let vis_marker = &func_definition.vis_marker;
+
+    let dispatcher_name = format_ident!("call_{}", method_name);
+    let godot_name = func_definition
+        .rename
+        .clone()
+        .unwrap_or_else(|| method_name.to_string());
+
+    let (ret_type, conversion) = match &func_definition.func.return_ty {
+        Some(ty) => (
+            quote! { #ty },
+            quote! { ::godot::builtin::FromVariant::from_variant(&result) },
+        ),
+        None => (quote! { () }, quote! { drop(result) }),
+    };
+
+    let doc = format!(
+        "Calls `{godot_name}` through Godot: a script override is preferred, otherwise [`Self::{method_name}()`] runs.\n\n\
+        The instance must not be bound while calling this, as the Rust function binds it again."
+    );
+
+    quote! {
+        #[doc = #doc]
+        #vis_marker fn #dispatcher_name(
+            this: &::godot::obj::Gd<Self>,
+            #( #param_idents: #param_types ),*
+        ) -> #ret_type {
+            let args: &[::godot::builtin::Variant] = &[
+                #( ::godot::builtin::ToVariant::to_variant(&#param_idents) ),*
+            ];
+
+            let mut object = ::godot::obj::Share::share(this).upcast::<::godot::engine::Object>();
+            let result = object.call(::godot::builtin::StringName::from(#godot_name), args);
+            #conversion
+        }
+    }
+}
+
+/// Generates `rpc_<name>(this: &Gd<Self>, ...)` for an `#[rpc]` function, which calls the method on all connected peers.
+pub fn make_rpc_sender(func_definition: &FuncDefinition) -> TokenStream {
+    let signature_info = get_signature_info(&func_definition.func, func_definition.has_gd_self);
+
+    let method_name = &signature_info.method_name;
+    let param_idents = &signature_info.param_idents;
+    let param_types = &signature_info.param_types;
+    let vis_marker = &func_definition.vis_marker;
+
+    let sender_name = format_ident!("rpc_{}", method_name);
+    let godot_name = func_definition
+        .rename
+        .clone()
+        .unwrap_or_else(|| method_name.to_string());
+
+    let doc = format!(
+        "Calls `{godot_name}` as remote procedure call on all connected peers, through `Node::rpc()`.\n\n\
+        With `call_local`, [`Self::{method_name}()`] also runs on this peer before returning, so the instance must not be bound \
+        while calling this."
+    );
+
+    quote! {
+        #[doc = #doc]
+        #vis_marker fn #sender_name(
+            this: &::godot::obj::Gd<Self>,
+            #( #param_idents: #param_types ),*
+        ) -> ::godot::engine::global::Error {
+            let args: &[::godot::builtin::Variant] = &[
+                #( ::godot::builtin::ToVariant::to_variant(&#param_idents) ),*
+            ];
+
+            let mut node = ::godot::obj::Share::share(this).upcast::<::godot::engine::Node>();
+            node.rpc(::godot::builtin::StringName::from(#godot_name), args)
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Implementation
 
@@ -127,6 +250,7 @@ pub fn make_method_registration(
 enum ReceiverType {
     Ref,
     Mut,
+    GdSelf,
     Static,
 }
 
@@ -139,7 +263,15 @@ struct SignatureInfo {
 }
 
 /// Returns a closure expression that forwards the parameters to the Rust instance.
-fn make_forwarding_closure(class_name: &Ident, signature_info: &SignatureInfo) -> TokenStream {
+/// Generates a closure calling the user method. `before_call` runs first, with `storage` in scope (not for static functions).
+///
+/// For `is_async`, the returned future is spawned on the main-thread executor; with `Gd<Self>`, it is bound to the instance.
+fn make_forwarding_closure(
+    class_name: &Ident,
+    signature_info: &SignatureInfo,
+    before_call: TokenStream,
+    is_async: bool,
+) -> TokenStream {
     let method_name = &signature_info.method_name;
     let params = &signature_info.param_idents;
 
@@ -161,26 +293,60 @@ fn make_forwarding_closure(class_name: &Ident, signature_info: &SignatureInfo) -
 
                     let storage =
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
+                    #before_call
                     #instance_decl
 
                     instance.#method_name(#(#params),*)
                 }
             }
         }
+        ReceiverType::GdSelf => {
+            let call = if is_async {
+                quote! {
+                    let this = storage.get_gd();
+                    let future = <#class_name>::#method_name(::godot::obj::Share::share(&this), #(#params),*);
+                    ::godot::task::spawn_bound(&this, future);
+                }
+            } else {
+                quote! { <#class_name>::#method_name(storage.get_gd(), #(#params),*) }
+            };
+
+            quote! {
+                |instance_ptr, params| {
+                    let ( #(#params,)* ) = params;
+
+                    let storage =
+                        unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
+                    #before_call
+
+                    #call
+                }
+            }
+        }
         ReceiverType::Static => {
+            let call = if is_async {
+                quote! { ::godot::task::spawn(<#class_name>::#method_name(#(#params),*)); }
+            } else {
+                quote! { <#class_name>::#method_name(#(#params),*) }
+            };
+
             quote! {
                 |_, params| {
                     let ( #(#params,)* ) = params;
-                    <#class_name>::#method_name(#(#params),*)
+                    #call
                 }
             }
         }
     }
 }
 
-fn get_signature_info(signature: &venial::Function) -> SignatureInfo {
+fn get_signature_info(signature: &venial::Function, has_gd_self: bool) -> SignatureInfo {
     let method_name = signature.name.clone();
-    let mut receiver_type = ReceiverType::Static;
+    let mut receiver_type = if has_gd_self {
+        ReceiverType::GdSelf
+    } else {
+        ReceiverType::Static
+    };
     let mut param_idents: Vec<Ident> = Vec::new();
     let mut param_types = Vec::new();
     let ret_type = match &signature.return_ty {
@@ -188,8 +354,11 @@ fn get_signature_info(signature: &venial::Function) -> SignatureInfo {
         Some(ty) => quote! { #ty },
     };
 
+    // With #[func(gd_self)], the first parameter is provided by the instance, not by the caller.
+    let skipped_params = usize::from(has_gd_self);
+
     let mut next_unnamed_index = 0;
-    for (arg, _) in &signature.params.inner {
+    for (arg, _) in signature.params.inner.iter().skip(skipped_params) {
         match arg {
             venial::FnParam::Receiver(recv) => {
                 receiver_type = if recv.tk_mut.is_some() {
@@ -226,14 +395,20 @@ fn get_signature_info(signature: &venial::Function) -> SignatureInfo {
     }
 }
 
-fn make_method_flags(method_type: ReceiverType) -> TokenStream {
-    match method_type {
-        ReceiverType::Ref | ReceiverType::Mut => {
+fn make_method_flags(method_type: ReceiverType, is_virtual: bool) -> TokenStream {
+    let flags = match method_type {
+        ReceiverType::Ref | ReceiverType::Mut | ReceiverType::GdSelf => {
             quote! { ::godot::engine::global::MethodFlags::METHOD_FLAGS_DEFAULT }
         }
         ReceiverType::Static => {
             quote! { ::godot::engine::global::MethodFlags::METHOD_FLAG_STATIC }
         }
+    };
+
+    if is_virtual {
+        quote! { #flags | ::godot::engine::global::MethodFlags::METHOD_FLAG_VIRTUAL }
+    } else {
+        flags
     }
 }
 
@@ -242,19 +417,43 @@ fn make_varcall_func(
     method_name: &Ident,
     sig_tuple: &TokenStream,
     wrapped_method: &TokenStream,
+    default_params: &[TokenStream],
 ) -> TokenStream {
     let invocation = make_varcall_invocation(method_name, sig_tuple, wrapped_method);
 
+    // Godot passes only the arguments given by the caller; missing trailing ones are filled in from the defaults.
+    let (arg_count, complete_args) = if default_params.is_empty() {
+        (quote! { _arg_count }, TokenStream::new())
+    } else {
+        let complete_args = quote! {
+            let defaults = [ #( #default_params ),* ];
+            let Some(all_args) = ::godot::private::varcall_args_with_defaults(
+                args,
+                arg_count,
+                <#sig_tuple as ::godot::builtin::meta::VarcallSignatureTuple>::PARAM_COUNT,
+                &defaults,
+                err,
+            ) else {
+                return;
+            };
+            let args = all_args.as_ptr();
+        };
+
+        (quote! { arg_count }, complete_args)
+    };
+
     quote! {
         {
             unsafe extern "C" fn function(
                 _method_data: *mut std::ffi::c_void,
                 instance_ptr: sys::GDExtensionClassInstancePtr,
                 args: *const sys::GDExtensionConstVariantPtr,
-                _arg_count: sys::GDExtensionInt,
+                #arg_count: sys::GDExtensionInt,
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
             ) {
+                #complete_args
+
                 let success = ::godot::private::handle_panic(
                     || stringify!(#method_name),
                     || #invocation
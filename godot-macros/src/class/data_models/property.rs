@@ -5,7 +5,7 @@
  */
 //! Parsing the `var` and `export` attributes on fields.
 
-use crate::class::{Field, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
+use crate::class::{Field, FieldVar, Fields, GetSet, GetterSetter, GetterSetterImpl, UsageFlags};
 use crate::util;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -38,11 +38,17 @@ impl FieldHint {
     }
 }
 
-pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
+/// With `has_generated_init`, the field initializers of `#[class(init)]` are known, and reported as defaults of exported properties.
+pub fn make_property_impl(
+    class_name: &Ident,
+    fields: &Fields,
+    has_generated_init: bool,
+) -> TokenStream {
     let class_name_obj = util::class_name_obj(class_name);
 
     let mut getter_setter_impls = Vec::new();
     let mut export_tokens = Vec::new();
+    let mut default_arms = Vec::new();
 
     for field in &fields.all_fields {
         let Field {
@@ -50,6 +56,10 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             ty: field_type,
             var,
             export,
+            group,
+            subgroup,
+            export_usage,
+            default,
             ..
         } = field;
 
@@ -69,6 +79,22 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
 
         let field_name = field_ident.to_string();
 
+        // Only the generated getter is known to return the field value as-is.
+        if has_generated_init && export.is_some() && matches!(var.getter, GetterSetter::Generated) {
+            let value_expr = match default {
+                None => quote! { ::std::default::Default::default() },
+                Some(default) => default.clone(),
+            };
+
+            default_arms.push(quote! {
+                #field_name => {
+                    let value: #field_type = #value_expr;
+                    let value = <#field_type as ::godot::bind::property::Property>::get_property(&value);
+                    Some(::godot::builtin::ToVariant::to_variant(&value))
+                }
+            });
+        }
+
         // rustfmt wont format this if we put it in the let-else.
         let FieldVar {
             getter,
@@ -78,9 +104,11 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         } = var;
 
         if let Some(export) = export {
-            hint = export.to_field_hint();
+            hint = export.to_field_hint(field_type);
 
-            if usage_flags.is_inferred() {
+            if let Some(export_usage) = export_usage {
+                usage_flags = export_usage.clone();
+            } else if usage_flags.is_inferred() {
                 usage_flags = UsageFlags::InferredExport;
             }
         }
@@ -171,9 +199,42 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             String::new()
         };
 
+        // Groups apply to all properties registered after them, so they must be registered right before the field.
+        let group_registration = group.as_ref().map(|group| {
+            quote! {
+                let group_name = ::godot::builtin::GodotString::from(#group);
+                let prefix = ::godot::builtin::GodotString::new();
+                unsafe {
+                    ::godot::sys::interface_fn!(classdb_register_extension_class_property_group)(
+                        ::godot::sys::get_library(),
+                        #class_name::class_name().string_sys(),
+                        group_name.string_sys(),
+                        prefix.string_sys(),
+                    );
+                }
+            }
+        });
+        let subgroup_registration = subgroup.as_ref().map(|subgroup| {
+            quote! {
+                let subgroup_name = ::godot::builtin::GodotString::from(#subgroup);
+                let prefix = ::godot::builtin::GodotString::new();
+                unsafe {
+                    ::godot::sys::interface_fn!(classdb_register_extension_class_property_subgroup)(
+                        ::godot::sys::get_library(),
+                        #class_name::class_name().string_sys(),
+                        subgroup_name.string_sys(),
+                        prefix.string_sys(),
+                    );
+                }
+            }
+        });
+
         export_tokens.push(quote! {
             use ::godot::builtin::meta::VariantMetadata;
 
+            #group_registration
+            #subgroup_registration
+
             let (hint, hint_string) = #hint;
             let usage = #usage_flags;
 
@@ -226,6 +287,13 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
                     }
                 )*
             }
+
+            fn __exported_property_default(property: &str) -> Option<::godot::builtin::Variant> {
+                match property {
+                    #( #default_arms )*
+                    _ => None,
+                }
+            }
         }
     }
 }
@@ -8,7 +8,9 @@ use proc_macro2::{Ident, Punct, TokenStream};
 use quote::{format_ident, quote};
 use venial::{Declaration, NamedField, Struct, StructFields};
 
-use crate::class::{make_property_impl, Field, FieldExport, FieldVar, Fields};
+use crate::class::{
+    make_property_impl, Field, FieldExport, FieldVar, Fields, GetterSetter, UsageFlags,
+};
 use crate::util::{bail, ident, KvParser};
 use crate::{util, ParseResult};
 
@@ -31,17 +33,30 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
     let inherits_macro = format_ident!("inherits_transitive_{}", base_ty);
 
     let prv = quote! { ::godot::private };
-    let godot_exports_impl = make_property_impl(class_name, &fields);
+    let godot_exports_impl = make_property_impl(class_name, &fields, struct_cfg.has_generated_init);
+    let with_base_field_impl = make_with_base_field_impl(class_name, &fields);
+    let before_ready_impl = make_before_ready_impl(class_name, &fields);
 
     let (godot_init_impl, create_fn);
     if struct_cfg.has_generated_init {
         godot_init_impl = make_godot_init_impl(class_name, fields);
         create_fn = quote! { Some(#prv::callbacks::create::<#class_name>) };
+    } else if struct_cfg.has_split_init {
+        godot_init_impl = make_split_init_impl(class_name);
+        create_fn = quote! { Some(#prv::callbacks::create::<#class_name>) };
     } else {
         godot_init_impl = TokenStream::new();
         create_fn = quote! { None };
     };
 
+    let is_instantiable = !struct_cfg.has_no_init;
+    let is_singleton = struct_cfg.is_singleton;
+    let is_hidden = struct_cfg.is_hidden;
+    let singleton_check = if is_singleton {
+        make_singleton_memory_check(class_name)
+    } else {
+        TokenStream::new()
+    };
     let config_impl = make_config_impl(class_name, struct_cfg.is_tool);
 
     Ok(quote! {
@@ -57,14 +72,23 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
 
         #godot_init_impl
         #godot_exports_impl
+        #with_base_field_impl
+        #before_ready_impl
         #config_impl
+        #singleton_check
 
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
             class_name: #class_name_obj,
             component: #prv::PluginComponent::ClassDef {
                 base_class_name: #base_class_name_obj,
+                is_instantiable: #is_instantiable,
+                is_singleton: #is_singleton,
+                is_hidden: #is_hidden,
                 generated_create_fn: #create_fn,
                 free_fn: #prv::callbacks::free::<#class_name>,
+                property_can_revert_fn: #prv::callbacks::property_can_revert::<#class_name>,
+                property_get_revert_fn: #prv::callbacks::property_get_revert::<#class_name>,
+                default_get_virtual_fn: #prv::callbacks::get_virtual_default::<#class_name>,
             },
         });
 
@@ -88,7 +112,11 @@ pub fn make_existence_check(ident: &Ident) -> TokenStream {
 fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
     let mut base_ty = ident("RefCounted");
     let mut has_generated_init = false;
+    let mut has_no_init = false;
+    let mut has_split_init = false;
     let mut is_tool = false;
+    let mut is_singleton = false;
+    let mut is_hidden = false;
 
     // #[class] attribute on struct
     if let Some(mut parser) = KvParser::parse(&class.attributes, "class")? {
@@ -100,17 +128,61 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
             has_generated_init = true;
         }
 
+        if parser.handle_alone("no_init")? {
+            if has_generated_init {
+                bail!(
+                    parser.span(),
+                    "#[class(init)] and #[class(no_init)] are mutually exclusive"
+                )?;
+            }
+            has_no_init = true;
+        }
+
         if parser.handle_alone("tool")? {
             is_tool = true;
         }
 
+        if parser.handle_alone("split_init")? {
+            if has_generated_init || has_no_init {
+                bail!(
+                    parser.span(),
+                    "#[class(split_init)] cannot be combined with #[class(init)] or #[class(no_init)]"
+                )?;
+            }
+            if !is_tool {
+                bail!(
+                    parser.span(),
+                    "#[class(split_init)] requires #[class(tool)]"
+                )?;
+            }
+            has_split_init = true;
+        }
+
+        if parser.handle_alone("singleton")? {
+            if has_no_init {
+                bail!(
+                    parser.span(),
+                    "#[class(singleton)] requires a constructor and cannot be combined with #[class(no_init)]"
+                )?;
+            }
+            is_singleton = true;
+        }
+
+        if parser.handle_alone("hidden")? {
+            is_hidden = true;
+        }
+
         parser.finish()?;
     }
 
     Ok(ClassAttributes {
         base_ty,
         has_generated_init,
+        has_no_init,
+        has_split_init,
         is_tool,
+        is_singleton,
+        is_hidden,
     })
 }
 
@@ -152,11 +224,48 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "init")? {
             let default = parser.handle_expr("default")?;
             field.default = default;
+
+            // #[init(node = "path")]
+            if let Some(node_path) = parser.handle_expr("node")? {
+                if field.default.is_some() {
+                    bail!(
+                        parser.span(),
+                        "#[init(node = ...)] and #[init(default = ...)] are mutually exclusive"
+                    )?;
+                }
+                if !is_onready_type(&field.ty) {
+                    bail!(
+                        parser.span(),
+                        "#[init(node = ...)] requires the field type to be `OnReady<Gd<T>>`"
+                    )?;
+                }
+                field.default = Some(quote! { ::godot::obj::OnReady::node(#node_path) });
+            }
+
             parser.finish()?;
         }
 
         // #[export]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export")? {
+            // Grouping keys can be combined with any export kind, so handle them first.
+            field.group = parser.handle_expr("group")?;
+            field.subgroup = parser.handle_expr("subgroup")?;
+            if let Some(usage) = parser.handle_expr("usage")? {
+                field.export_usage = Some(UsageFlags::parse_bitmask(usage)?);
+            }
+
+            // Custom accessors: shorthand for an additional #[var(get = ..., set = ...)].
+            let getter = GetterSetter::parse(&mut parser, "get")?;
+            let setter = GetterSetter::parse(&mut parser, "set")?;
+            if !getter.is_omitted() || !setter.is_omitted() {
+                field.var = Some(FieldVar {
+                    getter,
+                    setter,
+                    usage_flags: UsageFlags::InferredExport,
+                    ..Default::default()
+                });
+            }
+
             let export = FieldExport::new_from_kv(&mut parser)?;
             field.export = Some(export);
             parser.finish()?;
@@ -164,7 +273,32 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
 
         // #[var]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "var")? {
+            if field.var.is_some() {
+                bail!(
+                    parser.span(),
+                    "#[var] cannot be combined with #[export(get = ..., set = ...)]; specify the accessors in one of them"
+                )?;
+            }
             let var = FieldVar::new_from_kv(&mut parser)?;
+            if field.export_usage.is_some() && !var.usage_flags.is_inferred() {
+                bail!(
+                    parser.span(),
+                    "#[export(usage = ...)] and #[var(usage_flags = ...)] are mutually exclusive"
+                )?;
+            }
+            field.var = Some(var);
+            parser.finish()?;
+        }
+
+        // #[serialize]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "serialize")? {
+            if field.var.is_some() || field.export.is_some() {
+                bail!(
+                    parser.span(),
+                    "#[serialize] cannot be combined with #[var] or #[export]; those properties are already saved"
+                )?;
+            }
+            let var = FieldVar::new_serialized_from_kv(&mut parser)?;
             field.var = Some(var);
             parser.finish()?;
         }
@@ -189,7 +323,11 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
 struct ClassAttributes {
     base_ty: Ident,
     has_generated_init: bool,
+    has_no_init: bool,
+    has_split_init: bool,
     is_tool: bool,
+    is_singleton: bool,
+    is_hidden: bool,
 }
 
 fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
@@ -220,6 +358,86 @@ fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
     }
 }
 
+/// Constructor dispatching to the user-provided `init_editor()` or `init_runtime()`, for `#[class(split_init)]`.
+fn make_split_init_impl(class_name: &Ident) -> TokenStream {
+    quote! {
+        impl ::godot::obj::cap::GodotInit for #class_name {
+            fn __godot_init(base: ::godot::obj::Base<Self::Base>) -> Self {
+                if ::godot::private::is_editor() {
+                    Self::init_editor(base)
+                } else {
+                    Self::init_runtime(base)
+                }
+            }
+        }
+    }
+}
+
+fn make_with_base_field_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
+    let Some(Field { name, .. }) = &fields.base_field else {
+        return TokenStream::new();
+    };
+
+    quote! {
+        impl ::godot::obj::cap::WithBaseField for #class_name {
+            fn __godot_base(&self) -> &::godot::obj::Gd<Self::Base> {
+                &self.#name
+            }
+
+            fn __godot_base_mut(&mut self) -> &mut ::godot::obj::Gd<Self::Base> {
+                &mut self.#name
+            }
+        }
+    }
+}
+
+fn make_before_ready_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
+    let onready_fields: Vec<&Ident> = fields
+        .all_fields
+        .iter()
+        .filter(|field| is_onready_type(&field.ty))
+        .map(|field| &field.name)
+        .collect();
+
+    let has_onready_fields = !onready_fields.is_empty();
+
+    quote! {
+        impl ::godot::obj::cap::GodotBeforeReady for #class_name {
+            const HAS_ONREADY_FIELDS: bool = #has_onready_fields;
+
+            #[allow(unused_variables)]
+            fn __before_ready(&mut self, base: &::godot::obj::Gd<::godot::engine::Node>) {
+                if ::godot::private::is_class_inactive(Self::__config().is_tool) {
+                    return;
+                }
+
+                #( self.#onready_fields.init_auto(base); )*
+            }
+        }
+    }
+}
+
+/// Whether the type is spelled `OnReady<...>`, possibly with a path prefix.
+fn is_onready_type(ty: &venial::TyExpr) -> bool {
+    util::extract_typename(ty).map_or(false, |segment| segment.ident == "OnReady")
+}
+
+/// Singletons are owned by the engine until deinit, so reference-counted classes cannot be used.
+fn make_singleton_memory_check(class_name: &Ident) -> TokenStream {
+    quote! {
+        const _: () = {
+            fn __singleton_requires_manually_managed_base<T>()
+            where
+                T: ::godot::obj::GodotClass,
+                T::Mem: ::godot::obj::mem::PossiblyManual,
+            {
+            }
+
+            let _ = __singleton_requires_manually_managed_base::<#class_name>;
+        };
+    }
+}
+
 fn make_config_impl(class_name: &Ident, is_tool: bool) -> TokenStream {
     quote! {
         impl #class_name {
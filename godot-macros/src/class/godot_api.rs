@@ -4,15 +4,18 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::spanned::Spanned;
+use quote::{format_ident, quote, quote_spanned};
 use venial::{
     Attribute, AttributeValue, Constant, Declaration, Error, FnParam, Function, Impl, ImplMember,
     TyExpr,
 };
 
-use crate::class::{make_method_registration, make_virtual_method_callback, FuncDefinition};
+use crate::class::{
+    make_method_registration, make_rpc_sender, make_virtual_dispatcher,
+    make_virtual_method_callback, FuncDefinition,
+};
 use crate::util;
 use crate::util::{bail, KvParser};
 
@@ -37,7 +40,11 @@ pub fn attribute_godot_api(input_decl: Declaration) -> Result<TokenStream, Error
     };
 
     if decl.trait_ty.is_some() {
-        transform_trait_impl(decl)
+        if util::is_impl_named(&decl, "ScriptIterable") {
+            transform_script_iterable_impl(decl)
+        } else {
+            transform_trait_impl(decl)
+        }
     } else {
         transform_inherent_impl(decl)
     }
@@ -47,7 +54,11 @@ pub fn attribute_godot_api(input_decl: Declaration) -> Result<TokenStream, Error
 
 /// Attribute for user-declared function
 enum BoundAttrType {
-    Func { rename: Option<String> },
+    Func {
+        rename: Option<String>,
+        has_gd_self: bool,
+        is_virtual: bool,
+    },
     Signal(AttributeValue),
     Const(AttributeValue),
 }
@@ -70,9 +81,23 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
     let class_name_obj = util::class_name_obj(&class_name);
     let (funcs, signals) = process_godot_fns(&mut decl)?;
 
+    let virtual_dispatchers: Vec<TokenStream> = funcs
+        .iter()
+        .filter(|func_def| func_def.is_virtual)
+        .map(make_virtual_dispatcher)
+        .chain(
+            funcs
+                .iter()
+                .filter(|func_def| func_def.rpc_config.is_some())
+                .map(make_rpc_sender),
+        )
+        .collect();
+
     let mut signal_name_strs: Vec<String> = Vec::new();
     let mut signal_parameters_count: Vec<usize> = Vec::new();
     let mut signal_parameters: Vec<TokenStream> = Vec::new();
+    let mut signal_idents: Vec<Ident> = Vec::new();
+    let mut signal_param_tuples: Vec<TokenStream> = Vec::new();
 
     for signature in signals {
         let mut param_types: Vec<TyExpr> = Vec::new();
@@ -103,8 +128,17 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
         signal_name_strs.push(signature.name.to_string());
         signal_parameters_count.push(param_names.len());
         signal_parameters.push(param_array_decl);
+        signal_idents.push(signature.name.clone());
+        signal_param_tuples.push(quote! { ( #(#param_types,)* ) });
     }
 
+    let signals_impl = make_signals_impl(
+        &class_name,
+        &signal_idents,
+        &signal_name_strs,
+        &signal_param_tuples,
+    );
+
     let prv = quote! { ::godot::private };
 
     let methods_registration = funcs
@@ -148,8 +182,20 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
         quote! {}
     };
 
+    let dispatchers_impl = if virtual_dispatchers.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            impl #class_name {
+                #( #virtual_dispatchers )*
+            }
+        }
+    };
+
     let result = quote! {
         #decl
+        #dispatchers_impl
+        #signals_impl
 
         impl ::godot::obj::cap::ImplementsGodotApi for #class_name {
             fn __register_methods() {
@@ -199,6 +245,49 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
     Ok(result)
 }
 
+/// Generates the `{Class}Signals` struct with one `TypedSignal` accessor per signal, returned by `Gd::signals()`.
+fn make_signals_impl(
+    class_name: &Ident,
+    signal_idents: &[Ident],
+    signal_name_strs: &[String],
+    signal_param_tuples: &[TokenStream],
+) -> TokenStream {
+    if signal_idents.is_empty() {
+        return TokenStream::new();
+    }
+
+    let signals_struct = format_ident!("{}Signals", class_name);
+    let struct_doc = format!("Signals of `{class_name}`, returned by `Gd::signals()`.");
+
+    quote! {
+        #[doc = #struct_doc]
+        pub struct #signals_struct {
+            object: ::godot::obj::Gd<::godot::engine::Object>,
+        }
+
+        impl #signals_struct {
+            #(
+                pub fn #signal_idents(&self) -> ::godot::obj::TypedSignal<#signal_param_tuples> {
+                    ::godot::obj::TypedSignal::__new(
+                        ::godot::obj::Share::share(&self.object),
+                        #signal_name_strs,
+                    )
+                }
+            )*
+        }
+
+        impl ::godot::obj::cap::WithSignals for #class_name {
+            type SignalCollection = #signals_struct;
+
+            fn __signals(this: &::godot::obj::Gd<Self>) -> Self::SignalCollection {
+                #signals_struct {
+                    object: ::godot::obj::Share::share(this).upcast(),
+                }
+            }
+        }
+    }
+}
+
 fn process_godot_fns(decl: &mut Impl) -> Result<(Vec<FuncDefinition>, Vec<Function>), Error> {
     let mut func_definitions = vec![];
     let mut signal_signatures = vec![];
@@ -215,9 +304,18 @@ fn process_godot_fns(decl: &mut Impl) -> Result<(Vec<FuncDefinition>, Vec<Functi
             // Remaining code no longer has attribute -- rest stays
             method.attributes.remove(attr.index);
 
+            let rpc_config = extract_rpc_config(method)?;
+            if rpc_config.is_some() && !matches!(attr.ty, BoundAttrType::Func { .. }) {
+                return attr.bail("#[rpc] can only be combined with #[func]", method);
+            }
+
+            // `async` is only supported for #[func] and validated there.
+            let is_async = method.qualifiers.tk_async.is_some();
+            let async_allowed = matches!(attr.ty, BoundAttrType::Func { .. });
+
             if method.qualifiers.tk_default.is_some()
                 || method.qualifiers.tk_const.is_some()
-                || method.qualifiers.tk_async.is_some()
+                || (is_async && !async_allowed)
                 || method.qualifiers.tk_unsafe.is_some()
                 || method.qualifiers.tk_extern.is_some()
                 || method.qualifiers.extern_abi.is_some()
@@ -230,10 +328,65 @@ fn process_godot_fns(decl: &mut Impl) -> Result<(Vec<FuncDefinition>, Vec<Functi
             }
 
             match attr.ty {
-                BoundAttrType::Func { rename } => {
+                BoundAttrType::Func {
+                    ref rename,
+                    has_gd_self,
+                    is_virtual,
+                } => {
+                    // Virtual methods follow the Godot convention of a leading underscore, e.g. `_process`.
+                    let rename = match rename {
+                        None if is_virtual => Some(format!("_{}", method.name)),
+                        _ => rename.clone(),
+                    };
+
+                    // GDScript's `MyClass.new()` is the constructor; a method registered under that name would be shadowed.
+                    let godot_name = rename.clone().unwrap_or_else(|| method.name.to_string());
+                    if godot_name == "new" {
+                        return attr.bail(
+                            "#[func] cannot be registered as `new`, which is reserved for the Godot constructor; \
+                            choose another name (e.g. `create`) or use #[func(rename = ...)]",
+                            method,
+                        );
+                    }
+
+                    if has_gd_self {
+                        validate_gd_self_params(&attr, method)?;
+                    }
+                    validate_no_reference_params(&attr, method)?;
+
+                    if is_virtual && !has_gd_self && !has_receiver(method) {
+                        return attr.bail(
+                            "#[func(virtual)] cannot be used on static functions, as scripts override instance methods",
+                            method,
+                        );
+                    }
+
+                    if is_async {
+                        validate_async(&attr, method, is_virtual)?;
+                    }
+
+                    if rpc_config.is_some() && !has_gd_self && !has_receiver(method) {
+                        return attr.bail(
+                            "#[rpc] cannot be used on static functions, as remote calls target a node instance",
+                            method,
+                        );
+                    }
+
+                    // Also removes the #[default] attributes, which must not remain in the emitted code.
+                    let default_params = extract_default_params(method, has_gd_self)?;
+
                     // Signatures are the same thing without body
                     let sig = util::reduce_to_signature(method);
-                    func_definitions.push(FuncDefinition { func: sig, rename });
+                    func_definitions.push(FuncDefinition {
+                        func: sig,
+                        rename,
+                        has_gd_self,
+                        is_virtual,
+                        is_async,
+                        vis_marker: method.vis_marker.clone(),
+                        default_params,
+                        rpc_config,
+                    });
                 }
                 BoundAttrType::Signal(ref _attr_val) => {
                     if method.return_ty.is_some() {
@@ -251,6 +404,8 @@ fn process_godot_fns(decl: &mut Impl) -> Result<(Vec<FuncDefinition>, Vec<Functi
                     )
                 }
             }
+        } else if KvParser::parse(&method.attributes, "rpc")?.is_some() {
+            return bail!(&method.name, "#[rpc] can only be combined with #[func]");
         }
     }
 
@@ -263,6 +418,205 @@ fn process_godot_fns(decl: &mut Impl) -> Result<(Vec<FuncDefinition>, Vec<Functi
     Ok((func_definitions, signal_signatures))
 }
 
+/// Removes the `#[rpc]` attribute of a method, and returns an expression constructing its `RpcConfig`.
+///
+/// Defaults are the same as for GDScript's `@rpc`: `authority`, `unreliable`, `call_remote` and channel 0.
+fn extract_rpc_config(method: &mut Function) -> Result<Option<TokenStream>, Error> {
+    let Some(mut parser) = KvParser::parse(&method.attributes, "rpc")? else {
+        return Ok(None);
+    };
+
+    let rpc_mode = match (
+        parser.handle_alone("authority")?,
+        parser.handle_alone("any_peer")?,
+    ) {
+        (true, true) => {
+            return bail!(
+                parser.span(),
+                "#[rpc]: `authority` and `any_peer` are mutually exclusive"
+            )
+        }
+        (_, true) => quote! { AnyPeer },
+        _ => quote! { Authority },
+    };
+
+    let transfer_mode = match (
+        parser.handle_alone("unreliable")?,
+        parser.handle_alone("unreliable_ordered")?,
+        parser.handle_alone("reliable")?,
+    ) {
+        (false, false, true) => quote! { Reliable },
+        (false, true, false) => quote! { UnreliableOrdered },
+        (_, false, false) => quote! { Unreliable },
+        _ => {
+            return bail!(
+                parser.span(),
+                "#[rpc]: `unreliable`, `unreliable_ordered` and `reliable` are mutually exclusive"
+            )
+        }
+    };
+
+    let call_local = match (
+        parser.handle_alone("call_local")?,
+        parser.handle_alone("call_remote")?,
+    ) {
+        (true, true) => {
+            return bail!(
+                parser.span(),
+                "#[rpc]: `call_local` and `call_remote` are mutually exclusive"
+            )
+        }
+        (call_local, _) => call_local,
+    };
+
+    let channel = parser
+        .handle_expr("channel")?
+        .unwrap_or_else(|| quote! { 0 });
+    parser.finish()?;
+
+    method.attributes.retain(|attr| {
+        attr.get_single_path_segment()
+            .map_or(true, |name| name != "rpc")
+    });
+
+    let rpc = quote! { ::godot::builtin::meta::registration::rpc };
+    Ok(Some(quote! {
+        #rpc::RpcConfig {
+            rpc_mode: #rpc::RpcMode::#rpc_mode,
+            transfer_mode: #rpc::TransferMode::#transfer_mode,
+            call_local: #call_local,
+            channel: #channel,
+        }
+    }))
+}
+
+/// Removes `#[default(value)]` attributes from the parameters of a `#[func]`, and returns the default values in parameter order.
+///
+/// Each value is converted with `Into` to the parameter type, so a mismatching type is a compile error, and then to `Variant`.
+fn extract_default_params(
+    method: &mut Function,
+    has_gd_self: bool,
+) -> Result<Vec<TokenStream>, Error> {
+    let mut default_params = Vec::new();
+
+    for (index, (param, _)) in method.params.inner.iter_mut().enumerate() {
+        let FnParam::Typed(param) = param else {
+            continue;
+        };
+
+        let position = param.attributes.iter().position(|attr| {
+            attr.get_single_path_segment()
+                .map_or(false, |name| name == "default")
+        });
+
+        let Some(position) = position else {
+            if !default_params.is_empty() {
+                return bail!(
+                    &param.name,
+                    "#[func]: parameters following a #[default] parameter need a default value as well",
+                );
+            }
+            continue;
+        };
+
+        let attr = param.attributes.remove(position);
+        if has_gd_self && index == 0 {
+            return bail!(
+                &param.name,
+                "#[func]: the `Gd<Self>` parameter cannot have a default value",
+            );
+        }
+
+        let value = attr.value.get_value_tokens();
+        if value.is_empty() {
+            return bail!(
+                &param.name,
+                "#[default] requires a value, e.g. `#[default(5)]`",
+            );
+        }
+
+        let ty = &param.ty;
+        default_params.push(quote! {
+            ::godot::builtin::ToVariant::to_variant(
+                &::std::convert::Into::<#ty>::into(#( #value )*)
+            )
+        });
+    }
+
+    Ok(default_params)
+}
+
+/// With `#[func(gd_self)]`, the function takes `Gd<Self>` as its first parameter instead of a `self` receiver.
+fn validate_gd_self_params(attr: &BoundAttr, method: &Function) -> Result<(), Error> {
+    match method.params.inner.first() {
+        Some((FnParam::Typed(_), _)) => Ok(()),
+        Some((FnParam::Receiver(_), _)) => bail!(
+            &method.name,
+            "#[{}(gd_self)]: replace the `self` receiver with a first parameter of type `Gd<Self>`",
+            attr.attr_name
+        ),
+        None => bail!(
+            &method.name,
+            "#[{}(gd_self)]: function must have a first parameter of type `Gd<Self>`",
+            attr.attr_name
+        ),
+    }
+}
+
+/// An `async` #[func] is spawned as a task and returns to Godot immediately, so it cannot borrow `self` or return a value.
+fn validate_async(attr: &BoundAttr, method: &Function, is_virtual: bool) -> Result<(), Error> {
+    if has_receiver(method) {
+        return bail!(
+            &method.name,
+            "#[{}]: async functions cannot take `self`, as the instance cannot stay bound while the task is suspended; \
+            use #[{}(gd_self)] with a first parameter `this: Gd<Self>` instead",
+            attr.attr_name,
+            attr.attr_name
+        );
+    }
+    if let Some(return_ty) = &method.return_ty {
+        return bail!(
+            return_ty,
+            "#[{}]: async functions cannot return a value, as they return to Godot before the task completes",
+            attr.attr_name
+        );
+    }
+    if is_virtual {
+        return bail!(
+            &method.name,
+            "#[{}(virtual)] cannot be combined with async",
+            attr.attr_name
+        );
+    }
+    Ok(())
+}
+
+fn has_receiver(method: &Function) -> bool {
+    matches!(method.params.inner.first(), Some((FnParam::Receiver(_), _)))
+}
+
+/// Godot passes arguments by value (objects as a copy of their reference), so `&T`/`&mut T` parameters cannot be mapped.
+fn validate_no_reference_params(attr: &BoundAttr, method: &Function) -> Result<(), Error> {
+    for (param, _punct) in method.params.inner.iter() {
+        let FnParam::Typed(param) = param else {
+            continue;
+        };
+
+        let is_reference =
+            matches!(param.ty.tokens.first(), Some(TokenTree::Punct(p)) if p.as_char() == '&');
+        if is_reference {
+            return bail!(
+                &param.ty,
+                "#[{}]: reference parameters are not supported, as Godot has no out-parameters; \
+                take `Gd<T>` by value to modify the object, or return a `Gd<T>` to hand back a different one",
+                attr.attr_name
+            );
+        }
+    }
+
+    Ok(())
+}
+
 fn process_godot_constants(decl: &mut Impl) -> Result<Vec<Constant>, Error> {
     let mut constant_signatures = vec![];
 
@@ -316,11 +670,17 @@ where
                 let mut parser = KvParser::parse(attributes, "func")?.unwrap();
 
                 let rename = parser.handle_expr("rename")?.map(|ts| ts.to_string());
+                let has_gd_self = parser.handle_alone("gd_self")?;
+                let is_virtual = parser.handle_alone("virtual")?;
 
                 Some(BoundAttr {
                     attr_name: attr_name.clone(),
                     index,
-                    ty: BoundAttrType::Func { rename },
+                    ty: BoundAttrType::Func {
+                        rename,
+                        has_gd_self,
+                        is_virtual,
+                    },
                 })
             }
             name if name == "signal" => {
@@ -349,7 +709,10 @@ where
             )?;
         }
 
-        found = new_found;
+        // Other attributes (e.g. #[rpc]) may follow the bound one.
+        if new_found.is_some() {
+            found = new_found;
+        }
     }
 
     Ok(found)
@@ -357,6 +720,84 @@ where
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Codegen for `#[godot_api] impl ScriptIterable for MyType`
+///
+/// Registers GDScript's iteration methods `_iter_init`, `_iter_next` and `_iter_get`, forwarding to `ScriptIterable::item_at()`.
+fn transform_script_iterable_impl(original_impl: Impl) -> Result<TokenStream, Error> {
+    let class_name = util::validate_impl(&original_impl, Some("ScriptIterable"), "godot_api")?;
+    let class_name_obj = util::class_name_obj(&class_name);
+    let prv = quote! { ::godot::private };
+
+    let iteration_fns = [
+        (
+            "_iter_init",
+            quote! { fn __godot_iter_init(&self, iter: ::godot::builtin::VariantArray) -> bool },
+            quote! { #prv::iter_init(self, iter) },
+        ),
+        (
+            "_iter_next",
+            quote! { fn __godot_iter_next(&self, iter: ::godot::builtin::VariantArray) -> bool },
+            quote! { #prv::iter_next(self, iter) },
+        ),
+        (
+            "_iter_get",
+            quote! { fn __godot_iter_get(&self, iter: ::godot::builtin::Variant) -> ::godot::builtin::Variant },
+            quote! { #prv::iter_get(iter) },
+        ),
+    ];
+
+    let mut forwarding_fns = Vec::new();
+    let mut registrations = Vec::new();
+    for (godot_name, signature, body) in iteration_fns {
+        forwarding_fns.push(quote! {
+            #[doc(hidden)]
+            #signature {
+                #body
+            }
+        });
+
+        registrations.push(make_method_registration(
+            &class_name,
+            FuncDefinition {
+                func: util::parse_signature(signature),
+                rename: Some(godot_name.to_string()),
+                has_gd_self: false,
+                is_virtual: false,
+                is_async: false,
+                vis_marker: None,
+                default_params: Vec::new(),
+                rpc_config: None,
+            },
+        ));
+    }
+
+    let result = quote! {
+        #original_impl
+
+        impl #class_name {
+            #( #forwarding_fns )*
+        }
+
+        ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
+            class_name: #class_name_obj,
+            component: #prv::PluginComponent::UserIteration {
+                generated_register_fn: #prv::ErasedRegisterFn {
+                    raw: {
+                        fn register_iteration(_class_builder: &mut dyn ::std::any::Any) {
+                            #( #registrations )*
+                        }
+                        register_iteration
+                    },
+                },
+            },
+        });
+    };
+
+    Ok(result)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// Codegen for `#[godot_api] impl GodotExt for MyType`
 fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
     let (class_name, trait_name) = util::validate_trait_impl_virtual(&original_impl, "godot_api")?;
@@ -465,17 +906,31 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
         }
     }
 
+    let base_check = make_virtual_base_check(&class_name, &trait_name);
+
     let virtual_method_callbacks: Vec<TokenStream> = virtual_methods
         .iter()
         .map(|method| make_virtual_method_callback(&class_name, method))
         .collect();
 
+    // If ready() is not overridden, OnReady fields still need to be initialized.
+    let before_ready_arm = if virtual_method_names.iter().any(|name| name == "_ready") {
+        TokenStream::new()
+    } else {
+        quote! {
+            "_ready" if <Self as ::godot::obj::cap::GodotBeforeReady>::HAS_ONREADY_FIELDS => {
+                Some(#prv::callbacks::before_ready::<Self>)
+            }
+        }
+    };
+
     let result = quote! {
         #original_impl
         #godot_init_impl
         #to_string_impl
         #on_notification_impl
         #register_class_impl
+        #base_check
 
         impl ::godot::private::You_forgot_the_attribute__godot_api for #class_name {}
 
@@ -491,6 +946,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
                     #(
                        #virtual_method_names => #virtual_method_callbacks,
                     )*
+                    #before_ready_arm
                     _ => None,
                 }
             }
@@ -510,3 +966,19 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
 
     Ok(result)
 }
+
+/// Makes sure that `impl XyVirtual for T` is only possible if `T`'s base class is `Xy` or inherits from it.
+fn make_virtual_base_check(class_name: &Ident, trait_name: &Ident) -> TokenStream {
+    // unwrap: trait name validated to end in "Virtual".
+    let trait_class = trait_name.to_string();
+    let trait_class = trait_class.strip_suffix("Virtual").unwrap();
+    let trait_class = Ident::new(trait_class, trait_name.span());
+
+    // Error is reported on the trait name, e.g. "the trait `Inherits<Node2D>` is not implemented for `Node`".
+    quote_spanned! { trait_name.span()=>
+        const _: fn() = ::godot::private::virtual_trait_matches_base::<
+            #class_name,
+            ::godot::engine::#trait_class,
+        >;
+    }
+}
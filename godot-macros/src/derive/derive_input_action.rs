@@ -0,0 +1,81 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use venial::{Declaration, StructFields};
+
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+pub fn derive_input_action(decl: Declaration) -> ParseResult<TokenStream> {
+    let enum_ = match decl {
+        Declaration::Enum(e) => e,
+        Declaration::Struct(s) => {
+            return bail!(s.tk_struct, "InputAction can only be derived on enums")
+        }
+        Declaration::Union(u) => {
+            return bail!(u.tk_union, "InputAction can only be derived on enums")
+        }
+        _ => unreachable!(),
+    };
+
+    let name = &enum_.name;
+    let mut arms = Vec::new();
+
+    for (enum_v, _) in enum_.variants.inner.iter() {
+        let variant_name = &enum_v.name;
+        if !matches!(enum_v.contents, StructFields::Unit) {
+            return bail!(
+                variant_name,
+                "InputAction can only be derived on enums with only unit variants"
+            );
+        }
+
+        // #[action(name = "...")]
+        let action_name = match KvParser::parse(&enum_v.attributes, "action")? {
+            Some(mut parser) => {
+                let action_name = parser.handle_expr_required("name")?;
+                parser.finish()?;
+                action_name
+            }
+            None => {
+                let snake_case = to_snake_case(&variant_name.to_string());
+                quote! { #snake_case }
+            }
+        };
+
+        arms.push(quote! {
+            Self::#variant_name => ::godot::builtin::StringName::from(#action_name),
+        });
+    }
+
+    Ok(quote! {
+        impl ::godot::engine::InputAction for #name {
+            fn action_name(&self) -> ::godot::builtin::StringName {
+                match self {
+                    #( #arms )*
+                }
+            }
+        }
+    })
+}
+
+/// Converts a `PascalCase` identifier to `snake_case`, e.g. `MoveLeft` to `move_left`.
+fn to_snake_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 && !result.ends_with('_') {
+                result.push('_');
+            }
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
@@ -50,6 +50,9 @@ use venial::Declaration;
 /// }
 /// ```
 ///
+/// For `#[export]` fields, this value is also the default that the editor's inspector reverts to. Classes with a hand-written
+/// `init` do not report defaults, as they could only be obtained by constructing an instance.
+///
 /// The given value can be any Rust expression that can be evaluated in the scope where you write
 /// the attribute. However, due to limitations in the parser, some complex expressions must be
 /// surrounded by parentheses. This is the case if the expression includes a `,` that is _not_
@@ -68,6 +71,70 @@ use venial::Declaration;
 /// # }
 /// ```
 ///
+/// Fields of type [`OnReady<Gd<T>>`](../obj/struct.OnReady.html) can instead be annotated with `#[init(node = "path")]`,
+/// which looks up the node at that path (relative to the instance) right before `ready()` is called:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct MyNode {
+///     #[init(node = "Child/Grandchild")]
+///     grandchild: OnReady<Gd<Node>>,
+/// }
+/// ```
+///
+/// If a class should only be constructed from Rust, annotate it with `#[class(no_init)]`. The class is then registered
+/// without a constructor, so `MyStruct.new()` in GDScript fails with an error. Such a class can still be instantiated
+/// from Rust, using `Gd::with_base()`:
+///
+/// ```
+/// # use godot_macros::GodotClass;
+/// #[derive(GodotClass)]
+/// #[class(no_init)]
+/// struct MyStruct {
+///     // ...
+/// }
+/// ```
+///
+/// `no_init` cannot be combined with `init`, nor with a user-defined `init` function in the `#[godot_api]` virtual
+/// trait impl. The latter is detected during class registration.
+///
+/// # Singletons
+///
+/// With `#[class(singleton)]`, one instance of the class is created after all classes have been registered, and made
+/// available as engine singleton under the class name, e.g. `Engine.get_singleton("MySingleton")`. It is unregistered and freed
+/// when the `Scene` init level is deinitialized, so no manual lifecycle management is required.
+///
+/// The class must have a constructor (`init` or a user-defined `init` function) and a manually managed base, such as `Object`
+/// or `Node`; reference-counted bases are rejected at compile time. Registration panics if another singleton with the same
+/// name already exists.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, singleton, base=Object)]
+/// struct GameSettings {
+///     volume: f64,
+/// }
+/// ```
+///
+/// # Hidden classes
+///
+/// Classes annotated with `#[class(hidden)]` are not listed in the editor's "Create New Node" and "Create New Resource"
+/// dialogs. This is meant for internal classes of an extension, which users should not add manually. The class is otherwise
+/// registered as usual: it can still be constructed from code (e.g. `MyStruct.new()` in GDScript), and existing scenes or
+/// resources containing it load fine. To also prevent construction from GDScript, combine it with `no_init`.
+///
+/// ```
+/// # use godot_macros::GodotClass;
+/// #[derive(GodotClass)]
+/// #[class(init, hidden)]
+/// struct InternalHelper {
+///     // ...
+/// }
+/// ```
+///
 /// # Inheritance
 ///
 /// Unlike C++, Rust doesn't really have inheritance, but the GDExtension API lets us "inherit"
@@ -102,6 +169,9 @@ use venial::Declaration;
 /// }
 /// ```
 ///
+/// Apart from the field itself, the base is then also accessible through `self.base()` and `self.base_mut()` in any method,
+/// provided by the [`WithBaseField`](../obj/cap/trait.WithBaseField.html) trait in the prelude.
+///
 ///
 /// # Properties and exports
 ///
@@ -136,7 +206,7 @@ use venial::Declaration;
 ///
 /// If you want to implement your own getter and/or setter, write those as a function on your Rust
 /// type, expose it using `#[func]`, and annotate the field with
-/// `#[export(get = ..., set = ...)]`:
+/// `#[var(get = ..., set = ...)]`:
 ///
 /// ```
 /// use godot::prelude::*;
@@ -200,6 +270,34 @@ use venial::Declaration;
 /// ```
 ///
 /// If you dont also include a `#[var]` attribute, then a default one will be generated.
+///
+/// Exported properties can also route through custom accessors, e.g. to validate values set in the editor or to compute them
+/// on the fly. `#[export(get = ..., set = ...)]` is a shorthand for `#[export]` plus `#[var(get = ..., set = ...)]`, with the same
+/// rules for omitted accessors (only `get` makes the property read-only). It cannot be combined with a separate `#[var]`.
+///
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// struct MyStruct {
+///     #[export(get = get_health, set = set_health)]
+///     health: i64,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     pub fn get_health(&self) -> i64 {
+///         self.health
+///     }
+///
+///     #[func]
+///     pub fn set_health(&mut self, value: i64) {
+///         self.health = value.clamp(0, 100);
+///     }
+/// }
+/// ```
+///
 /// `#[export]` also supports all of GDScript's annotations, in a slightly different format. The format is
 /// translated from an annotation by following these four rules:
 ///
@@ -226,6 +324,10 @@ use venial::Declaration;
 ///     #[export(range = (0.0, 10.0, or_greater))]
 ///     range_f64: f64,
 ///
+///     // @export_range(0.0, 100.0, 0.5, exp, hide_slider)
+///     #[export(range = (0.0, 100.0, 0.5, exp, hide_slider))]
+///     range_with_step: f64,
+///
 ///     // @export_file
 ///     #[export(file)]
 ///     file: GodotString,
@@ -277,6 +379,38 @@ use venial::Declaration;
 /// impl MyStruct {}
 /// ```
 ///
+/// Exported properties can be organized in collapsible inspector sections, like GDScript's `@export_group` and
+/// `@export_subgroup`. The `group` and `subgroup` keys can be combined with any of the above, and start a section
+/// _at_ the annotated field: it contains this and all following fields, until the next group (or subgroup,
+/// respectively). An empty name such as `group = ""` ends the current section.
+///
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// struct MyStruct {
+///     // @export_group("Movement")
+///     // @export var speed: float
+///     #[export(group = "Movement")]
+///     speed: f64,
+///     #[export]
+///     acceleration: f64,
+///
+///     // @export_subgroup("Jumping")
+///     // @export_range(0.0, 10.0) var jump_height: float
+///     #[export(subgroup = "Jumping", range = (0.0, 10.0))]
+///     jump_height: f64,
+///
+///     // @export_group("Combat")
+///     // @export var health: int
+///     #[export(group = "Combat")]
+///     health: i64,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {}
+/// ```
+///
 /// You can specify custom property hints, hint strings, and usage flags in a `#[var]` attribute using the
 /// `hint`, `hint_string`, and `usage_flags` keys in the attribute:
 ///
@@ -300,11 +434,86 @@ use venial::Declaration;
 /// impl MyStruct {}
 /// ```
 ///
+/// Exported properties use `PROPERTY_USAGE_DEFAULT` (shown in the editor and saved). To set an exact bitmask instead, add
+/// `usage` to the `#[export]` attribute. The `PROPERTY_USAGE_` prefix can be omitted; unknown flags cause a compile error.
+///
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// struct MyStruct {
+///     // Saved and shown, but not editable in the inspector.
+///     #[export(usage = STORAGE | EDITOR | READ_ONLY)]
+///     seed: i64,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {}
+/// ```
+///
+/// # Saving non-exported state
+///
+/// Exported properties are saved together with scenes and resources (e.g. `.tres` files). Other fields can take part in
+/// saving with `#[serialize]`: they are registered as properties with only the `PROPERTY_USAGE_STORAGE` flag, so they are
+/// written and loaded with the object, but not shown in the inspector. Like `#[var]`, this generates a getter and a setter,
+/// which can be replaced with custom ones using `get = ...` and `set = ...`.
+///
+/// Godot assigns saved properties one by one, with the values found in the file. There is no built-in versioning, but a
+/// custom setter can act as migration hook, e.g. to convert values written by an older version of the class. Properties
+/// that are missing in the file are not assigned, so they keep the value from `init`.
+///
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Resource)]
+/// struct Inventory {
+///     #[export]
+///     capacity: i64,
+///
+///     #[serialize(set = set_items)]
+///     items: PackedStringArray,
+/// }
+///
+/// #[godot_api]
+/// impl Inventory {
+///     #[func]
+///     fn set_items(&mut self, items: PackedStringArray) {
+///         // Older versions stored items in upper case.
+///         self.items = items.as_slice().iter().map(|item| item.to_lower()).collect();
+///     }
+/// }
+/// ```
+///
+/// `#[serialize]` cannot be combined with `#[var]` or `#[export]`, since those properties are already saved.
+///
 ///
 /// # Signals
 ///
-/// The `#[signal]` attribute is accepted, but not yet implemented. See [issue
-/// #8](https://github.com/godot-rust/gdext/issues/8).
+/// Signals are declared in the `#[godot_api]` impl block, as functions without body annotated with `#[signal]`. For each class
+/// with signals, a `{Class}Signals` struct is generated, accessible through `Gd::signals()`. It has one method per signal,
+/// returning a [`TypedSignal`](../obj/struct.TypedSignal.html) that is checked against the declaration at compile time:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Door {}
+///
+/// #[godot_api]
+/// impl Door {
+///     #[signal]
+///     fn opened(by_player: bool);
+/// }
+///
+/// fn open(door: &Gd<Door>, listener: &Gd<Node>) {
+///     let mut signal = door.signals().opened();
+///     signal.connect(listener.callable("on_door_opened"));
+///     signal.emit((true,));
+/// }
+/// ```
+///
+/// Full support for signals is tracked in [issue #8](https://github.com/godot-rust/gdext/issues/8).
 ///
 ///
 /// # Running code in the editor
@@ -316,7 +525,39 @@ use venial::Declaration;
 /// for more information and further customization.
 ///
 /// This is very similar to [GDScript's `@tool` feature](https://docs.godotengine.org/en/stable/tutorials/plugins/running_code_in_the_editor.html).
-#[proc_macro_derive(GodotClass, attributes(class, base, var, export, init, signal))]
+///
+/// Tool classes often need a different setup in the editor than in the game. Instead of checking `Engine::is_editor_hint()` in
+/// `init`, you can add the `split_init` key, which generates a constructor calling one of two associated functions you provide:
+/// `init_editor` in the editor, and `init_runtime` otherwise. It requires `tool` and replaces `init`, so the two cannot be combined.
+/// State needed in both cases is best initialized in a shared helper, which both functions call:
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(tool, split_init, base=Node)]
+/// struct Spawner {
+///     preview: bool,
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// impl Spawner {
+///     fn init_editor(base: Base<Node>) -> Self {
+///         Self { preview: true, ..Self::init_common(base) }
+///     }
+///
+///     fn init_runtime(base: Base<Node>) -> Self {
+///         Self::init_common(base)
+///     }
+///
+///     fn init_common(base: Base<Node>) -> Self {
+///         Self { preview: false, base }
+///     }
+/// }
+/// ```
+#[proc_macro_derive(
+    GodotClass,
+    attributes(class, base, var, export, serialize, init, signal)
+)]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
 }
@@ -378,6 +619,10 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// Note that `init` can be either provided by overriding it, or generated with a `#[class(init)]` attribute on the struct.
 /// Classes without `init` cannot be instantiated from GDScript.
 ///
+/// The virtual trait must belong to the class's base or one of its ancestors: with `base=Node2D`, you can implement
+/// `Node2DVirtual` or `NodeVirtual`, but not `Sprite2DVirtual`. A mismatch is a compile error, since Godot would never
+/// call the overridden methods.
+///
 /// ## `Node` as a base, generated `init`
 ///
 /// ```no_run
@@ -397,6 +642,251 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// ## Static factory functions
+///
+/// A `#[func]` without `self` receiver is registered as a static method. Returning `Gd<Self>` makes it a factory,
+/// callable from GDScript as `Enemy.create(10)`. The name `new` is reserved for the Godot constructor and cannot be used.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// pub struct Enemy {
+///     health: i64,
+/// }
+///
+/// #[godot_api]
+/// impl Enemy {
+///     #[func]
+///     fn create(health: i64) -> Gd<Self> {
+///         Gd::new(Self { health })
+///     }
+/// }
+/// ```
+///
+/// ## Receiving `Gd<Self>` instead of `self`
+///
+/// With `#[func(gd_self)]`, the first parameter is a `Gd<Self>` pointing to the object the method is called on. It replaces
+/// the `self` receiver and is not visible to Godot, so the method remains a regular instance method for GDScript callers.
+/// This is useful to pass the object itself to Godot APIs that expect a `Gd`.
+///
+/// The instance is **not** bound when the function is entered. Use `bind()`/`bind_mut()` to access fields, and make sure
+/// no guard is held while calling into Godot code which may call back into the same object (e.g. by emitting a signal),
+/// as that would re-enter and panic on the conflicting borrow.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// pub struct Player {
+///     score: i64,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[func(gd_self)]
+///     fn add_score(mut this: Gd<Self>, points: i64) {
+///         this.bind_mut().score += points;
+///
+///         // Guard is released here, so Godot may safely call back into this object.
+///         let target = this.upcast::<Node>();
+///         godot_print!("scored: {target}");
+///     }
+/// }
+/// ```
+///
+/// ## Virtual functions, overridable in scripts
+///
+/// With `#[func(virtual)]`, the function is registered as a virtual method under the Godot name `_<name>` (or the one given
+/// with `rename`), so GDScript subclasses of the class can override it. The Rust function body acts as the default
+/// implementation that runs when there is no override.
+///
+/// Calling the Rust function directly always runs the default. To dispatch to an override, use the generated associated
+/// function `call_<name>()`, which takes the object as a `&Gd<Self>` and calls the method dynamically through Godot.
+/// Since the default implementation binds the instance, the object must not be bound while `call_<name>()` runs.
+/// Calling it from a `#[func(gd_self)]` method avoids that.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// pub struct Weapon {
+///     base_damage: i64,
+/// }
+///
+/// #[godot_api]
+/// impl Weapon {
+///     /// Overridable as `func _damage(target: Node) -> int` in a GDScript that `extends Weapon`.
+///     #[func(virtual)]
+///     fn damage(&self, _target: Gd<Node>) -> i64 {
+///         self.base_damage
+///     }
+///
+///     #[func(gd_self)]
+///     fn attack(this: Gd<Self>, target: Gd<Node>) {
+///         let damage = Self::call_damage(&this, target);
+///         godot_print!("dealt {damage} damage");
+///     }
+/// }
+/// ```
+///
+/// ## Async functions
+///
+/// A `#[func]` can be an `async fn`. When called, the returned future is spawned onto the main-thread executor in
+/// [`godot::task`](../task/index.html) and the call returns immediately, running everything up to the first pending
+/// `.await`. Instance methods must use `gd_self`, since the future cannot hold a borrow of `self` across awaits; the
+/// task is then cancelled once the object is freed. Async functions cannot return a value and cannot be `virtual`.
+///
+/// The future owns `this`, so for `RefCounted` classes, a pending call keeps the object alive until the future completes.
+/// To not extend its lifetime over a long wait, keep only `this.instance_id()` across the `.await` and get the object back
+/// with `Gd::try_from_instance_id()` afterwards.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///# use godot::task;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// pub struct Door {
+///     open: bool,
+/// }
+///
+/// #[godot_api]
+/// impl Door {
+///     #[func(gd_self)]
+///     async fn open_next_frame(mut this: Gd<Self>) {
+///         task::next_process_frame().await.unwrap();
+///         this.bind_mut().open = true;
+///     }
+/// }
+/// ```
+///
+/// ## Object parameters
+///
+/// Godot passes objects as a copy of their reference, so a `#[func]` takes them as `Gd<T>` by value. Changes made through
+/// it (e.g. setting a property) are visible to the caller, but there are no out-parameters: assigning a different object
+/// to the parameter has no effect outside the function. Reference parameters like `&mut Gd<Node>` are thus rejected at
+/// compile time. To hand back another object, return it instead.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// pub struct Spawner;
+///
+/// #[godot_api]
+/// impl Spawner {
+///     /// Renames `node` in place; the caller sees the new name.
+///     #[func]
+///     fn rename(&self, mut node: Gd<Node>, name: GodotString) {
+///         node.set_name(name);
+///     }
+///
+///     /// Returns a replacement, since `node` itself cannot be reassigned for the caller.
+///     #[func]
+///     fn replace(&self, node: Gd<Node>) -> Gd<Node> {
+///         let mut replacement = Node::new_alloc();
+///         replacement.set_name(GodotString::from(&node.get_name()));
+///         replacement
+///     }
+/// }
+/// ```
+///
+/// ## Default parameters
+///
+/// Trailing parameters of a `#[func]` can be given default values with `#[default(value)]`, so that GDScript callers may omit
+/// them. The value is converted with `Into` to the parameter's type; a value of an unrelated type is a compile error. Once
+/// a parameter has a default, all following ones need one as well.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// pub struct Greeter;
+///
+/// #[godot_api]
+/// impl Greeter {
+///     // GDScript: greet("Ann"), greet("Ann", "Hi"), greet("Ann", "Hi", 3)
+///     #[func]
+///     fn greet(&self, name: GodotString, #[default("Hello")] greeting: GodotString, #[default(1)] times: i64) {
+///         for _ in 0..times {
+///             godot_print!("{greeting}, {name}!");
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Remote procedure calls
+///
+/// For high-level multiplayer, a `#[func]` of a node class can additionally be marked with `#[rpc]`, which configures it like
+/// GDScript's `@rpc` annotation. The following keys are supported; the defaults are the same as in GDScript:
+/// * `authority` (default) or `any_peer`: who may call the method remotely. This is checked by the receiving peer.
+/// * `unreliable` (default), `unreliable_ordered` or `reliable`: the transfer mode.
+/// * `call_remote` (default) or `call_local`: whether the method also runs on the calling peer.
+/// * `channel = n`: the channel used for sending, default 0.
+///
+/// Godot stores RPC configurations per node, so they are applied to every new instance of the class. To call the method on
+/// the other peers, use the generated associated function `rpc_<name>()`, which takes the object as a `&Gd<Self>` and returns
+/// the error code of `Node::rpc()`. Arguments are converted to variants and serialized by Godot, which by default does not
+/// allow objects; pass built-in types (such as numbers, strings, vectors or arrays of those) instead. With `call_local`, the
+/// method runs locally before `rpc_<name>()` returns, so, as with `call_<name>()`, the object must not be bound at that time.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// pub struct Player {
+///     health: i64,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[func]
+///     #[rpc(authority, reliable, call_local, channel = 1)]
+///     fn take_damage(&mut self, amount: i64) {
+///         self.health -= amount;
+///     }
+///
+///     #[func(gd_self)]
+///     fn hit(this: Gd<Self>) {
+///         Self::rpc_take_damage(&this, 10);
+///     }
+/// }
+/// ```
+///
+/// ## Iteration from GDScript
+///
+/// `#[godot_api]` can also be applied to an `impl ScriptIterable for MyClass` block. This registers the methods that GDScript's
+/// `for item in object` loop calls, so a Rust collection type can be iterated like a built-in one. See the
+/// [`ScriptIterable`](../obj/trait.ScriptIterable.html) trait for details.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///# use godot::obj::ScriptIterable;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=RefCounted)]
+/// pub struct Countdown {
+///     start: i64,
+/// }
+///
+/// #[godot_api]
+/// impl ScriptIterable for Countdown {
+///     type Item = i64;
+///
+///     fn item_at(&self, index: usize) -> Option<i64> {
+///         let value = self.start - index as i64;
+///         (value >= 0).then_some(value)
+///     }
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
     translate(input, class::attribute_godot_api)
@@ -520,6 +1010,26 @@ pub fn derive_export(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_export)
 }
 
+/// Derive macro for [InputAction](../engine/trait.InputAction.html) on enums with unit variants.
+///
+/// Each variant is mapped to the `snake_case` version of its name, e.g. `MoveLeft` to the action `"move_left"`. To use another
+/// name, annotate the variant with `#[action(name = "...")]`:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(InputAction)]
+/// enum MenuAction {
+///     #[action(name = "ui_accept")]
+///     Confirm,
+///     #[action(name = "ui_cancel")]
+///     Back,
+/// }
+/// ```
+#[proc_macro_derive(InputAction, attributes(action))]
+pub fn derive_input_action(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_input_action)
+}
+
 /// Similar to `#[test]`, but runs an integration test with Godot.
 ///
 /// Transforms the `fn` into one returning `bool` (success of the test), which must be called explicitly.
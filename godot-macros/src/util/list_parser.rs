@@ -147,6 +147,21 @@ impl ListParser {
         Ok(Some(id))
     }
 
+    /// Take the next element of the list, if it is an expression other than a single identifier.
+    ///
+    /// Returns `Ok(None)` if there are no more elements left or the next element is an identifier.
+    pub fn try_next_non_ident_expr(&mut self) -> ParseResult<Option<TokenStream>> {
+        let Some(kv) = self.peek() else {
+            return Ok(None);
+        };
+
+        if kv.as_ident().is_ok() {
+            return Ok(None);
+        }
+
+        self.next_expr().map(Some)
+    }
+
     /// Take the next element of the list, ensuring it is one of the given identifiers.
     ///
     /// Returns `Ok(None)` if there are no more elements left.
@@ -110,7 +110,8 @@
 //! * **`double-precision`**
 //!
 //!   Use `f64` instead of `f32` for the floating-point type [`real`][type@builtin::real]. Requires Godot to be compiled with the
-//!   scons flag `precision=double`.<br><br>
+//!   scons flag `precision=double`. This also switches the components of all math types (`Vector2`, `Vector3`, `Basis`, ...).
+//!   A mismatch with the engine build is detected when the extension is initialized, and causes a panic.<br><br>
 //!
 //! * **`custom-godot`**
 //!
@@ -135,7 +136,26 @@
 //!
 //!   Experimental threading support. This enables `Send`/`Sync` traits for `Gd<T>` and makes the guard types `Gd`/`GdMut` aware of
 //!   multi-threaded references. The safety aspects of this are not ironed out yet; use at your own risk. The API may also change
-//!   at any time.
+//!   at any time.<br><br>
+//!
+//! * **`signal-stats`**
+//!
+//!   Count how often each signal is emitted from Rust, and how many connected callbacks these emissions reach. The statistics
+//!   are accessible through [`profiling::signal_stats()`](crate::profiling::signal_stats) and are meant to help find signal storms.
+//!   Adds a small overhead to every `emit_signal()` call.<br><br>
+//!
+//! * **`gd-drop-trace`**
+//!
+//!   Log every drop of a `Gd<T>` pointing to a `RefCounted` object, with its class, instance ID and the reference count
+//!   remaining after the drop. Complements [`Gd::ref_count()`](crate::obj::Gd::ref_count) when hunting reference leaks.
+//!   Very verbose; meant only for debugging sessions. Without the feature, `Gd::drop` is not affected.<br><br>
+//!
+//! * **`glam-interop`**
+//!
+//!   Implement `From` conversions between the vector types, `Quaternion` and `Color` and their counterparts in
+//!   [glam](https://docs.rs/glam) 0.23. With `double-precision`, the `f64` types (`DVec3`, `DQuat`, ...) are used, except for `Color`.
+//!   Components keep their order (`x, y, z, w` or `r, g, b, a`) and are not transformed: both libraries are agnostic of
+//!   handedness, and Godot's convention (right-handed, Y up, -Z forward) carries over unchanged.
 //!
 //! # Public API
 //!
@@ -154,22 +174,31 @@
 //! This allows us to decide whether it fits the scope of the library and to design proper APIs for it.
 
 #[doc(inline)]
-pub use godot_core::{builtin, engine, log, obj};
+pub use godot_core::{builtin, engine, log, obj, task};
 
 #[doc(hidden)]
 pub use godot_core::sys;
 
+#[cfg(feature = "signal-stats")]
+pub use godot_core::profiling;
+
 pub mod init {
     pub use godot_core::init::*;
 
     // Re-exports
+    pub use godot_core::{
+        register_class_dynamic, unregister_class_dynamic, ClassRegistrationError,
+        DynamicClassDescriptor,
+    };
     pub use godot_macros::gdextension;
 }
 
 /// Export user-defined classes and methods to be called by the engine.
 pub mod bind {
     pub use godot_core::property;
-    pub use godot_macros::{godot_api, Export, FromVariant, GodotClass, Property, ToVariant};
+    pub use godot_macros::{
+        godot_api, Export, FromVariant, GodotClass, InputAction, Property, ToVariant,
+    };
 }
 
 /// Testing facilities (unstable).
@@ -184,23 +213,32 @@ pub use godot_core::private;
 /// Often-imported symbols.
 pub mod prelude {
     pub use super::bind::property::{Export, Property, TypeStringHint};
-    pub use super::bind::{godot_api, Export, FromVariant, GodotClass, Property, ToVariant};
+    pub use super::bind::{
+        godot_api, Export, FromVariant, GodotClass, InputAction, Property, ToVariant,
+    };
 
     pub use super::builtin::math::FloatExt as _;
     pub use super::builtin::*;
-    pub use super::builtin::{array, dict, varray}; // Re-export macros.
+    pub use super::builtin::{array, dict, sname, variant_match, varray}; // Re-export macros.
     pub use super::engine::{
         load, try_load, utilities, AudioStreamPlayer, AudioStreamPlayerVirtual, Camera2D,
-        Camera2DVirtual, Camera3D, Camera3DVirtual, Input, Node, Node2D, Node2DVirtual, Node3D,
-        Node3DVirtual, NodeVirtual, Object, ObjectVirtual, PackedScene, PackedSceneExt,
-        PackedSceneVirtual, RefCounted, RefCountedVirtual, Resource, ResourceVirtual, SceneTree,
-        SceneTreeVirtual,
+        Camera2DVirtual, Camera3D, Camera3DVirtual, Input, InputAction, Node, Node2D,
+        Node2DVirtual, Node3D, Node3DVirtual, NodeVirtual, Object, ObjectVirtual, PackedScene,
+        PackedSceneExt, PackedSceneVirtual, RefCounted, RefCountedVirtual, Resource,
+        ResourceVirtual, SceneTree, SceneTreeVirtual,
     };
     pub use super::init::{gdextension, ExtensionLayer, ExtensionLibrary, InitHandle, InitLevel};
     pub use super::log::*;
-    pub use super::obj::{Base, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId, Share};
+    pub use super::obj::{
+        Base, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId, OnReady, Share,
+    };
 
     // Make trait methods available
+    pub use super::engine::EngineExt as _;
+    pub use super::engine::InputExt as _;
     pub use super::engine::NodeExt as _;
+    pub use super::engine::ObjectExt as _;
+    pub use super::engine::SceneTreeExt as _;
+    pub use super::obj::cap::WithBaseField as _;
     pub use super::obj::EngineEnum as _;
 }
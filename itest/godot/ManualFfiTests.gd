@@ -304,3 +304,78 @@ func test_func_rename():
 	assert_eq(func_rename.has_method("renamed_static"), false)
 	assert_eq(func_rename.has_method("spell_static"), true)
 	assert_eq(func_rename.spell_static(), "static")
+
+func test_func_gd_self():
+	var obj := GdSelfReference.new()
+	var other := GdSelfReference.new()
+
+	assert_eq(obj.update_internal(42), 42)
+	assert_eq(obj.get_internal(), 42)
+	assert_eq(obj.get_internal_via_godot(), 42)
+
+	assert_eq(obj.is_same(obj), true)
+	assert_eq(obj.is_same(other), false)
+
+func test_func_object_params():
+	var obj := FuncObjectParams.new()
+	var node := Node.new()
+
+	obj.rename_node(node, "renamed")
+	assert_eq(node.name, &"renamed")
+
+	var replacement: Node = obj.replace_node(node)
+	assert_that(replacement != node, "replacement is a different object")
+	assert_eq(replacement.name, &"renamed")
+
+	replacement.free()
+	node.free()
+
+class VirtualOverride extends FuncVirtualBase:
+	var was_reset := false
+
+	func _describe(prefix: String) -> String:
+		return prefix + "script"
+
+	func _reset() -> void:
+		was_reset = true
+
+func test_func_virtual_override():
+	var base := FuncVirtualBase.new()
+	assert_eq(base.describe_dispatched("base: "), "base: rust 7")
+
+	var derived := VirtualOverride.new()
+	assert_eq(derived.describe_dispatched("derived: "), "derived: script")
+
+	derived.reset_dispatched()
+	assert_that(derived.was_reset, "script override called")
+	assert_eq(derived.get_level(), 7, "Rust default not called")
+
+func test_func_script_iterable():
+	var collection := FuncIterable.new()
+	collection.push(3)
+	collection.push(1)
+	collection.push(4)
+
+	var seen := []
+	for item in collection:
+		seen.append(item)
+	assert_eq(seen, [3, 1, 4])
+
+	# Each loop starts from the beginning.
+	var count := 0
+	for item in collection:
+		count += 1
+	assert_eq(count, 3)
+
+func test_func_default_params():
+	var obj := FuncDefaults.new()
+	assert_eq(obj.sum(1), 106)
+	assert_eq(obj.sum(1, 2), 103)
+	assert_eq(obj.sum(1, 2, 3), 6)
+	assert_eq(obj.label("id: "), "id: none")
+
+func test_hidden_class_new():
+	# Hidden classes are only left out of the editor's dialogs; GDScript can still construct them.
+	var obj = HiddenClass.new()
+	assert_eq(obj.get_class(), "HiddenClass")
+	obj.free()
@@ -47,3 +47,41 @@ func test_collision_object_2d_input_event():
 
 	window.queue_free()
 
+# Deferred calls are only run at idle time, so frames must pass before the effect is visible.
+func test_callable_call_deferred():
+	var tree: SceneTree = Engine.get_main_loop()
+
+	var obj := CallableTestObj.new()
+	obj.foo_deferred(10)
+	assert_eq(obj.value, 0, "not invoked immediately")
+
+	await tree.process_frame
+	await tree.process_frame
+	assert_eq(obj.value, 10, "invoked at idle time")
+
+func test_signals_typed_emit_deferred():
+	var tree: SceneTree = Engine.get_main_loop()
+
+	var emitter := Emitter.new()
+	var receiver := Receiver.new()
+	emitter.signal_1_arg.connect(receiver.receive_1_arg)
+
+	emitter.emit_1_arg_deferred(987)
+	assert_that(not receiver.was_used(1), "not emitted immediately")
+
+	await tree.process_frame
+	await tree.process_frame
+	assert_that(receiver.was_used(1), "emitted at idle time")
+
+	# Pending emission is dropped once the emitter is freed.
+	var late_receiver := Receiver.new()
+	emitter.signal_1_arg.connect(late_receiver.receive_1_arg)
+	emitter.emit_1_arg_deferred(987)
+	emitter.free()
+
+	await tree.process_frame
+	await tree.process_frame
+	assert_that(not late_receiver.was_used(1), "dropped after emitter is freed")
+
+	receiver.free()
+	late_receiver.free()
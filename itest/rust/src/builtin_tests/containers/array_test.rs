@@ -80,6 +80,53 @@ fn array_try_into_vec() {
     assert_eq!(result, Ok(vec![1, 2]));
 }
 
+#[itest]
+fn array_into_vec() {
+    let array = array![1, 2, 3];
+    let shared = array.share();
+    assert_eq!(array.into_vec(), vec![1, 2, 3]);
+    assert_eq!(shared.len(), 3, "other references are unaffected");
+
+    assert_eq!(Array::<i64>::new().into_vec(), Vec::<i64>::new());
+    assert_eq!(
+        varray![1, "two"].try_into_vec(),
+        Ok(vec![1.to_variant(), "two".to_variant()])
+    );
+
+    // Both are INT arrays in Godot, but 300 does not fit into u8.
+    let bytes = Array::<u8>::from_variant(&array![1, 300].to_variant());
+    assert!(bytes.share().try_into_vec().is_err());
+    expect_panic("into_vec() with out-of-range element", || {
+        bytes.into_vec();
+    });
+}
+
+#[itest]
+fn array_map_collect() {
+    let array = array![1, 2, 3];
+
+    let strings: Array<GodotString> = array.map_collect(|i| GodotString::from(format!("#{i}")));
+    assert_eq!(strings, array!["#1".into(), "#2".into(), "#3".into()]);
+    assert_eq!(
+        strings.as_inner().get_typed_builtin(),
+        VariantType::String as i64,
+        "output array is typed"
+    );
+
+    let variants: VariantArray = array.map_collect(|i| (i * 2).to_variant());
+    assert_eq!(variants, varray![2, 4, 6]);
+
+    // The closure may modify the array through another reference.
+    let mut shared = array.share();
+    let doubled = array.map_collect(|i| {
+        if i == 1 {
+            shared.set(2, 30);
+        }
+        i * 2
+    });
+    assert_eq!(doubled, array![2, 4, 60]);
+}
+
 #[itest]
 fn array_iter_shared() {
     let array = array![1, 2];
@@ -209,6 +256,23 @@ fn array_rfind() {
     assert_eq!(array.rfind(&1, Some(1)), Some(0));
 }
 
+#[itest]
+fn array_position_find_by() {
+    let array = array![1, 4, 9, 16];
+
+    assert_eq!(array.position(|&i| i > 3), Some(1));
+    assert_eq!(array.position(|&i| i > 20), None);
+    assert_eq!(array.find_by(|i| i % 2 == 0), Some(4));
+    assert_eq!(array.find_by(|&i| i == 5), None);
+
+    let strings: Array<GodotString> = array!["a".into(), "bc".into()];
+    assert_eq!(strings.position(|s| s.len_chars() == 2), Some(1));
+
+    let empty = Array::<i64>::new();
+    assert_eq!(empty.position(|_| true), None);
+    assert_eq!(empty.find_by(|_| true), None);
+}
+
 #[itest]
 fn array_min_max() {
     let int_array = array![1, 2];
@@ -281,6 +345,25 @@ fn array_extend() {
     assert_eq!(array, array![1, 2, 3, 4]);
 }
 
+#[itest]
+fn array_fill_resize_with() {
+    let mut array = Array::<i64>::new();
+    array.resize_with(4, |i| i as i64 * 10);
+    assert_eq!(array, array![0, 10, 20, 30]);
+
+    // Shrinking does not call the closure.
+    array.resize_with(2, |_| panic!("must not be called when shrinking"));
+    assert_eq!(array, array![0, 10]);
+
+    // Shared references observe the change.
+    let shared = array.share();
+    array.resize_with(3, |i| -(i as i64));
+    assert_eq!(shared, array![0, 10, -2]);
+
+    array.fill(&7);
+    assert_eq!(shared, array![7, 7, 7]);
+}
+
 #[itest]
 fn array_sort() {
     let mut array = array![2, 1];
@@ -288,6 +371,63 @@ fn array_sort() {
     assert_eq!(array, array![1, 2]);
 }
 
+#[itest]
+fn array_sort_by_key() {
+    let mut array = array![3, -5, 1, -2];
+    let shared = array.share();
+    array.sort_unstable_by_key(|v: &i64| v.abs());
+    assert_eq!(array, array![1, -2, 3, -5]);
+    assert_eq!(shared, array![1, -2, 3, -5]);
+
+    let mut empty = Array::<i64>::new();
+    empty.sort_unstable_by_key(|v| -v);
+    assert!(empty.is_empty());
+}
+
+#[itest]
+fn array_slice_to_vec() {
+    let array = array![0, 1, 2, 3, 4];
+
+    assert_eq!(array.slice_to_vec(1..3), vec![1, 2]);
+    assert_eq!(array.slice_to_vec(1..=3), vec![1, 2, 3]);
+    assert_eq!(array.slice_to_vec(..2), vec![0, 1]);
+    assert_eq!(array.slice_to_vec(3..), vec![3, 4]);
+    assert_eq!(array.slice_to_vec(..), vec![0, 1, 2, 3, 4]);
+    assert_eq!(array.slice_to_vec(5..), Vec::<i64>::new());
+
+    expect_panic("end out of bounds", || {
+        array.slice_to_vec(2..6);
+    });
+    expect_panic("inclusive end out of bounds", || {
+        array.slice_to_vec(..=5);
+    });
+    expect_panic("start after end", || {
+        #[allow(clippy::reversed_empty_ranges)]
+        array.slice_to_vec(3..2);
+    });
+}
+
+#[itest]
+fn array_dedup() {
+    let mut array = array![1, 1, 2, 3, 3, 3, 1];
+    let shared = array.share();
+    array.dedup();
+    assert_eq!(array, array![1, 2, 3, 1]);
+    assert_eq!(shared, array![1, 2, 3, 1]);
+
+    let mut array = array![10, -10, 20, 21, 30];
+    array.dedup_by_key(|v: &mut i64| *v / 10 * v.signum());
+    assert_eq!(array, array![10, 20, 30]);
+
+    let mut empty = Array::<i64>::new();
+    empty.dedup();
+    assert!(empty.is_empty());
+
+    let mut single = array![7];
+    single.dedup_by_key(|v: &mut i64| *v);
+    assert_eq!(single, array![7]);
+}
+
 #[itest]
 fn array_reverse() {
     let mut array = array![1, 2];
@@ -302,6 +442,27 @@ fn array_shuffle() {
     assert_eq!(array, array![1]);
 }
 
+#[itest]
+fn array_shuffle_seeded() {
+    let mut array: Array<i64> = (0..10).collect();
+    array.shuffle_seeded(42);
+
+    // Fixed expectation: the permutation for a given seed must be stable across platforms and versions.
+    assert_eq!(array, array![8, 3, 6, 5, 4, 0, 9, 2, 1, 7]);
+
+    let mut other: Array<i64> = (0..10).collect();
+    other.shuffle_seeded(43);
+    assert_eq!(other, array![4, 9, 2, 1, 6, 0, 8, 3, 5, 7]);
+
+    let mut empty = Array::<i64>::new();
+    empty.shuffle_seeded(42);
+    assert!(empty.is_empty());
+
+    let mut single = array![1];
+    single.shuffle_seeded(42);
+    assert_eq!(single, array![1]);
+}
+
 #[itest]
 fn array_mixed_values() {
     let int = 1;
@@ -355,6 +516,36 @@ fn array_mixed_values() {
     node.free();
 }
 
+#[itest]
+fn array_get_object() {
+    let ref_counted = RefCounted::new();
+    let node = Node::new_alloc();
+
+    let mut array = varray![ref_counted.share(), node.share(), 42, Variant::nil()];
+    assert_eq!(ref_counted.ref_count(), Some(2));
+
+    let extracted = array.get_object::<RefCounted>(0).expect("live RefCounted");
+    assert_eq!(extracted, ref_counted);
+    assert_eq!(ref_counted.ref_count(), Some(3));
+
+    assert_eq!(array.get_object::<Node>(1), Some(node.share()));
+    assert_eq!(array.get_object::<Object>(1), Some(node.share().upcast()));
+    assert_eq!(array.get_object::<Node>(0), None);
+    assert_eq!(array.get_object::<Object>(2), None);
+    assert_eq!(array.get_object::<Object>(3), None);
+    assert_eq!(array.get_object::<Object>(4), None);
+
+    // The array keeps referring to the freed node, which must not be converted anymore.
+    node.free();
+    assert_eq!(array.get_object::<Node>(1), None);
+    assert!(Gd::<Node>::try_from_variant(&array.get(1)).is_err());
+
+    // The extracted pointer holds its own reference.
+    array.clear();
+    assert_eq!(ref_counted.ref_count(), Some(2));
+    assert!(extracted.is_instance_valid());
+}
+
 #[itest]
 fn untyped_array_pass_to_godot_func() {
     let mut node = Node::new_alloc();
@@ -438,6 +629,32 @@ fn untyped_array_try_from_typed() {
     node.free();
 }
 
+#[itest]
+fn array_for_each_bind() {
+    let array: Array<Gd<ArrayBindTest>> = (1..=3)
+        .map(|value| Gd::new(ArrayBindTest { value }))
+        .collect();
+
+    array.for_each_bind_mut(|element| element.value *= 10);
+
+    let mut values = vec![];
+    array.for_each_bind(|element| values.push(element.value));
+    assert_eq!(values, vec![10, 20, 30]);
+
+    // Element is already borrowed elsewhere.
+    let first = array.get(0);
+    let _guard = first.bind();
+    expect_panic("for_each_bind_mut() while element is borrowed", || {
+        array.for_each_bind_mut(|element| element.value = 0);
+    });
+}
+
+#[derive(GodotClass, Debug)]
+#[class(init, base=RefCounted)]
+struct ArrayBindTest {
+    value: i64,
+}
+
 #[derive(GodotClass, Debug)]
 #[class(init, base=RefCounted)]
 struct ArrayTest;
@@ -4,6 +4,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::HashSet;
+
 use godot::bind::{godot_api, GodotClass};
 use godot::builtin::inner::InnerCallable;
 use godot::builtin::{varray, Callable, GodotString, StringName, ToVariant, Variant};
@@ -15,6 +17,7 @@ use crate::framework::itest;
 #[derive(GodotClass)]
 #[class(init, base=RefCounted)]
 struct CallableTestObj {
+    #[var]
     value: i32,
 }
 
@@ -25,6 +28,12 @@ impl CallableTestObj {
         self.value = a;
     }
 
+    /// Used by `test_callable_call_deferred` in SpecialTests.gd, which can let frames pass.
+    #[func(gd_self)]
+    fn foo_deferred(this: Gd<Self>, a: i32) {
+        this.callable("foo").call_deferred(&[a.to_variant()]);
+    }
+
     #[func]
     fn bar(&self, b: i32) -> GodotString {
         b.to_variant().stringify()
@@ -61,6 +70,29 @@ fn callable_hash() {
     assert_ne!(obj.callable("foo").hash(), obj.callable("bar").hash());
 }
 
+#[itest]
+fn callable_eq_in_hash_set() {
+    let obj = Gd::<CallableTestObj>::new_default();
+    let other = Gd::<CallableTestObj>::new_default();
+
+    assert_eq!(obj.callable("foo"), obj.callable("foo"));
+    assert_ne!(obj.callable("foo"), obj.callable("bar"));
+    assert_ne!(obj.callable("foo"), other.callable("foo"));
+
+    let connected: HashSet<Callable> = [
+        obj.callable("foo"),
+        obj.callable("bar"),
+        obj.callable("foo"),
+        other.callable("foo"),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(connected.len(), 3);
+    assert!(connected.contains(&obj.callable("bar")));
+    assert!(!connected.contains(&other.callable("bar")));
+}
+
 #[itest]
 fn callable_object_method() {
     let obj = Gd::<CallableTestObj>::new_default();
@@ -93,6 +125,17 @@ fn callable_call() {
     assert_eq!(Callable::invalid().callv(varray![1, 2, 3]), Variant::nil());
 }
 
+#[itest]
+fn callable_call_deferred() {
+    let obj = Gd::<CallableTestObj>::new_default();
+    let callable = obj.callable("foo");
+
+    // Not invoked before the end of the frame; see SpecialTests.gd for the invocation. Once the object is freed, the deferred
+    // call is discarded.
+    callable.call_deferred(&[10.to_variant()]);
+    assert_eq!(obj.bind().value, 0);
+}
+
 #[itest]
 fn callable_call_return() {
     let obj = Gd::<CallableTestObj>::new_default();
@@ -6,7 +6,8 @@
 
 use std::collections::{HashMap, HashSet};
 
-use godot::builtin::{dict, varray, Dictionary, FromVariant, ToVariant, Variant};
+use godot::builtin::{dict, varray, Dictionary, FromVariant, GodotString, ToVariant, Variant};
+use godot::engine::{Node, Object, RefCounted};
 use godot::obj::Share;
 
 use crate::framework::{expect_panic, itest};
@@ -36,6 +37,15 @@ fn dictionary_from_iterator() {
     assert_eq!(dictionary.get(2), Some("bar".to_variant()), "key = 2");
 }
 
+#[itest]
+fn dictionary_collect_duplicate_keys() {
+    let dictionary: Dictionary = [("a", 1), ("b", 2), ("a", 3)].into_iter().collect();
+
+    assert_eq!(dictionary.len(), 2);
+    assert_eq!(dictionary.get("a"), Some(3.to_variant()), "last value wins");
+    assert_eq!(dictionary.get("b"), Some(2.to_variant()));
+}
+
 #[itest]
 fn dictionary_from() {
     let dictionary = Dictionary::from(&HashMap::from([("foo", 1), ("bar", 2)]));
@@ -186,6 +196,22 @@ fn dictionary_get() {
     assert_eq!(dictionary.get("foobar"), None, "key = \"foobar\"");
 }
 
+#[itest]
+fn dictionary_get_typed() {
+    let dictionary = dict! {
+        "int": 7,
+        "string": "text",
+    };
+
+    assert_eq!(dictionary.get_typed::<i64>("int"), Ok(Some(7)));
+    assert_eq!(dictionary.get_typed::<i64>("missing"), Ok(None));
+    assert!(dictionary.get_typed::<i64>("string").is_err());
+
+    assert_eq!(dictionary.get_or("int", 0), 7);
+    assert_eq!(dictionary.get_or("missing", 3), 3);
+    assert_eq!(dictionary.get_or("string", 3), 3);
+}
+
 #[itest]
 fn dictionary_insert() {
     let mut dictionary = dict! {
@@ -326,6 +352,31 @@ fn dictionary_keys_values() {
     assert_eq!(dictionary.values_array(), varray![0, true]);
 }
 
+#[itest]
+fn dictionary_keys_values_typed() {
+    let dictionary = dict! {
+        "foo": 1,
+        "bar": 2,
+    };
+
+    assert_eq!(
+        dictionary.keys_typed::<GodotString>(),
+        Ok(vec!["foo".into(), "bar".into()])
+    );
+    assert_eq!(dictionary.values_typed::<i64>(), Ok(vec![1, 2]));
+    assert!(dictionary.values_typed::<bool>().is_err());
+
+    // Heterogeneous values fail as a whole.
+    let mixed = dict! {
+        "foo": 1,
+        "bar": "text",
+    };
+    assert!(mixed.keys_typed::<GodotString>().is_ok());
+    assert!(mixed.values_typed::<i64>().is_err());
+
+    assert_eq!(Dictionary::new().keys_typed::<i64>(), Ok(vec![]));
+}
+
 #[itest]
 fn dictionary_equal() {
     assert_eq!(dict! {"foo": "bar"}, dict! {"foo": "bar"});
@@ -585,3 +636,28 @@ fn dictionary_iter_erase() {
     ```
      */
 }
+
+#[itest]
+fn dictionary_get_object() {
+    let ref_counted = RefCounted::new();
+    let node = Node::new_alloc();
+
+    let mut dictionary = dict! {
+        "counted": ref_counted.share(),
+        "node": node.share(),
+        "number": 42,
+    };
+
+    let extracted = dictionary.get_object::<RefCounted>("counted");
+    assert_eq!(extracted, Some(ref_counted.share()));
+    assert_eq!(dictionary.get_object::<Node>("node"), Some(node.share()));
+    assert_eq!(dictionary.get_object::<Node>("counted"), None);
+    assert_eq!(dictionary.get_object::<Object>("number"), None);
+    assert_eq!(dictionary.get_object::<Object>("missing"), None);
+
+    node.free();
+    assert_eq!(dictionary.get_object::<Node>("node"), None);
+
+    dictionary.clear();
+    assert_eq!(extracted.unwrap().ref_count(), Some(2));
+}
@@ -5,7 +5,11 @@
  */
 
 use crate::framework::{expect_panic, itest};
-use godot::builtin::{PackedByteArray, PackedFloat32Array, PackedStringArray};
+use godot::builtin::{
+    Color, PackedByteArray, PackedColorArray, PackedFloat32Array, PackedInt32Array,
+    PackedInt64Array, PackedStringArray, PackedVector3Array, Vector3,
+};
+use godot::engine::file_access::CompressionMode;
 
 #[itest]
 fn packed_array_default() {
@@ -223,3 +227,120 @@ fn packed_array_reverse() {
     array.reverse();
     assert_eq!(array.to_vec(), vec![2, 1]);
 }
+
+#[itest]
+fn packed_vector3_array_bulk() {
+    let vectors = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+
+    let array = PackedVector3Array::from_slice(&vectors);
+    assert_eq!(array.as_slice(), &vectors);
+
+    let raw = PackedVector3Array::from_raw(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    assert_eq!(raw, array);
+
+    let mut target = PackedVector3Array::from(&[Vector3::ZERO; 5]);
+    let shared = target.clone();
+    target.set_from_slice(&vectors[..1]);
+    assert_eq!(target.as_slice(), &vectors[..1]);
+    assert_eq!(
+        shared.len(),
+        5,
+        "copy-on-write keeps other instances unchanged"
+    );
+
+    assert!(PackedVector3Array::from_raw(&[]).is_empty());
+    assert!(PackedVector3Array::from_slice(&[]).is_empty());
+}
+
+#[itest]
+fn packed_color_array_sample() {
+    let gradient =
+        PackedColorArray::from(&[Color::BLACK, Color::WHITE, Color::from_rgb(1.0, 0.0, 0.0)]);
+
+    assert_eq!(gradient.sample(0.0), Color::BLACK);
+    assert_eq!(gradient.sample(0.25), Color::from_rgb(0.5, 0.5, 0.5));
+    assert_eq!(gradient.sample(0.5), Color::WHITE);
+    assert_eq!(gradient.sample(0.75), Color::from_rgb(1.0, 0.5, 0.5));
+    assert_eq!(gradient.sample(1.0), Color::from_rgb(1.0, 0.0, 0.0));
+
+    // Clamped.
+    assert_eq!(gradient.sample(-1.0), Color::BLACK);
+    assert_eq!(gradient.sample(2.0), Color::from_rgb(1.0, 0.0, 0.0));
+
+    let single = PackedColorArray::from(&[Color::WHITE]);
+    assert_eq!(single.sample(0.3), Color::WHITE);
+
+    expect_panic("sample empty array", || {
+        PackedColorArray::new().sample(0.5);
+    });
+}
+
+#[itest]
+fn packed_byte_array_compress_roundtrip() {
+    let data: Vec<u8> = (0..200).map(|i| (i % 7) as u8).collect();
+    let original = PackedByteArray::from(data.as_slice());
+
+    for mode in [
+        CompressionMode::FASTLZ,
+        CompressionMode::DEFLATE,
+        CompressionMode::ZSTD,
+        CompressionMode::GZIP,
+    ] {
+        let compressed = original.compress(mode);
+        assert!(compressed.len() < original.len(), "repetitive data shrinks");
+
+        let decompressed = compressed.decompress(original.len(), mode);
+        assert_eq!(decompressed, Ok(original.clone()));
+    }
+}
+
+#[itest]
+fn packed_byte_array_decompress_wrong_size() {
+    let original = PackedByteArray::from(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let compressed = original.compress(CompressionMode::ZSTD);
+
+    let err = compressed
+        .decompress(3, CompressionMode::ZSTD)
+        .expect_err("too small expected size");
+    assert_eq!(err.expected_size(), 3);
+
+    assert!(compressed.decompress(100, CompressionMode::ZSTD).is_err());
+}
+
+#[itest]
+fn packed_int_array_bitset() {
+    let mut bits = PackedInt32Array::new();
+    assert!(!bits.get_bit(5));
+
+    // Clearing beyond the end does not grow.
+    bits.set_bit(40, false);
+    assert_eq!(bits.len(), 0);
+
+    bits.set_bit(40, true);
+    assert_eq!(bits.len(), 2);
+    assert_eq!(bits.get(1), 1 << 8);
+
+    bits.set_bit(31, true);
+    assert!(bits.get_bit(31));
+    assert_eq!(bits.get(0), i32::MIN);
+    assert_eq!(bits.count_ones(), 2);
+
+    bits.set_bit(40, false);
+    assert!(!bits.get_bit(40));
+    assert_eq!(bits.count_ones(), 1);
+}
+
+#[itest]
+fn packed_int_array_from_bools() {
+    let bools: Vec<bool> = (0..70).map(|i| i % 3 == 0).collect();
+    let bits = PackedInt64Array::from_bools(&bools);
+
+    assert_eq!(bits.len(), 2);
+    assert_eq!(bits.count_ones(), 24);
+    for (i, &value) in bools.iter().enumerate() {
+        assert_eq!(bits.get_bit(i), value, "bit {i}");
+    }
+    assert!(!bits.get_bit(127));
+
+    assert!(PackedInt32Array::from_bools(&[]).is_empty());
+}
@@ -27,6 +27,12 @@ impl Emitter {
     fn signal_1_arg(arg1: i64);
     #[signal]
     fn signal_2_arg(arg1: Gd<Object>, arg2: GodotString);
+
+    /// Used by `test_signals_typed_emit_deferred` in SpecialTests.gd, which can let frames pass.
+    #[func(gd_self)]
+    fn emit_1_arg_deferred(this: Gd<Self>, arg1: i64) {
+        this.signals().signal_1_arg().emit_deferred((arg1,));
+    }
 }
 
 #[derive(GodotClass)]
@@ -39,6 +45,11 @@ struct Receiver {
 
 #[godot_api]
 impl Receiver {
+    #[func]
+    fn was_used(&self, index: i64) -> bool {
+        self.used[index as usize].get()
+    }
+
     #[func]
     fn receive_0_arg(&self) {
         self.used[0].set(true);
@@ -87,3 +98,78 @@ fn signals() {
     receiver.free();
     emitter.free();
 }
+
+#[itest]
+fn signals_typed() {
+    let emitter = Gd::<Emitter>::new_default();
+    let receiver = Gd::<Receiver>::new_default();
+
+    let mut signal = emitter.signals().signal_1_arg();
+    assert_eq!(signal.name(), "signal_1_arg");
+    assert_eq!(signal.arity(), 1);
+    assert_eq!(emitter.signals().signal_2_arg().arity(), 2);
+
+    let callable = receiver.callable("receive_1_arg");
+    signal.connect(callable.clone());
+    assert!(signal.is_connected(callable.clone()));
+
+    signal.emit((987,));
+    assert!(receiver.bind().used[1].get());
+
+    signal.disconnect(callable.clone());
+    assert!(!signal.is_connected(callable));
+
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signals_typed_emit_deferred() {
+    let emitter = Gd::<Emitter>::new_default();
+    let receiver = Gd::<Receiver>::new_default();
+
+    let mut signal = emitter.signals().signal_1_arg();
+    signal.connect(receiver.callable("receive_1_arg"));
+
+    // Not dispatched before idle time; see SpecialTests.gd for the dispatch.
+    signal.emit_deferred((987,));
+    assert!(!receiver.bind().used[1].get());
+
+    // The pending emission is dropped once the emitter is freed, and emitting on a freed object is a no-op.
+    emitter.free();
+    signal.emit_deferred((987,));
+
+    receiver.free();
+}
+
+#[cfg(feature = "signal-stats")]
+#[itest]
+fn signals_stats() {
+    use godot::profiling::{reset_signal_stats, signal_stats, SignalStats};
+
+    let mut emitter = Gd::<Emitter>::new_default();
+    let receiver = Gd::<Receiver>::new_default();
+    emitter.connect("signal_0_arg".into(), receiver.callable("receive_0_arg"));
+
+    reset_signal_stats();
+    emitter.emit_signal("signal_0_arg".into(), &[]);
+    emitter.emit_signal("signal_0_arg".into(), &[]);
+    emitter.emit_signal("signal_1_arg".into(), &[Variant::from(987)]);
+
+    let stats = signal_stats();
+    let expected = [("signal_0_arg", 2, 2), ("signal_1_arg", 1, 0)].map(
+        |(signal_name, emit_count, callback_count)| SignalStats {
+            class_name: "Emitter".to_string(),
+            signal_name: signal_name.to_string(),
+            emit_count,
+            callback_count,
+        },
+    );
+    assert_eq!(stats, expected);
+
+    reset_signal_stats();
+    assert!(signal_stats().is_empty());
+
+    receiver.free();
+    emitter.free();
+}
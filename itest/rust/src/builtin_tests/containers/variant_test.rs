@@ -8,11 +8,12 @@ use std::cmp::Ordering;
 use std::fmt::Display;
 
 use godot::builtin::{
-    dict, varray, FromVariant, GodotString, NodePath, StringName, ToVariant, Variant, Vector2,
-    Vector3,
+    dict, variant_match, varray, FromVariant, GodotString, NodePath, StringName, ToVariant,
+    Variant, Vector2, Vector3,
 };
 use godot::builtin::{
-    Basis, Dictionary, VariantArray, VariantConversionError, VariantOperator, VariantType,
+    Basis, Dictionary, VariantArray, VariantConversionError, VariantDispatch, VariantOperator,
+    VariantType,
 };
 use godot::engine::Node2D;
 use godot::obj::InstanceId;
@@ -411,6 +412,167 @@ fn variant_hash_correct() {
     assert_ne!(dict! { 0: dict!{ 0: 0 } }, dict! { 0: dict!{ 0: 1 } });
 }
 
+#[itest]
+fn variant_duplicate() {
+    let original = varray![varray![1], 2].to_variant();
+    let shallow = original.duplicate(false);
+    let deep = original.duplicate(true);
+
+    assert_eq!(shallow, original);
+    assert_eq!(deep, original);
+
+    let mut inner = original.to::<VariantArray>().get(0).to::<VariantArray>();
+    inner.push(3.to_variant());
+
+    let nested_len = |v: &Variant| v.to::<VariantArray>().get(0).to::<VariantArray>().len();
+    assert_eq!(nested_len(&shallow), 2, "shallow copy shares nested array");
+    assert_eq!(nested_len(&deep), 1, "deep copy owns nested array");
+
+    let node = Node2D::new_alloc();
+    let object = node.to_variant();
+    assert_eq!(object.duplicate(true), object, "objects are not copied");
+    node.free();
+}
+
+#[itest]
+fn variant_type_ord() {
+    assert_eq!(Variant::nil().type_ord(), 0);
+    assert_eq!(true.to_variant().type_ord(), 1);
+    assert_eq!(7.to_variant().type_ord(), 2);
+    assert_eq!(2.5.to_variant().type_ord(), 3);
+    assert_eq!(GodotString::from("hi").to_variant().type_ord(), 4);
+    assert_eq!(varray![].to_variant().type_ord(), 28);
+    assert_eq!(
+        Vector3::ZERO.to_variant().type_ord(),
+        VariantType::Vector3 as i32
+    );
+
+    let node = Node2D::new_alloc();
+    assert_eq!(node.to_variant().type_ord(), 24);
+    node.free();
+}
+
+#[itest]
+fn variant_match_macro() {
+    fn describe(value: Variant) -> String {
+        variant_match!(value, {
+            Nil => "nil".to_string(),
+            Int(i) => format!("int {i}"),
+            String(s) => format!("string {s}"),
+            Vector3(v) => format!("vector {}", v.x),
+            Object(obj) => format!("object {}", obj.get_class()),
+            _ => "other".to_string(),
+        })
+    }
+
+    assert_eq!(describe(Variant::nil()), "nil");
+    assert_eq!(describe(7.to_variant()), "int 7");
+    assert_eq!(describe("hi".to_variant()), "string hi");
+    assert_eq!(
+        describe(Vector3::new(1.5, 0.0, 0.0).to_variant()),
+        "vector 1.5"
+    );
+    assert_eq!(describe(true.to_variant()), "other");
+
+    let node = Node2D::new_alloc();
+    assert_eq!(describe(node.to_variant()), "object Node2D");
+    node.free();
+}
+
+#[itest]
+fn variant_approx_eq() {
+    let approx = |a: Variant, b: Variant| a.approx_eq(&b, 0.001);
+
+    assert!(approx(1.0.to_variant(), 1.0005.to_variant()));
+    assert!(!approx(1.0.to_variant(), 1.01.to_variant()));
+    assert!(approx(
+        Vector2::new(1.0, 2.0).to_variant(),
+        Vector2::new(1.0005, 1.9995).to_variant()
+    ));
+    assert!(!approx(
+        Vector3::new(1.0, 2.0, 3.0).to_variant(),
+        Vector3::new(1.0, 2.0, 3.1).to_variant()
+    ));
+
+    // NaN and infinity.
+    let nan = f64::NAN.to_variant();
+    assert_ne!(nan, nan.clone());
+    assert!(!approx(nan.clone(), nan));
+    assert!(approx(
+        f64::INFINITY.to_variant(),
+        f64::INFINITY.to_variant()
+    ));
+    assert!(!approx(
+        f64::INFINITY.to_variant(),
+        f64::NEG_INFINITY.to_variant()
+    ));
+
+    // Strict fallback for other types.
+    assert!(approx(1.to_variant(), 1.0.to_variant()));
+    assert!(!approx(1.to_variant(), 1.0005.to_variant()));
+    assert!(approx("text".to_variant(), "text".to_variant()));
+    assert!(!approx(
+        varray![1.0].to_variant(),
+        varray![1.0005].to_variant()
+    ));
+
+    // Objects: identity.
+    let a = Node2D::new_alloc();
+    let b = Node2D::new_alloc();
+    assert!(approx(a.to_variant(), a.to_variant()));
+    assert!(!approx(a.to_variant(), b.to_variant()));
+    a.free();
+    b.free();
+}
+
+#[itest]
+fn variant_dispatch() {
+    let dispatch = |v: Variant| VariantDispatch::from_variant(&v);
+
+    assert!(matches!(dispatch(Variant::nil()), VariantDispatch::Nil));
+    assert!(matches!(
+        dispatch(true.to_variant()),
+        VariantDispatch::Bool(true)
+    ));
+    assert!(matches!(
+        dispatch((-7).to_variant()),
+        VariantDispatch::Int(-7)
+    ));
+    assert!(matches!(
+        dispatch(Vector2::new(1.0, 2.0).to_variant()),
+        VariantDispatch::Vector2(v) if v == Vector2::new(1.0, 2.0)
+    ));
+    assert!(matches!(
+        dispatch("text".to_variant()),
+        VariantDispatch::String(s) if s == GodotString::from("text")
+    ));
+    assert!(matches!(
+        dispatch(varray![1, 2].to_variant()),
+        VariantDispatch::Array(a) if a.len() == 2
+    ));
+
+    let node = Node2D::new_alloc();
+    let id = node.instance_id();
+    assert!(matches!(
+        dispatch(node.to_variant()),
+        VariantDispatch::Object(obj) if obj.instance_id() == id
+    ));
+    node.free();
+
+    // Round-trip through the type.
+    for value in [
+        Variant::nil(),
+        1.5.to_variant(),
+        StringName::from("name").to_variant(),
+        Dictionary::new().to_variant(),
+    ] {
+        assert_eq!(
+            VariantDispatch::from(&value).variant_type(),
+            value.get_type()
+        );
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 fn truncate_bad<T>(original_value: i64)
@@ -517,6 +679,29 @@ where
     }
 }
 
+#[itest]
+fn variant_json() {
+    let parsed = Variant::from_json(r#"{"name": "gdext", "tags": [1, 2.5, true, null]}"#)
+        .expect("valid JSON");
+    let dict = parsed.to::<Dictionary>();
+    assert_eq!(dict.get_or_nil("name"), "gdext".to_variant());
+    assert_eq!(
+        dict.get_or_nil("tags"),
+        varray![1.0, 2.5, true, Variant::nil()].to_variant()
+    );
+
+    // Numbers are always floats, and written with full precision.
+    let pi = std::f64::consts::PI.to_variant();
+    assert_eq!(Variant::from_json(&pi.to_json("").to_string()), Ok(pi));
+
+    let compact = dict! { "a": 1, "b": "x" }.to_variant().to_json("");
+    assert_eq!(compact, gstr(r#"{"a":1,"b":"x"}"#));
+
+    let err = Variant::from_json("{\n\"a\": 1,\n\"b\" 2\n}").expect_err("invalid JSON");
+    assert!(err.line() > 0);
+    assert!(err.message().len_chars() > 0);
+}
+
 fn gstr(s: &str) -> GodotString {
     GodotString::from(s)
 }
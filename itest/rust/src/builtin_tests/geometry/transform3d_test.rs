@@ -78,3 +78,33 @@ fn transform3d_xform_equiv() {
         "operator: Transform3D * Plane"
     );
 }
+
+#[itest]
+fn transform3d_interpolate_with_equiv() {
+    let rotated = Transform3D::IDENTITY
+        .rotated(Vector3::new(1.0, 2.0, 3.0).normalized(), 2.0)
+        .translated(Vector3::new(-3.0, 0.5, 4.0));
+
+    // Non-uniform scale, and mirrored (negative determinant).
+    let scaled = rotated.scaled(Vector3::new(0.5, 2.0, 3.0));
+    let mirrored = rotated.scaled(Vector3::new(-1.0, 2.0, 1.0));
+    assert!(mirrored.basis.determinant() < 0.0);
+
+    let pairs = [
+        (rotated, Transform3D::IDENTITY),
+        (scaled, rotated),
+        (mirrored, TEST_TRANSFORM.orthonormalized()),
+        (mirrored, scaled),
+    ];
+
+    for (from, to) in pairs {
+        let inner = InnerTransform3D::from_outer(&from);
+        for weight in [0.0, 0.25, 0.5, 1.0] {
+            assert_eq_approx!(
+                inner.interpolate_with(to, weight),
+                from.interpolate_with(to, weight as real),
+                "from: {from}, to: {to}, weight: {weight}\n"
+            );
+        }
+    }
+}
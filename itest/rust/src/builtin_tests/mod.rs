@@ -31,3 +31,4 @@ mod string {
 }
 
 mod color_test;
+mod real_test;
@@ -0,0 +1,42 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::mem::size_of;
+
+use crate::framework::itest;
+use godot::builtin::{real, Basis, Quaternion, ToVariant, Vector2, Vector3, Vector4};
+use godot::engine::Os;
+
+fn is_double_precision() -> bool {
+    size_of::<real>() == size_of::<f64>()
+}
+
+#[itest]
+fn real_matches_engine_precision() {
+    let engine_double = Os::singleton().has_feature("double".into());
+
+    assert_eq!(is_double_precision(), engine_double);
+}
+
+#[itest]
+fn real_math_type_sizes() {
+    assert_eq!(size_of::<Vector2>(), 2 * size_of::<real>());
+    assert_eq!(size_of::<Vector3>(), 3 * size_of::<real>());
+    assert_eq!(size_of::<Vector4>(), 4 * size_of::<real>());
+    assert_eq!(size_of::<Quaternion>(), 4 * size_of::<real>());
+    assert_eq!(size_of::<Basis>(), 9 * size_of::<real>());
+}
+
+#[itest]
+fn real_precision_roundtrip() {
+    // 1 + 2^-30 is exactly representable in f64, but rounds to 1.0 in f32.
+    let component = 1.0 + (2.0 as real).powi(-30);
+    let vector = Vector3::new(component, 0.0, 0.0);
+
+    let roundtrip = vector.to_variant().to::<Vector3>();
+    assert_eq!(roundtrip, vector);
+    assert_eq!(roundtrip.x != 1.0, is_double_precision());
+}
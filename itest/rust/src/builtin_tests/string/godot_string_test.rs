@@ -7,7 +7,7 @@
 use std::collections::HashSet;
 
 use crate::framework::itest;
-use godot::builtin::GodotString;
+use godot::builtin::{dict, varray, GodotString};
 
 // TODO use tests from godot-rust/gdnative
 
@@ -95,3 +95,86 @@ fn string_hash() {
     .collect();
     assert_eq!(set.len(), 5);
 }
+
+#[itest]
+fn string_format() {
+    let template = GodotString::from("{name} is {age} years old, {unknown}");
+    let formatted = template.format(dict! { "name": "Alice", "age": 30 });
+    assert_eq!(formatted, "Alice is 30 years old, {unknown}".into());
+
+    let template = GodotString::from("{0} + {1} = {2}");
+    assert_eq!(template.format(varray![1, 2, 3]), "1 + 2 = 3".into());
+
+    let template = GodotString::from("$a and $b");
+    let formatted = template.format_with_placeholder(dict! { "a": 1, "b": 2 }, "$_");
+    assert_eq!(formatted, "1 and 2".into());
+}
+
+#[itest]
+fn string_similarity() {
+    let string = GodotString::from("godot");
+    assert_eq!(string.similarity(&string.clone()), 1.0);
+    assert_eq!(string.similarity(&"rust".into()), 0.0);
+
+    let partial = string.similarity(&"godette".into());
+    assert!(
+        partial > 0.0 && partial < 1.0,
+        "partial similarity {partial}"
+    );
+
+    let empty = GodotString::new();
+    assert_eq!(empty.similarity(&GodotString::new()), 1.0);
+    assert_eq!(empty.similarity(&string), 0.0);
+}
+
+#[itest]
+fn string_levenshtein_distance() {
+    let distance = |a: &str, b: &str| GodotString::from(a).levenshtein_distance(&b.into());
+
+    assert_eq!(distance("kitten", "sitting"), 3);
+    assert_eq!(distance("sitting", "kitten"), 3);
+    assert_eq!(distance("same", "same"), 0);
+    assert_eq!(distance("", "abc"), 3);
+    assert_eq!(distance("abc", ""), 3);
+    assert_eq!(distance("", ""), 0);
+
+    // Counted in Unicode scalar values, not bytes or graphemes.
+    assert_eq!(distance("ñandú", "nandu"), 2);
+    assert_eq!(distance("e\u{301}", "é"), 2);
+}
+
+#[itest]
+fn string_len_chars_and_bytes() {
+    let empty = GodotString::new();
+    assert_eq!(empty.len_chars(), 0);
+    assert_eq!(empty.len_bytes(), 0);
+
+    let ascii = GodotString::from("hello");
+    assert_eq!(ascii.len_chars(), 5);
+    assert_eq!(ascii.len_bytes(), 5);
+
+    // 2-byte, 3-byte and 4-byte UTF-8 sequences; the emoji is not split into a surrogate pair.
+    let mixed = GodotString::from("ä€🦀");
+    assert_eq!(mixed.len_chars(), 3);
+    assert_eq!(mixed.len_bytes(), 2 + 3 + 4);
+
+    // Combining characters count separately.
+    let combined = GodotString::from("e\u{0301}");
+    assert_eq!(combined.len_chars(), 2);
+    assert_eq!(combined.len_bytes(), 3);
+
+    assert_eq!(mixed.len_bytes(), mixed.to_string().len());
+    assert_eq!(mixed.len_chars(), mixed.chars_checked().len());
+}
+
+#[itest]
+fn string_case_conversion() {
+    let string = GodotString::from("Godot Ñandú straße");
+    assert_eq!(string.to_lower(), "godot ñandú straße".into());
+    assert_eq!(string.to_upper(), "GODOT ÑANDÚ STRAßE".into());
+
+    assert_eq!(string.to_ascii_lowercase(), "godot Ñandú straße".into());
+    assert_eq!(string.to_ascii_uppercase(), "GODOT ÑANDú STRAßE".into());
+
+    assert_eq!(GodotString::new().to_upper(), GodotString::new());
+}
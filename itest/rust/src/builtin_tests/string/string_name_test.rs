@@ -7,7 +7,7 @@
 use std::collections::HashSet;
 
 use crate::framework::itest;
-use godot::builtin::{GodotString, NodePath, StringName};
+use godot::builtin::{sname, GodotString, NodePath, StringName};
 
 #[itest]
 fn string_name_default() {
@@ -109,3 +109,15 @@ fn string_name_is_empty() {
     let empty = StringName::default();
     assert!(empty.is_empty());
 }
+
+#[itest]
+fn string_name_sname_macro() {
+    let names: Vec<StringName> = (0..3).map(|_| sname!("cached_name")).collect();
+    for name in &names {
+        assert_eq!(*name, StringName::from("cached_name"));
+    }
+
+    // Different call sites with the same literal yield equal names.
+    assert_eq!(sname!("cached_name"), names[0]);
+    assert_ne!(sname!("other_name"), names[0]);
+}
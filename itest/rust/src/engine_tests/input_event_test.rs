@@ -0,0 +1,79 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+
+use godot::bind::InputAction;
+use godot::engine::{
+    Input, InputAction, InputEvent, InputEventKey, InputEventKind, InputEventMouseMotion,
+    InputEventShortcut, InputExt,
+};
+use godot::obj::{Gd, Share};
+
+#[derive(InputAction, Copy, Clone)]
+enum TestAction {
+    UiAccept,
+    #[action(name = "ui_cancel")]
+    Back,
+    NotRegistered,
+}
+
+#[itest]
+fn input_event_kind_matches_subtype() {
+    let key = InputEventKey::new();
+    let event: Gd<InputEvent> = key.share().upcast();
+    match InputEventKind::from_event(event) {
+        InputEventKind::Key(matched) => assert_eq!(matched, key),
+        other => panic!("expected Key, got {other:?}"),
+    }
+
+    let motion = InputEventMouseMotion::new();
+    let kind = InputEventKind::from(motion.share().upcast::<InputEvent>());
+    assert!(matches!(kind, InputEventKind::MouseMotion(matched) if matched == motion));
+}
+
+#[itest]
+fn input_event_kind_other() {
+    let shortcut = InputEventShortcut::new();
+    let event: Gd<InputEvent> = shortcut.share().upcast();
+
+    match InputEventKind::from_event(event) {
+        InputEventKind::Other(matched) => assert_eq!(matched, shortcut.upcast()),
+        other => panic!("expected Other, got {other:?}"),
+    }
+}
+
+#[itest]
+fn input_action_names() {
+    assert_eq!(TestAction::UiAccept.action_name(), "ui_accept");
+    assert_eq!(TestAction::Back.action_name(), "ui_cancel");
+    assert_eq!(TestAction::NotRegistered.action_name(), "not_registered");
+}
+
+#[itest]
+fn input_action_pressed_typed() {
+    let mut input = Input::singleton();
+    assert!(!input.is_action_pressed_typed(TestAction::UiAccept));
+
+    input.action_press("ui_accept".into());
+    assert!(input.is_action_pressed_typed(TestAction::UiAccept));
+    assert_eq!(input.get_action_strength_typed(TestAction::UiAccept), 1.0);
+    assert!(!input.is_action_pressed_typed(TestAction::Back));
+
+    input.action_release("ui_accept".into());
+    assert!(!input.is_action_pressed_typed(TestAction::UiAccept));
+}
+
+#[itest]
+fn input_action_not_registered() {
+    let input = Input::singleton();
+    assert!(!input.is_action_pressed_typed(TestAction::NotRegistered));
+    assert!(!input.is_action_just_released_typed(TestAction::NotRegistered));
+    assert_eq!(
+        input.get_action_strength_typed(TestAction::NotRegistered),
+        0.0
+    );
+}
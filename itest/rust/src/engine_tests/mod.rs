@@ -4,6 +4,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod input_event_test;
 mod native_structures_test;
 mod node_test;
+mod task_test;
 mod utilities_test;
@@ -4,13 +4,19 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 use std::str::FromStr;
 
-use godot::builtin::{NodePath, Variant};
-use godot::engine::{global, Node, Node3D, NodeExt, PackedScene, SceneTree};
-use godot::obj::Share;
+use godot::bind::{godot_api, GodotClass};
+use godot::builtin::{GodotString, NodePath, StringName, ToVariant, Variant, Vector2, Vector3};
+use godot::engine::{
+    debug_dump, global, DebugSummary, Node, Node2D, Node3D, NodeExt, NodePathCache, PackedScene,
+    PhysicsFrame, ReplaceError, SceneTree, SceneTreeExt, TreeObserver,
+};
+use godot::obj::{Gd, Share};
 
-use crate::framework::{itest, TestContext};
+use crate::framework::{expect_panic, itest, TestContext};
 
 #[itest]
 fn node_get_node() {
@@ -49,6 +55,319 @@ fn node_get_node_fail() {
     child.free();
 }
 
+#[itest]
+fn node_find_child_typed() {
+    let mut child = Node3D::new_alloc();
+    child.set_name("child_3d".into());
+    let child_id = child.instance_id();
+
+    let mut other = Node::new_alloc();
+    other.set_name("child_plain".into());
+
+    let mut parent = Node::new_alloc();
+    parent.set_name("parent".into());
+    parent.add_child(child.share().upcast());
+    parent.add_child(other.share());
+
+    // Wildcard pattern, first match in tree order is returned
+    let found = parent.find_child_typed::<Node3D>("child_*", true, false);
+    let found = found.expect("find_child_typed() returned Some(..)");
+    assert_eq!(found.instance_id(), child_id);
+
+    // Match, but wrong type
+    let found = parent.find_child_typed::<Node3D>("child_plain", true, false);
+    assert!(found.is_none());
+
+    // Only owned nodes considered
+    let found = parent.find_child_typed::<Node3D>("child_3d", true, true);
+    assert!(found.is_none());
+
+    // No match
+    let found = parent.find_child_typed::<Node>("non-existent", true, false);
+    assert!(found.is_none());
+
+    parent.free();
+}
+
+#[itest]
+fn node_set_node_name() {
+    let mut node = Node3D::new_alloc();
+
+    node.set_node_name("Player");
+    assert_eq!(node.get_name(), "Player");
+
+    let other = StringName::from("Enemy");
+    node.set_node_name(other);
+    assert_eq!(node.get_name(), "Enemy");
+    assert_ne!(node.get_name(), "Player");
+
+    // Invalid characters are sanitized by Godot.
+    node.set_node_name("a.b/c");
+    let name = node.get_name().to_string();
+    assert!(name.starts_with('a') && name.ends_with('c'), "got {name}");
+    assert!(!name.contains('.') && !name.contains('/'), "got {name}");
+
+    node.free();
+}
+
+#[itest]
+fn node_add_child_at_and_move_below() {
+    let mut parent = Node::new_alloc();
+    let names = |parent: &Gd<Node>| -> Vec<String> {
+        let children = parent.get_children();
+        children
+            .iter_shared()
+            .map(|c| c.get_name().to_string())
+            .collect()
+    };
+
+    let make_child = |name: &str| {
+        let mut child = Node::new_alloc();
+        child.set_node_name(name);
+        child
+    };
+    let a = make_child("a");
+    let b = make_child("b");
+    let c = make_child("c");
+    let d = make_child("d");
+
+    parent.add_child_at(a.share(), 0);
+    parent.add_child_at(b.share(), 0);
+    parent.add_child_at(c.share(), 1);
+    assert_eq!(names(&parent), ["b", "c", "a"]);
+
+    // Out of range: appended.
+    parent.add_child_at(d.share(), 100);
+    assert_eq!(names(&parent), ["b", "c", "a", "d"]);
+
+    // Child before sibling.
+    parent.move_child_below(b.share(), &a);
+    assert_eq!(names(&parent), ["c", "a", "b", "d"]);
+
+    // Child after sibling.
+    parent.move_child_below(d.share(), &c);
+    assert_eq!(names(&parent), ["c", "d", "a", "b"]);
+
+    // Already below.
+    parent.move_child_below(d.share(), &c);
+    assert_eq!(names(&parent), ["c", "d", "a", "b"]);
+
+    parent.free();
+}
+
+#[itest]
+fn node_replace_in_tree() {
+    let mut parent = Node::new_alloc();
+    let mut old = Node::new_alloc();
+    old.set_node_name("old");
+    old.add_to_group("enemies".into());
+
+    let mut grandchild = Node::new_alloc();
+    grandchild.set_node_name("grandchild");
+    old.add_child(grandchild.share());
+
+    let mut first = Node::new_alloc();
+    first.set_node_name("first");
+    parent.add_child(first);
+    parent.add_child(old.share());
+    parent.add_child(Node::new_alloc());
+
+    let replacement = Node3D::new_alloc();
+    assert_eq!(old.replace_in_tree(replacement.share(), true), Ok(()));
+
+    let mut replacement = replacement.upcast::<Node>();
+    assert_eq!(replacement.get_parent(), Some(parent.share()));
+    assert_eq!(replacement.get_index(), 1);
+    assert_eq!(replacement.get_name(), "old");
+    assert!(replacement.is_in_group("enemies".into()));
+    assert_eq!(grandchild.get_parent(), Some(replacement.share()));
+    assert_eq!(old.get_parent(), None);
+    assert_eq!(old.get_child_count(), 0);
+
+    // Errors leave both nodes unchanged.
+    let orphan = Node::new_alloc();
+    assert_eq!(
+        old.replace_in_tree(orphan.share(), false),
+        Err(ReplaceError::NotInTree)
+    );
+    assert_eq!(orphan.get_parent(), None);
+
+    assert_eq!(
+        replacement.replace_in_tree(grandchild.share(), false),
+        Err(ReplaceError::ReplacementHasParent)
+    );
+    assert_eq!(replacement.get_parent(), Some(parent.share()));
+    assert_eq!(grandchild.get_parent(), Some(replacement.share()));
+
+    orphan.free();
+    old.free();
+    parent.free();
+}
+
+#[itest]
+fn node_reparent(ctx: &TestContext) {
+    let mut root = ctx.scene_tree.share();
+
+    // 2D, inside the tree.
+    let mut old_parent = Node2D::new_alloc();
+    old_parent.set_position(Vector2::new(10.0, 20.0));
+    let mut new_parent = Node2D::new_alloc();
+    new_parent.set_position(Vector2::new(-5.0, 5.0));
+    root.add_child(old_parent.share().upcast());
+    root.add_child(new_parent.share().upcast());
+
+    let mut child = Node2D::new_alloc();
+    child.set_position(Vector2::new(1.0, 2.0));
+    old_parent.add_child(child.share().upcast());
+
+    child.reparent_to(new_parent.share(), true);
+    assert_eq!(child.get_parent(), Some(new_parent.share().upcast()));
+    assert_eq!(child.get_global_position(), Vector2::new(11.0, 22.0));
+    assert_eq!(child.get_position(), Vector2::new(16.0, 17.0));
+
+    child.reparent_to(old_parent.share(), false);
+    assert_eq!(child.get_parent(), Some(old_parent.share().upcast()));
+    assert_eq!(child.get_position(), Vector2::new(16.0, 17.0));
+
+    // Same parent: no-op.
+    child.reparent_to(old_parent.share(), true);
+    assert_eq!(child.get_position(), Vector2::new(16.0, 17.0));
+
+    // The engine method is not shadowed.
+    child.reparent(new_parent.share().upcast());
+    assert_eq!(child.get_parent(), Some(new_parent.share().upcast()));
+
+    // Cycles are rejected.
+    expect_panic("reparent into descendant", || {
+        new_parent.share().reparent_to(child.share(), false)
+    });
+
+    old_parent.free();
+    new_parent.free();
+
+    // 3D, outside the tree.
+    let mut parent = Node3D::new_alloc();
+    parent.set_position(Vector3::new(1.0, 2.0, 3.0));
+    let mut child = Node3D::new_alloc();
+    child.set_position(Vector3::new(4.0, 5.0, 6.0));
+
+    // An orphan is added, keeping its local transform.
+    child.reparent_to(parent.share(), true);
+    assert_eq!(child.get_parent(), Some(parent.share().upcast()));
+    assert_eq!(child.get_position(), Vector3::new(4.0, 5.0, 6.0));
+
+    let other = Node::new_alloc();
+    expect_panic("keep global transform outside tree", || {
+        child.share().reparent_to(other.share(), true)
+    });
+
+    child.reparent_to(other.share(), false);
+    assert_eq!(child.get_parent(), Some(other.share()));
+    assert_eq!(child.get_position(), Vector3::new(4.0, 5.0, 6.0));
+
+    // Plain nodes have no transform: the flag is ignored, even outside the tree.
+    let mut plain = Node::new_alloc();
+    parent.add_child(plain.share());
+    plain.reparent_to(other.share(), true);
+    assert_eq!(plain.get_parent(), Some(other.share()));
+
+    other.free();
+    parent.free();
+}
+
+#[itest]
+fn node_path_to_and_cache() {
+    let mut root = Node::new_alloc();
+    let mut branch = Node::new_alloc();
+    branch.set_node_name("branch");
+    let mut leaf = Node3D::new_alloc();
+    leaf.set_node_name("leaf");
+    let mut other = Node::new_alloc();
+    other.set_node_name("other");
+
+    branch.add_child(leaf.share().upcast());
+    root.add_child(branch.share());
+    root.add_child(other.share());
+
+    assert_eq!(other.path_to(&leaf).to_string(), "../branch/leaf");
+    assert_eq!(leaf.path_to(&other).to_string(), "../../other");
+    assert_eq!(root.path_to(&root).to_string(), ".");
+
+    let mut cache = NodePathCache::new();
+    assert_eq!(cache.path_to(&other, &leaf).to_string(), "../branch/leaf");
+    assert_eq!(cache.len(), 1);
+
+    // Cached paths are not updated automatically.
+    leaf.set_node_name("renamed");
+    assert_eq!(cache.path_to(&other, &leaf).to_string(), "../branch/leaf");
+
+    cache.invalidate(leaf.instance_id());
+    assert!(cache.is_empty());
+    assert_eq!(
+        cache.path_to(&other, &leaf).to_string(),
+        "../branch/renamed"
+    );
+
+    // User-defined nodes work as start point as well.
+    let user_node = Gd::<DumpedNode>::new_default();
+    branch.add_child(user_node.share().upcast());
+    assert_eq!(cache.path_to(&user_node, &other).to_string(), "../../other");
+
+    root.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct DumpedNode {
+    hp: i64,
+}
+
+#[godot_api]
+impl DumpedNode {}
+
+impl DebugSummary for DumpedNode {
+    fn debug_summary(&self) -> String {
+        format!("hp={}", self.hp)
+    }
+}
+
+godot::obj::register_dyn!(DumpedNode: dyn DebugSummary);
+
+#[itest]
+fn node_debug_dump() {
+    let mut root = Node::new_alloc();
+    root.set_node_name("root");
+
+    let mut player = Gd::<DumpedNode>::new_default();
+    player.bind_mut().hp = 42;
+    let mut player = player.upcast::<Node>();
+    player.set_node_name("player");
+
+    let mut weapon = Node3D::new_alloc();
+    weapon.set_node_name("weapon");
+    let mut deep = Node::new_alloc();
+    deep.set_node_name("deep");
+    weapon.add_child(deep);
+
+    player.add_child(weapon.upcast());
+    root.add_child(player);
+
+    assert_eq!(
+        debug_dump(&root, 8).to_string(),
+        "root (Node)\n  player (DumpedNode): hp=42\n    weapon (Node3D)\n      deep (Node)"
+    );
+    assert_eq!(
+        debug_dump(&root, 1).to_string(),
+        "root (Node)\n  player (DumpedNode): hp=42\n    ... (1 children omitted)"
+    );
+    assert_eq!(
+        debug_dump(&root, 0).to_string(),
+        "root (Node)\n  ... (1 children omitted)"
+    );
+
+    root.free();
+}
+
 #[itest]
 fn node_path_from_str(ctx: &TestContext) {
     let child = ctx.scene_tree.share();
@@ -90,3 +409,255 @@ fn node_call_group(ctx: &TestContext) {
     node.add_to_group("group".into());
     tree.call_group("group".into(), "set_name".into(), &[Variant::from("name")]);
 }
+
+#[itest]
+fn node_tree_observer(ctx: &TestContext) {
+    let mut root = ctx.scene_tree.share();
+    let tree = root.get_tree().unwrap();
+    let added = Rc::new(RefCell::new(Vec::new()));
+    let removed = Rc::new(RefCell::new(Vec::new()));
+
+    let mut child = Node::new_alloc();
+    child.set_name("observed".into());
+
+    let observer = {
+        let added = added.clone();
+        let removed = removed.clone();
+        TreeObserver::new(
+            tree,
+            move |node| added.borrow_mut().push(node.get_name().to_string()),
+            move |node| removed.borrow_mut().push(node.get_name().to_string()),
+        )
+    };
+    root.add_child(child.share());
+    root.remove_child(child.share());
+    drop(observer);
+
+    // Detached on drop, and the closures are dropped as well.
+    assert_eq!(Rc::strong_count(&added), 1);
+    root.add_child(child.share());
+    root.remove_child(child.share());
+
+    assert_eq!(*added.borrow(), vec!["observed".to_string()]);
+    assert_eq!(*removed.borrow(), vec!["observed".to_string()]);
+
+    child.free();
+}
+
+#[itest]
+fn node_on_tree_exit(ctx: &TestContext) {
+    let mut root = ctx.scene_tree.share();
+    let exits = Rc::new(Cell::new(0));
+    let on_exit = || {
+        let exits = exits.clone();
+        move || exits.set(exits.get() + 1)
+    };
+
+    let child = Node::new_alloc();
+    root.add_child(child.share());
+
+    let exiting = child.on_tree_exiting(on_exit());
+    let exited = child.on_tree_exited(on_exit());
+    assert!(exiting.is_connected());
+    assert!(exited.is_connected());
+
+    root.remove_child(child.share());
+    assert_eq!(exits.get(), 2);
+
+    // One-shot connections: leaving the tree again does not call the closure.
+    assert!(!exiting.is_connected());
+    root.add_child(child.share());
+    root.remove_child(child.share());
+    assert_eq!(exits.get(), 2);
+    drop(exiting);
+    drop(exited);
+
+    // Dropping the guard disconnects, and drops the closure.
+    root.add_child(child.share());
+    let guard = child.on_tree_exiting(on_exit());
+    drop(guard);
+    root.remove_child(child.share());
+    assert_eq!(exits.get(), 2);
+    assert_eq!(Rc::strong_count(&exits), 1);
+
+    // Outside of the tree, the closure runs once the node has been added and leaves again.
+    let guard = child.on_tree_exited(on_exit());
+    assert!(guard.is_connected());
+    assert_eq!(exits.get(), 2);
+    root.add_child(child.share());
+    root.remove_child(child.share());
+    assert_eq!(exits.get(), 3);
+    drop(guard);
+
+    child.free();
+}
+
+#[itest]
+fn node_call_group_typed(ctx: &TestContext) {
+    let mut root = ctx.scene_tree.share();
+    let mut tree = root.get_tree().unwrap();
+
+    let mut plain = Node::new_alloc();
+    let mut spatial = Node3D::new_alloc();
+    plain.add_to_group("typed_group".into());
+    spatial.add_to_group("typed_group".into());
+    root.add_child(plain.share());
+    root.add_child(spatial.share().upcast());
+
+    // Only the Node3D is called.
+    let count =
+        tree.call_group_typed::<Node3D>("typed_group", "set_visible", &[false.to_variant()]);
+    assert_eq!(count, 1);
+    assert!(!spatial.is_visible());
+
+    // Nodes without the method are skipped.
+    let count = tree.call_group_typed::<Node>("typed_group", "hide", &[]);
+    assert_eq!(count, 1);
+
+    let count = tree.call_group_typed::<Node>("typed_group", "set_process", &[true.to_variant()]);
+    assert_eq!(count, 2);
+
+    let count = tree.call_group_typed::<Node>("no_such_group", "set_process", &[true.to_variant()]);
+    assert_eq!(count, 0);
+
+    plain.free();
+    spatial.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct GroupFreer {
+    victim: Option<Gd<Node>>,
+}
+
+#[godot_api]
+impl GroupFreer {
+    #[func]
+    fn free_victim(&mut self) {
+        if let Some(victim) = self.victim.take() {
+            victim.free();
+        }
+    }
+}
+
+#[itest]
+fn node_call_group_typed_freed_during_call(ctx: &TestContext) {
+    let mut root = ctx.scene_tree.share();
+    let mut tree = root.get_tree().unwrap();
+
+    let mut first = Gd::<GroupFreer>::new_default();
+    let mut second = Gd::<GroupFreer>::new_default();
+    first.bind_mut().victim = Some(second.share().upcast());
+    first.add_to_group("freeing_group".into());
+    second.add_to_group("freeing_group".into());
+
+    // Group members are called in tree order, so the first node frees the second before it is reached.
+    root.add_child(first.share().upcast());
+    root.add_child(second.share().upcast());
+
+    let count = tree.call_group_typed::<GroupFreer>("freeing_group", "free_victim", &[]);
+    assert_eq!(count, 1);
+    assert!(!second.is_instance_valid());
+
+    first.free();
+}
+
+#[itest]
+fn node_physics_frame_outside_physics() {
+    // Tests run during idle processing, not in a physics frame.
+    assert_eq!(PhysicsFrame::from_delta(1.0 / 60.0), None);
+
+    // Later calls use the tracked state instead of querying the engine; it must agree.
+    assert_eq!(PhysicsFrame::from_delta(1.0 / 60.0), None);
+}
+
+#[itest]
+fn node_process_toggles() {
+    // User-defined node: methods are available without upcast.
+    let mut node = Gd::<DumpedNode>::new_default();
+    assert!(!node.is_processing());
+    assert!(!node.is_physics_processing());
+
+    node.set_process(true);
+    node.set_physics_process(true);
+    assert!(node.is_processing());
+    assert!(node.is_physics_processing());
+
+    node.set_process(false);
+    assert!(!node.is_processing());
+    assert!(node.is_physics_processing());
+
+    // Engine node.
+    let mut engine_node = node.upcast::<Node>();
+    engine_node.set_physics_process(false);
+    assert!(!engine_node.is_physics_processing());
+
+    engine_node.free();
+}
+
+#[itest]
+fn node_sibling_navigation() {
+    let mut parent = Node::new_alloc();
+    let first = Node::new_alloc();
+    let second = Node3D::new_alloc();
+    let third = Node::new_alloc();
+    parent.add_child(first.share());
+    parent.add_child(second.share().upcast());
+    parent.add_child(third.share());
+
+    assert_eq!(first.sibling_index(), Some(0));
+    assert_eq!(second.sibling_index(), Some(1));
+    assert_eq!(third.sibling_index(), Some(2));
+
+    assert_eq!(second.next_sibling(), Some(third.share()));
+    assert_eq!(second.prev_sibling(), Some(first.share()));
+    assert_eq!(first.prev_sibling(), None);
+    assert_eq!(third.next_sibling(), None);
+
+    // Without a parent, there are no siblings.
+    assert_eq!(parent.sibling_index(), None);
+    assert_eq!(parent.next_sibling(), None);
+    assert_eq!(parent.prev_sibling(), None);
+
+    parent.free();
+}
+
+#[itest]
+fn node_draw_callbacks() {
+    let mut node = Node2D::new_alloc();
+    let draw_count = Rc::new(Cell::new(0));
+
+    let counter = draw_count.clone();
+    let node_id = node.instance_id();
+    node.add_draw_callback(move |canvas_item| {
+        // Draws onto the node itself.
+        assert_eq!(canvas_item.instance_id(), node_id);
+        counter.set(counter.get() + 1)
+    });
+
+    // The callback is held by an internal child, which does not show up among the regular children.
+    assert_eq!(node.get_child_count(), 0);
+    assert_eq!(node.get_child_count_ex().include_internal(true).done(), 1);
+
+    let relay = node
+        .get_child_ex(0)
+        .include_internal(true)
+        .done()
+        .expect("relay child");
+    assert_eq!(relay.get_class(), GodotString::from("GdextClosureRelay"));
+
+    // The node is not in the tree, so trigger the draw phase manually.
+    node.emit_signal("draw".into(), &[]);
+    assert_eq!(draw_count.get(), 1);
+
+    node.add_draw_callback(|_canvas_item| {});
+    assert_eq!(node.get_child_count_ex().include_internal(true).done(), 2);
+
+    // Disconnected immediately, freed at the end of the frame.
+    node.clear_draw_callbacks();
+    assert!(relay.is_queued_for_deletion());
+    node.emit_signal("draw".into(), &[]);
+    assert_eq!(draw_count.get(), 1);
+
+    node.free();
+}
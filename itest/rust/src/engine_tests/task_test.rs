@@ -0,0 +1,324 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+
+use godot::bind::{godot_api, GodotClass};
+use godot::builtin::{dict, varray, ToVariant, Variant};
+use godot::engine::{Object, PackedScene, RefCounted};
+use godot::obj::{Gd, GodotClass as _, Share};
+use godot::task;
+
+use crate::framework::itest;
+
+#[itest]
+fn task_spawn_ready_future() {
+    let ran = Rc::new(Cell::new(false));
+    let ran_in_task = ran.clone();
+
+    let handle = task::spawn(async move {
+        ran_in_task.set(true);
+    });
+
+    assert!(ran.get(), "synchronous part runs immediately");
+    assert!(handle.is_finished());
+    assert!(!handle.is_pending());
+}
+
+#[itest]
+fn task_next_frame_pending_within_frame() {
+    let resumed = Rc::new(Cell::new(false));
+    let resumed_in_task = resumed.clone();
+
+    let handle = task::spawn(async move {
+        task::next_process_frame()
+            .await
+            .expect("itest runs inside a SceneTree");
+        resumed_in_task.set(true);
+    });
+
+    // Tests run within a single frame, so the frame counter cannot advance here.
+    task::poll_tasks();
+    assert!(handle.is_pending());
+    assert!(!resumed.get());
+
+    handle.clone().cancel();
+    assert!(!handle.is_pending());
+    assert!(!handle.is_finished());
+
+    task::poll_tasks();
+    assert!(!resumed.get(), "cancelled task is not resumed");
+}
+
+#[itest]
+fn task_timeout() {
+    // Zero duration: already elapsed.
+    let handle = task::spawn(async {
+        task::timeout(0.0)
+            .await
+            .expect("itest runs inside a SceneTree");
+    });
+    assert!(handle.is_finished());
+
+    // Tests run within a single frame, so the timer cannot fire here.
+    let handle = task::spawn(async {
+        task::timeout(10.0)
+            .await
+            .expect("itest runs inside a SceneTree");
+    });
+    task::poll_tasks();
+    assert!(handle.is_pending());
+
+    handle.cancel();
+}
+
+#[itest]
+fn task_await_signal() {
+    let mut obj = Object::new_alloc();
+    obj.add_user_signal_ex("fired".into())
+        .arguments(varray![dict! {"name": "a"}, dict! {"name": "b"}])
+        .done();
+
+    let received = Rc::new(RefCell::new(None));
+    let received_in_task = received.clone();
+
+    let future = obj.await_signal("fired");
+    let handle = task::spawn(async move {
+        let args = future.await.expect("object is alive");
+        *received_in_task.borrow_mut() = Some(args);
+    });
+
+    task::poll_tasks();
+    assert!(handle.is_pending());
+
+    obj.emit_signal("fired".into(), &[1.to_variant(), "x".to_variant()]);
+    task::poll_tasks();
+    assert!(handle.is_finished());
+    assert_eq!(
+        received.borrow_mut().take(),
+        Some(vec![1.to_variant(), "x".to_variant()])
+    );
+
+    // Unknown signals are detected when the future is created.
+    assert!(obj.try_await_signal("no_such_signal").is_none());
+
+    // Freeing the object resolves the future with an error.
+    let future = obj.await_signal("fired");
+    let handle = task::spawn(async move {
+        assert_eq!(future.await, Err(task::TaskError::ObjectFreed));
+    });
+    obj.free();
+    task::poll_tasks();
+    assert!(handle.is_finished());
+}
+
+#[itest]
+fn task_await_signal_many_args() {
+    let mut obj = Object::new_alloc();
+    obj.add_user_signal("fired".into());
+
+    let args: Vec<Variant> = (0..10).map(|i| i.to_variant()).collect();
+    let received = Rc::new(RefCell::new(None));
+    let received_in_task = received.clone();
+
+    let future = obj.await_signal("fired");
+    let handle = task::spawn(async move {
+        *received_in_task.borrow_mut() = Some(future.await.expect("object is alive"));
+    });
+
+    // User signals without declared arguments accept any number of them; all are passed on.
+    obj.emit_signal("fired".into(), &args);
+    task::poll_tasks();
+    assert!(handle.is_finished());
+    assert_eq!(received.borrow_mut().take(), Some(args));
+
+    obj.free();
+}
+
+#[itest]
+fn task_load_async() {
+    let loaded = Rc::new(RefCell::new(None));
+    let loaded_in_task = loaded.clone();
+
+    let handle = task::spawn(async move {
+        let scene = task::load_async::<PackedScene>("res://TestRunner.tscn").await;
+        *loaded_in_task.borrow_mut() = Some(scene);
+    });
+    poll_until_finished(&handle);
+
+    let scene = loaded
+        .borrow_mut()
+        .take()
+        .unwrap()
+        .expect("scene is loaded");
+    assert!(scene.can_instantiate());
+}
+
+#[itest]
+fn task_load_async_errors() {
+    let result = Rc::new(RefCell::new(None));
+    let result_in_task = result.clone();
+
+    // The script of the test runner is already loaded, so it is taken from the cache regardless of the type.
+    let handle = task::spawn(async move {
+        let scene = task::load_async::<PackedScene>("res://TestRunner.gd").await;
+        *result_in_task.borrow_mut() = Some(scene.map(|_| ()));
+    });
+    poll_until_finished(&handle);
+
+    let err = result.borrow_mut().take().unwrap().unwrap_err();
+    assert_eq!(
+        err,
+        task::LoadError::WrongType {
+            path: "res://TestRunner.gd".into(),
+            expected: PackedScene::class_name(),
+            actual: "GDScript".into(),
+        }
+    );
+
+    let result_in_task = result.clone();
+    let handle = task::spawn(async move {
+        let missing = task::load_async::<PackedScene>("res://NonExisting.tscn").await;
+        *result_in_task.borrow_mut() = Some(missing.map(|_| ()));
+    });
+    poll_until_finished(&handle);
+
+    let err = result.borrow_mut().take().unwrap().unwrap_err();
+    assert_eq!(
+        err,
+        task::LoadError::Failed {
+            path: "res://NonExisting.tscn".into()
+        }
+    );
+}
+
+#[itest]
+fn task_load_async_dropped() {
+    // The request is released in the background; loading the same path again must still work.
+    let future = task::load_async::<PackedScene>("res://TestRunner.tscn");
+    drop(future);
+
+    let handle = task::spawn(async {
+        task::load_async::<PackedScene>("res://TestRunner.tscn")
+            .await
+            .expect("scene is loaded");
+    });
+    poll_until_finished(&handle);
+}
+
+/// Polls tasks until `handle` has finished, waiting for background work such as threaded loading.
+fn poll_until_finished(handle: &task::TaskHandle) {
+    for _ in 0..500 {
+        task::poll_tasks();
+        if !handle.is_pending() {
+            assert!(handle.is_finished());
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    panic!("task did not finish within 5 seconds");
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+thread_local! {
+    static ASYNC_FUNC_DROPPED: Cell<bool> = Cell::new(false);
+}
+
+/// Sets `ASYNC_FUNC_DROPPED` when the future holding it is dropped.
+struct DropFlag;
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        ASYNC_FUNC_DROPPED.with(|dropped| dropped.set(true));
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct AsyncFuncTest {
+    started: bool,
+    resumed: bool,
+}
+
+#[godot_api]
+impl AsyncFuncTest {
+    #[func(gd_self)]
+    async fn wait_frame(mut this: Gd<Self>) {
+        let _flag = DropFlag;
+        this.bind_mut().started = true;
+
+        if task::next_process_frame().await.is_ok() {
+            this.bind_mut().resumed = true;
+        }
+    }
+}
+
+#[itest]
+fn task_async_func_cancelled_on_free() {
+    ASYNC_FUNC_DROPPED.with(|dropped| dropped.set(false));
+
+    let obj = Gd::<AsyncFuncTest>::new_default();
+    let result = obj
+        .share()
+        .upcast::<Object>()
+        .call("wait_frame".into(), &[]);
+    assert_eq!(result, Variant::nil(), "async func returns immediately");
+
+    {
+        let guard = obj.bind();
+        assert!(guard.started, "synchronous part runs within the call");
+        assert!(!guard.resumed);
+    }
+    assert!(!ASYNC_FUNC_DROPPED.with(Cell::get));
+
+    obj.free();
+    task::poll_tasks();
+    assert!(
+        ASYNC_FUNC_DROPPED.with(Cell::get),
+        "task is dropped once its object is freed"
+    );
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct AsyncKeepAliveTest;
+
+#[godot_api]
+impl AsyncKeepAliveTest {
+    #[func(gd_self)]
+    async fn wait_for(_this: Gd<Self>, trigger: Gd<Object>) {
+        let _ = trigger.await_signal("fired").await;
+    }
+}
+
+#[itest]
+fn task_async_func_keeps_refcounted_alive() {
+    let mut trigger = Object::new_alloc();
+    trigger.add_user_signal("fired".into());
+
+    let obj = Gd::<AsyncKeepAliveTest>::new_default();
+    let id = obj.instance_id();
+    obj.share()
+        .upcast::<RefCounted>()
+        .call("wait_for".into(), &[trigger.to_variant()]);
+    drop(obj);
+
+    // The pending future holds `this`, so the object outlives all other references.
+    assert!(Gd::<AsyncKeepAliveTest>::try_from_instance_id(id).is_some());
+
+    trigger.emit_signal("fired".into(), &[]);
+    task::poll_tasks();
+    assert!(
+        Gd::<AsyncKeepAliveTest>::try_from_instance_id(id).is_none(),
+        "object is released once the future completes"
+    );
+
+    trigger.free();
+}
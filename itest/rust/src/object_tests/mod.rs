@@ -6,6 +6,7 @@
 
 mod base_test;
 mod object_test;
+mod onready_test;
 mod property_test;
 mod singleton_test;
 mod virtual_methods_test;
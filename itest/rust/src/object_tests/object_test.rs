@@ -5,6 +5,7 @@
  */
 
 use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 use std::rc::Rc;
 
 use godot::bind::{godot_api, GodotClass};
@@ -12,9 +13,11 @@ use godot::builtin::{
     FromVariant, GodotString, StringName, ToVariant, Variant, VariantConversionError, Vector3,
 };
 use godot::engine::{
-    file_access, Area2D, Camera3D, FileAccess, Node, Node3D, Object, RefCounted, RefCountedVirtual,
+    file_access, Area2D, Camera3D, ClassDb, FileAccess, Node, Node3D, NodeVirtual, Object,
+    ObjectExt, RefCounted, RefCountedVirtual, Resource, Script,
 };
-use godot::obj::{Base, Gd, InstanceId};
+use godot::obj::cap::WithBaseField;
+use godot::obj::{Base, Gd, GodotClass as _, InstanceId};
 use godot::obj::{Inherits, Share};
 use godot::sys::{self, GodotFfi};
 
@@ -100,6 +103,46 @@ fn object_user_roundtrip_write() {
     assert_eq!(obj2.bind().value, value);
 } // drop
 
+#[itest]
+fn object_user_with_bind() {
+    let mut obj: Gd<ObjPayload> = Gd::new(ObjPayload { value: 10 });
+
+    let doubled = obj.with_bind_mut(|payload| {
+        payload.value *= 2;
+        payload.value
+    });
+    assert_eq!(doubled, 20);
+
+    // Guard released after the closure, so exclusive borrows are possible again.
+    let value = obj.with_bind(|payload| payload.value);
+    assert_eq!(value, 20);
+    assert_eq!(obj.bind_mut().value, 20);
+}
+
+#[itest]
+fn object_user_bind_sync() {
+    let mut obj: Gd<ObjPayload> = Gd::new(ObjPayload { value: 21 });
+
+    {
+        let guard = obj.bind_sync();
+        let doubled = std::thread::scope(|s| s.spawn(|| guard.value * 2).join().unwrap());
+        assert_eq!(doubled, 42);
+
+        let value = obj.bind_sync_map(|payload| &payload.value);
+        let read = std::thread::scope(|s| s.spawn(|| *value).join().unwrap());
+        assert_eq!(read, 21);
+
+        // No mutation while the instance is shared.
+        let mut obj2 = obj.share();
+        expect_panic("bind_mut() while bind_sync() guard is alive", move || {
+            obj2.bind_mut();
+        });
+    }
+
+    // Guards released.
+    assert_eq!(obj.bind_mut().value, 21);
+}
+
 #[itest]
 fn object_engine_roundtrip() {
     let pos = Vector3::new(1.0, 2.0, 3.0);
@@ -266,6 +309,25 @@ fn object_dead_eq() {
     a.free();
 }
 
+#[itest]
+fn object_hash_map_key() {
+    let a = Node3D::new_alloc();
+    let b = Node3D::new_alloc();
+
+    let mut map = HashMap::new();
+    map.insert(a.share(), 1);
+    map.insert(b.share(), 2);
+    map.insert(a.share(), 3);
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&a), Some(&3));
+    assert_eq!(map.get(&b), Some(&2));
+
+    map.clear();
+    a.free();
+    b.free();
+}
+
 #[itest]
 fn object_user_convert_variant() {
     let value: i16 = 17943;
@@ -330,6 +392,40 @@ fn check_convert_variant_refcount(obj: Gd<RefCounted>) {
     assert_eq!(obj.get_reference_count(), 1);
 }
 
+#[itest]
+fn object_ref_count() {
+    let user: Gd<ObjPayload> = Gd::new(ObjPayload { value: 1 });
+    assert_eq!(user.ref_count(), Some(1));
+
+    let shared = user.share();
+    assert_eq!(user.ref_count(), Some(2));
+    drop(shared);
+    assert_eq!(user.ref_count(), Some(1));
+
+    let node = Node3D::new_alloc();
+    assert_eq!(node.ref_count(), None);
+    node.free();
+}
+
+// Output is printed to the console; this test only ensures that tracing does not interfere with reference counting.
+#[cfg(feature = "gd-drop-trace")]
+#[itest]
+fn object_drop_trace() {
+    let user: Gd<ObjPayload> = Gd::new(ObjPayload { value: 1 });
+    let shared = user.share();
+    drop(shared);
+    assert_eq!(user.ref_count(), Some(1));
+
+    let id = user.instance_id();
+    drop(user);
+    assert!(Gd::<ObjPayload>::try_from_instance_id(id).is_none());
+
+    // Manually managed objects are not traced.
+    let node = Node3D::new_alloc();
+    drop(node.share());
+    node.free();
+}
+
 #[itest]
 fn object_engine_convert_variant_nil() {
     let nil = Variant::nil();
@@ -472,6 +568,77 @@ fn object_engine_bad_downcast() {
     free_ref.free();
 }
 
+#[itest]
+fn object_engine_downcast_result() {
+    let object: Gd<Object> = Object::new_alloc();
+    let id = object.instance_id();
+
+    // Failed cast returns the original object, which can still be freed.
+    let object: Gd<Object> = match object.downcast::<Node3D>() {
+        Ok(_) => panic!("Object must not be castable to Node3D"),
+        Err(original) => original,
+    };
+    assert_eq!(object.instance_id(), id);
+    object.free();
+
+    let node: Gd<Node> = Node3D::new_alloc().upcast();
+    let node3d: Gd<Node3D> = node.downcast::<Node3D>().expect("downcast");
+    node3d.free();
+}
+
+#[itest]
+fn object_engine_downcast_verbose() {
+    let node: Gd<Node> = Node3D::new_alloc().upcast();
+    let free_ref = node.share();
+
+    let err = node
+        .try_cast_verbose::<Camera3D>()
+        .expect_err("Node3D must not be castable to Camera3D");
+    assert_eq!(err.from_class(), Node::class_name());
+    assert_eq!(err.to_class(), Camera3D::class_name());
+    assert_eq!(err.actual_class(), Some(&GodotString::from("Node3D")));
+    assert_eq!(
+        err.to_string(),
+        "downcast from Node failed: expected Camera3D, got Node3D"
+    );
+
+    let dead_ref = free_ref.share();
+    let node3d: Gd<Node3D> = free_ref.try_cast_verbose::<Node3D>().expect("downcast");
+    node3d.free();
+
+    let err = dead_ref
+        .try_cast_verbose::<Node3D>()
+        .expect_err("freed object must not be castable");
+    assert_eq!(err.actual_class(), None);
+}
+
+#[itest]
+fn object_resource_duplicate_with_flags() {
+    let mut original = Gd::<NestedResource>::new_default();
+    original.bind_mut().value = 7;
+    original.bind_mut().inner = Some(Resource::new());
+
+    let shallow: Gd<NestedResource> = original.duplicate_with_flags(false);
+    let deep: Gd<NestedResource> = original.duplicate_with_flags(true);
+
+    assert_ne!(shallow, original);
+    assert_eq!(shallow.bind().value, 7);
+    assert_eq!(deep.bind().value, 7);
+
+    let original_inner = original.bind().inner.as_ref().map(Gd::share);
+    assert_eq!(
+        shallow.bind().inner,
+        original_inner,
+        "shallow copy shares subresource"
+    );
+    assert!(deep.bind().inner.is_some());
+    assert_ne!(
+        deep.bind().inner,
+        original_inner,
+        "deep copy duplicates subresource"
+    );
+}
+
 #[itest]
 fn object_engine_accept_polymorphic() {
     let mut node = Camera3D::new_alloc();
@@ -549,6 +716,45 @@ fn object_user_downcast() {
     assert_eq!(concrete.bind().value, 17943);
 }
 
+#[itest]
+fn object_user_into_from_base() {
+    let obj = user_object();
+    let id = obj.instance_id();
+
+    let base: Gd<RefCounted> = obj.into_base();
+    assert_eq!(base.instance_id(), id);
+
+    let obj = Gd::<ObjPayload>::from_base(base).expect("from_base");
+    assert_eq!(obj.instance_id(), id);
+    assert_eq!(obj.bind().value, 17943);
+
+    // Pure engine object of the base class: there is no Rust instance.
+    assert!(Gd::<ObjPayload>::from_base(RefCounted::new()).is_none());
+
+    // Another user class with the same base.
+    let other = Gd::new(CustomClassA {}).into_base();
+    assert!(Gd::<CustomClassB>::from_base(other).is_none());
+}
+
+#[itest]
+fn object_is_class_named() {
+    let obj = user_object();
+    assert!(obj.is_class_named("ObjPayload"));
+    assert!(obj.is_class_named("RefCounted"));
+    assert!(obj.is_class_named("Object"));
+    assert!(!obj.is_class_named("Node"));
+
+    // Dynamic class is preserved through upcasts.
+    let object = obj.upcast::<Object>();
+    assert!(object.is_class_named("ObjPayload"));
+    assert_eq!(object.get_class(), GodotString::from("ObjPayload"));
+
+    let node = Node3D::new_alloc();
+    assert!(node.is_class_named("Node"));
+    assert!(!node.is_class_named("Node2D"));
+    node.free();
+}
+
 #[itest]
 fn object_user_bad_downcast() {
     let obj = user_object();
@@ -783,6 +989,82 @@ fn custom_constructor_works() {
     obj.free();
 }
 
+#[derive(GodotClass)]
+#[class(no_init, base=Object)]
+struct NotInstantiable {
+    val: i64,
+}
+
+#[godot_api]
+impl NotInstantiable {}
+
+#[itest]
+fn no_init_class_not_instantiable() {
+    let can_instantiate = ClassDb::singleton().can_instantiate("NotInstantiable".into());
+    assert!(!can_instantiate);
+
+    // Construction from Rust is still possible.
+    let obj = Gd::with_base(|_base| NotInstantiable { val: 7 });
+    assert_eq!(obj.bind().val, 7);
+    obj.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, hidden, base=Object)]
+struct HiddenClass {
+    #[init(default = 5)]
+    val: i64,
+}
+
+#[godot_api]
+impl HiddenClass {}
+
+#[itest]
+fn hidden_class_still_instantiable() {
+    let mut class_db = ClassDb::singleton();
+    assert!(class_db.class_exists("HiddenClass".into()));
+    assert!(class_db.can_instantiate("HiddenClass".into()));
+
+    let obj = class_db
+        .instantiate("HiddenClass".into())
+        .to::<Gd<HiddenClass>>();
+    assert_eq!(obj.bind().val, 5);
+    obj.free();
+}
+
+#[derive(GodotClass)]
+#[class(tool, split_init, base=Object)]
+struct SplitInit {
+    context: &'static str,
+}
+
+#[godot_api]
+impl SplitInit {}
+
+impl SplitInit {
+    fn init_editor(_base: Base<Object>) -> Self {
+        Self { context: "editor" }
+    }
+
+    fn init_runtime(_base: Base<Object>) -> Self {
+        Self { context: "runtime" }
+    }
+}
+
+#[itest]
+fn split_init_selects_runtime() {
+    // Integration tests do not run in the editor.
+    let obj = Gd::<SplitInit>::new_default();
+    assert_eq!(obj.bind().context, "runtime");
+    obj.free();
+
+    let obj = ClassDb::singleton()
+        .instantiate("SplitInit".into())
+        .to::<Gd<SplitInit>>();
+    assert_eq!(obj.bind().context, "runtime");
+    obj.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[derive(GodotClass)]
@@ -840,3 +1122,163 @@ fn double_use_reference() {
     double_use.free();
     emitter.free();
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(base=Node)]
+struct BaseAccessor {
+    #[base]
+    node: Base<Node>,
+    name_in_init: StringName,
+}
+
+#[godot_api]
+impl NodeVirtual for BaseAccessor {
+    fn init(node: Base<Node>) -> Self {
+        let mut this = Self {
+            node,
+            name_in_init: StringName::default(),
+        };
+
+        // Base is accessible as soon as the struct exists, independently of the field name.
+        this.base_mut().set_name("Initial".into());
+        this.name_in_init = this.base().get_name();
+        this
+    }
+}
+
+#[itest]
+fn object_user_base_accessor() {
+    let mut obj = Gd::<BaseAccessor>::new_default();
+    assert_eq!(obj.bind().name_in_init, StringName::from("Initial"));
+
+    obj.bind_mut().base_mut().set_name("Renamed".into());
+    assert_eq!(obj.bind().base().get_name(), StringName::from("Renamed"));
+    assert_eq!(obj.bind().base().instance_id(), obj.instance_id());
+
+    obj.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct PanicOnCall;
+
+#[godot_api]
+impl PanicOnCall {
+    #[func]
+    fn answer(&self, offset: i64) -> i64 {
+        42 + offset
+    }
+
+    #[func]
+    fn do_panic(&self) {
+        panic!("intentional panic in #[func]");
+    }
+}
+
+#[itest]
+fn object_try_set_script() {
+    let mut script = ClassDb::singleton()
+        .instantiate("GDScript".into())
+        .to::<Gd<Script>>();
+    script.set_source_code("extends Node3D\nfunc greet():\n\treturn 'hi'\n".into());
+    script.reload();
+
+    // Script extends Node3D, so it cannot be attached to a plain Node.
+    let mut node = Node::new_alloc();
+    let err = node
+        .try_set_script(script.share())
+        .expect_err("incompatible base class");
+    assert_eq!(err.script_base_type(), "Node3D");
+    assert_eq!(err.object_class(), "Node");
+    assert!(node.script().is_none());
+    node.free();
+
+    let mut node = Node3D::new_alloc();
+    node.try_set_script(script.share())
+        .expect("compatible base class");
+    assert_eq!(node.script(), Some(script));
+    assert_eq!(node.call("greet".into(), &[]), "hi".to_variant());
+
+    node.remove_script();
+    assert!(node.script().is_none());
+    node.free();
+}
+
+#[itest]
+fn object_call_safe() {
+    let mut obj = Gd::<PanicOnCall>::new_default().upcast::<RefCounted>();
+
+    let result = obj.call_safe("answer", &[1.to_variant()]);
+    assert_eq!(result, Ok(43.to_variant()));
+
+    let err = obj
+        .call_safe("do_panic", &[])
+        .expect_err("panicking callee must return Err");
+    assert_eq!(err.method_name(), "do_panic");
+
+    assert!(obj.call_safe("no_such_method", &[]).is_err());
+
+    // Object is still usable afterwards.
+    assert_eq!(
+        obj.call_safe("answer", &[0.to_variant()]),
+        Ok(42.to_variant())
+    );
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=Resource)]
+struct NestedResource {
+    #[export]
+    value: i64,
+
+    #[export]
+    inner: Option<Gd<Resource>>,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+trait Damageable {
+    fn health(&self) -> i32;
+    fn take_damage(&mut self, amount: i32);
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct DamageableCrate {
+    #[init(default = 10)]
+    health: i32,
+}
+
+impl Damageable for DamageableCrate {
+    fn health(&self) -> i32 {
+        self.health
+    }
+
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+}
+
+godot::obj::register_dyn!(DamageableCrate: dyn Damageable);
+
+#[itest]
+fn object_user_dyn_trait() {
+    let mut object: Gd<RefCounted> = Gd::<DamageableCrate>::new_default().upcast();
+
+    let remaining = object.with_dyn_mut(|d: &mut dyn Damageable| {
+        d.take_damage(3);
+        d.health()
+    });
+    assert_eq!(remaining, Some(7));
+    assert_eq!(object.with_dyn(|d: &dyn Damageable| d.health()), Some(7));
+
+    // Class not registered for the trait.
+    let other: Gd<RefCounted> = Gd::<ObjPayload>::new_default().upcast();
+    assert_eq!(other.with_dyn(|d: &dyn Damageable| d.health()), None);
+}
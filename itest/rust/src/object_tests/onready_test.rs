@@ -0,0 +1,97 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::{expect_panic, itest, TestContext};
+
+use godot::bind::{godot_api, GodotClass};
+use godot::engine::{Node, NodeVirtual};
+use godot::obj::{Gd, OnReady, Share};
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct OnReadyWithReady {
+    #[init(node = "Child")]
+    child: OnReady<Gd<Node>>,
+
+    #[init(default = OnReady::new(|| 42))]
+    number: OnReady<i32>,
+
+    seen_in_ready: Option<(String, i32)>,
+}
+
+#[godot_api]
+impl NodeVirtual for OnReadyWithReady {
+    fn ready(&mut self) {
+        // Both fields must be initialized before ready() runs.
+        self.seen_in_ready = Some((self.child.get_name().to_string(), *self.number));
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct OnReadyWithoutReady {
+    #[init(default = OnReady::new(|| 7))]
+    value: OnReady<i32>,
+
+    #[init(default = OnReady::manual())]
+    manual: OnReady<i32>,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[itest]
+fn onready_initialized_before_ready(test_context: &TestContext) {
+    let obj = Gd::<OnReadyWithReady>::new_default();
+    assert!(!obj.bind().child.is_initialized());
+
+    let mut child = Node::new_alloc();
+    child.set_name("Child".into());
+    obj.share().upcast::<Node>().add_child(child.share());
+
+    let mut scene_tree = test_context.scene_tree.share();
+    scene_tree.add_child(obj.share().upcast());
+
+    {
+        let guard = obj.bind();
+        assert_eq!(guard.seen_in_ready, Some(("Child".to_string(), 42)));
+        assert_eq!(*guard.child, child);
+    }
+
+    obj.free();
+}
+
+#[itest]
+fn onready_without_ready_override(test_context: &TestContext) {
+    let mut obj = Gd::<OnReadyWithoutReady>::new_default();
+
+    let mut scene_tree = test_context.scene_tree.share();
+    scene_tree.add_child(obj.share().upcast());
+
+    {
+        let mut guard = obj.bind_mut();
+        assert_eq!(*guard.value, 7);
+
+        // Manual fields are left to the user.
+        assert!(!guard.manual.is_initialized());
+        guard.manual.init(5);
+        assert_eq!(*guard.manual, 5);
+    }
+
+    obj.free();
+}
+
+#[itest]
+fn onready_access_before_ready_panics() {
+    let obj = Gd::<OnReadyWithoutReady>::new_default();
+
+    // OnReady holds a boxed closure, which is not unwind-safe.
+    let access = std::panic::AssertUnwindSafe(|| {
+        let _value: i32 = *obj.bind().value;
+    });
+    expect_panic("OnReady access before ready", access);
+
+    obj.free();
+}
@@ -4,9 +4,14 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::HashMap;
+
 use godot::{
     bind::property::ExportInfo,
-    engine::{global::PropertyHint, Texture},
+    engine::{
+        global::{PropertyHint, PropertyUsageFlags},
+        Texture, Texture2D,
+    },
     prelude::*,
     test::itest,
 };
@@ -234,6 +239,9 @@ struct CheckAllExports {
     #[export(range = (0.0, 10.0, or_greater, or_less, exp, radians, hide_slider))]
     range_exported: f64,
 
+    #[export(range = (0.0, 10.0, 0.2, or_greater))]
+    range_exported_with_step: f64,
+
     #[export(enum = (A = 10, B, C, D = 20))]
     enum_exported: i64,
 
@@ -368,3 +376,503 @@ fn derive_export() {
         "A:0,B:1,C:2".to_variant()
     );
 }
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct HasOptionalObjects {
+    #[export]
+    optional_resource: Option<Gd<Resource>>,
+
+    #[export]
+    optional_node: Option<Gd<Node>>,
+}
+
+#[godot_api]
+impl HasOptionalObjects {}
+
+#[itest]
+fn export_optional_object() {
+    let mut obj: Gd<HasOptionalObjects> = Gd::new_default();
+
+    let resource_property = find_property(&obj, "optional_resource").unwrap();
+    assert_eq!(
+        resource_property.get_or_nil("type"),
+        (VariantType::Object as i32).to_variant()
+    );
+    assert_eq!(
+        resource_property.get_or_nil("hint"),
+        PropertyHint::PROPERTY_HINT_RESOURCE_TYPE.ord().to_variant()
+    );
+    assert_eq!(
+        resource_property.get_or_nil("hint_string"),
+        "Resource".to_variant()
+    );
+
+    let node_property = find_property(&obj, "optional_node").unwrap();
+    assert_eq!(
+        node_property.get_or_nil("hint"),
+        PropertyHint::PROPERTY_HINT_NODE_TYPE.ord().to_variant()
+    );
+
+    // Round-trip: null -> object -> null.
+    assert!(obj.get("optional_resource".into()).is_nil());
+
+    let resource = Resource::new();
+    obj.set("optional_resource".into(), resource.to_variant());
+    assert_eq!(obj.bind().optional_resource, Some(resource.share()));
+    assert_eq!(obj.get("optional_resource".into()), resource.to_variant());
+
+    obj.set("optional_resource".into(), Variant::nil());
+    assert_eq!(obj.bind().optional_resource, None);
+    assert!(obj.get("optional_resource".into()).is_nil());
+
+    obj.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct HasGroupedExports {
+    #[export]
+    ungrouped: i64,
+
+    #[export(group = "Movement")]
+    speed: f64,
+
+    #[export(subgroup = "Jumping", range = (0.0, 10.0))]
+    jump_height: f64,
+}
+
+#[godot_api]
+impl HasGroupedExports {}
+
+#[itest]
+fn export_group_order() {
+    let obj: Gd<HasGroupedExports> = Gd::new_default();
+
+    let names: Vec<String> = obj
+        .get_property_list()
+        .iter_shared()
+        .map(|property| property.get_or_nil("name").to::<GodotString>().to_string())
+        .collect();
+
+    let position = |name: &str| {
+        names
+            .iter()
+            .position(|n| n == name)
+            .unwrap_or_else(|| panic!("property `{name}` not found"))
+    };
+
+    // Group markers are registered right before their first member.
+    assert_eq!(position("Movement") + 1, position("speed"));
+    assert_eq!(position("Jumping") + 1, position("jump_height"));
+    assert!(position("ungrouped") < position("Movement"));
+
+    let group = find_property(&obj, "Movement").unwrap();
+    assert_eq!(
+        group.get_or_nil("usage"),
+        PropertyUsageFlags::PROPERTY_USAGE_GROUP.ord().to_variant()
+    );
+
+    obj.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct HasTypedResourceArray {
+    #[export]
+    textures: Array<Gd<Texture2D>>,
+}
+
+#[godot_api]
+impl HasTypedResourceArray {}
+
+#[itest]
+fn export_typed_resource_array() {
+    let mut obj: Gd<HasTypedResourceArray> = Gd::new_default();
+
+    let property = find_property(&obj, "textures").unwrap();
+
+    assert_eq!(
+        property.get_or_nil("type"),
+        (VariantType::Array as i32).to_variant()
+    );
+    assert_eq!(
+        property.get_or_nil("hint"),
+        PropertyHint::PROPERTY_HINT_TYPE_STRING.ord().to_variant()
+    );
+
+    // Format "<element type>/<element hint>:<element hint string>", which makes the editor only accept Texture2D elements.
+    let expected_hint_string = format!(
+        "{}/{}:Texture2D",
+        VariantType::Object as i32,
+        PropertyHint::PROPERTY_HINT_RESOURCE_TYPE.ord()
+    );
+    assert_eq!(
+        property.get_or_nil("hint_string"),
+        expected_hint_string.to_variant()
+    );
+
+    // Round-trip keeps the array typed.
+    let textures = Array::<Gd<Texture2D>>::new();
+    obj.set("textures".into(), textures.to_variant());
+
+    let roundtrip = obj.get("textures".into());
+    assert_eq!(roundtrip.try_to::<Array<Gd<Texture2D>>>(), Ok(textures));
+    assert!(roundtrip.try_to::<Array<Gd<Texture>>>().is_err());
+
+    obj.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct HasColorExports {
+    #[export(color_no_alpha)]
+    #[init(default = Color::from_rgb(1.0, 0.5, 0.0))]
+    tint: Color,
+
+    #[export(color_no_alpha)]
+    palette: PackedColorArray,
+}
+
+#[godot_api]
+impl HasColorExports {}
+
+#[itest]
+fn export_color_no_alpha() {
+    let mut obj: Gd<HasColorExports> = Gd::new_default();
+    let tint = find_property(&obj, "tint").unwrap();
+    assert_eq!(
+        tint.get_or_nil("hint"),
+        PropertyHint::PROPERTY_HINT_COLOR_NO_ALPHA
+            .ord()
+            .to_variant()
+    );
+
+    // Element hint applies to each color in the array.
+    let palette = find_property(&obj, "palette").unwrap();
+    assert_eq!(
+        palette.get_or_nil("hint"),
+        PropertyHint::PROPERTY_HINT_TYPE_STRING.ord().to_variant()
+    );
+    let expected_hint_string = format!(
+        "{}/{}:",
+        VariantType::Color as i32,
+        PropertyHint::PROPERTY_HINT_COLOR_NO_ALPHA.ord()
+    );
+    assert_eq!(
+        palette.get_or_nil("hint_string"),
+        expected_hint_string.to_variant()
+    );
+
+    // The hint does not interfere with the default value.
+    obj.bind_mut().tint = Color::from_rgb(0.0, 0.0, 1.0);
+    assert!(obj.property_can_revert("tint".into()));
+    assert_eq!(
+        obj.property_get_revert("tint".into()),
+        Color::from_rgb(1.0, 0.5, 0.0).to_variant()
+    );
+
+    obj.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct HasExportDefaults {
+    #[export]
+    #[init(default = 12.5)]
+    speed: f64,
+
+    #[export]
+    name: GodotString,
+
+    #[var]
+    #[init(default = 3)]
+    internal: i32,
+}
+
+#[godot_api]
+impl HasExportDefaults {}
+
+#[derive(GodotClass)]
+#[class(base=Node)]
+struct HasExportCustomInit {
+    #[export]
+    speed: f64,
+
+    #[base]
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl HasExportCustomInit {}
+
+#[godot_api]
+impl NodeVirtual for HasExportCustomInit {
+    fn init(base: Base<Node>) -> Self {
+        Self { speed: 12.5, base }
+    }
+}
+
+#[itest]
+fn export_default_revert() {
+    let mut obj: Gd<HasExportDefaults> = Gd::new_default();
+    obj.bind_mut().speed = 99.0;
+
+    assert!(obj.property_can_revert("speed".into()));
+    assert_eq!(obj.property_get_revert("speed".into()), 12.5.to_variant());
+    assert_eq!(
+        obj.property_get_revert("name".into()),
+        GodotString::new().to_variant()
+    );
+
+    // Only exported properties are shown in the inspector, and can thus be reverted.
+    assert!(!obj.property_can_revert("internal".into()));
+    assert!(!obj.property_can_revert("no_such_property".into()));
+
+    obj.free();
+
+    // A hand-written init is opaque, so no defaults are known.
+    let obj: Gd<HasExportCustomInit> = Gd::new_default();
+    assert!(!obj.property_can_revert("speed".into()));
+
+    obj.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct HasExportedMap {
+    #[export]
+    weights: HashMap<String, i64>,
+}
+
+#[godot_api]
+impl HasExportedMap {}
+
+#[itest]
+fn export_hash_map() {
+    let mut obj: Gd<HasExportedMap> = Gd::new_default();
+    obj.bind_mut().weights.insert("sword".to_string(), 3);
+
+    let property = find_property(&obj, "weights").unwrap();
+    assert_eq!(
+        property.get_or_nil("type"),
+        (VariantType::Dictionary as i32).to_variant()
+    );
+
+    let dict = obj.get("weights".into()).to::<Dictionary>();
+    assert_eq!(dict.len(), 1);
+    assert_eq!(dict.get("sword"), Some(3.to_variant()));
+
+    obj.set("weights".into(), dict! {"bow": 5, "axe": 2}.to_variant());
+    let expected = HashMap::from([("bow".to_string(), 5), ("axe".to_string(), 2)]);
+    assert_eq!(obj.bind().weights, expected);
+
+    obj.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Resource)]
+struct HasSerializedState {
+    #[export]
+    level: i64,
+
+    #[serialize]
+    seed: i64,
+
+    #[serialize(set = set_label)]
+    label: GodotString,
+
+    transient: i64,
+}
+
+#[godot_api]
+impl HasSerializedState {
+    #[func]
+    fn set_label(&mut self, label: GodotString) {
+        self.label = label.to_lower();
+    }
+}
+
+#[itest]
+fn serialize_storage_only() {
+    let obj: Gd<HasSerializedState> = Gd::new_default();
+
+    let usage_of = |name: &str| {
+        find_property(&obj, name).map(|property| property.get_or_nil("usage").to::<u32>())
+    };
+
+    assert_eq!(
+        usage_of("seed"),
+        Some(PropertyUsageFlags::PROPERTY_USAGE_STORAGE.ord() as u32)
+    );
+    assert_eq!(
+        usage_of("label"),
+        Some(PropertyUsageFlags::PROPERTY_USAGE_STORAGE.ord() as u32)
+    );
+    assert_eq!(usage_of("transient"), None);
+}
+
+#[itest]
+fn serialize_roundtrip_through_duplicate() {
+    let mut obj: Gd<HasSerializedState> = Gd::new_default();
+    {
+        let mut state = obj.bind_mut();
+        state.level = 3;
+        state.seed = 12345;
+        state.transient = 7;
+    }
+    obj.set("label".into(), "OLD Format".to_variant());
+
+    // duplicate() copies exactly the properties that are saved to resource files.
+    let copy = obj.duplicate_with_flags(false);
+    let copy = copy.bind();
+    assert_eq!(copy.level, 3);
+    assert_eq!(copy.seed, 12345);
+    assert_eq!(copy.label, GodotString::from("old format"));
+    assert_eq!(copy.transient, 0);
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct HasRangeExports {
+    #[export(range = (0.0, 100.0, 0.5, or_greater, exp))]
+    with_step: f64,
+
+    #[export(range = (-1.0, 1.0, hide_slider))]
+    without_step: f64,
+}
+
+#[godot_api]
+impl HasRangeExports {}
+
+#[itest]
+fn export_range_hint_string() {
+    let obj: Gd<HasRangeExports> = Gd::new_default();
+
+    let hint_string_of = |name: &str| {
+        find_property(&obj, name)
+            .unwrap()
+            .get_or_nil("hint_string")
+            .to::<GodotString>()
+    };
+
+    assert_eq!(
+        hint_string_of("with_step"),
+        "0,100,0.5,or_greater,exp".into()
+    );
+    assert_eq!(hint_string_of("without_step"), "-1,1,hide_slider".into());
+
+    obj.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct HasExportUsage {
+    #[export(usage = STORAGE | EDITOR | READ_ONLY)]
+    read_only: i64,
+
+    #[export(usage = PROPERTY_USAGE_STORAGE)]
+    storage_only: i64,
+
+    #[export]
+    inferred: i64,
+}
+
+#[godot_api]
+impl HasExportUsage {}
+
+#[itest]
+fn export_usage_bitmask() {
+    let obj: Gd<HasExportUsage> = Gd::new_default();
+
+    let usage_of = |name: &str| {
+        find_property(&obj, name)
+            .unwrap()
+            .get_or_nil("usage")
+            .to::<u32>()
+    };
+
+    let expected = PropertyUsageFlags::PROPERTY_USAGE_STORAGE
+        | PropertyUsageFlags::PROPERTY_USAGE_EDITOR
+        | PropertyUsageFlags::PROPERTY_USAGE_READ_ONLY;
+    assert_eq!(usage_of("read_only"), expected.ord() as u32);
+    assert_eq!(
+        usage_of("storage_only"),
+        PropertyUsageFlags::PROPERTY_USAGE_STORAGE.ord() as u32
+    );
+    assert_eq!(
+        usage_of("inferred"),
+        PropertyUsageFlags::PROPERTY_USAGE_DEFAULT.ord() as u32
+    );
+
+    obj.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct HasExportAccessors {
+    #[export(get = get_health, set = set_health)]
+    health: i64,
+
+    #[export(get = get_score)]
+    score: i64,
+}
+
+#[godot_api]
+impl HasExportAccessors {
+    #[func]
+    fn get_health(&self) -> i64 {
+        self.health
+    }
+
+    #[func]
+    fn set_health(&mut self, health: i64) {
+        self.health = health.clamp(0, 100);
+    }
+
+    #[func]
+    fn get_score(&self) -> i64 {
+        self.score * 10
+    }
+}
+
+#[itest]
+fn export_custom_accessors() {
+    let mut obj: Gd<HasExportAccessors> = Gd::new_default();
+
+    obj.set("health".into(), 150.to_variant());
+    assert_eq!(obj.bind().health, 100);
+    assert_eq!(obj.get("health".into()), 100.to_variant());
+
+    // Get-only: the value is computed, and setting it has no effect.
+    obj.bind_mut().score = 4;
+    assert_eq!(obj.get("score".into()), 40.to_variant());
+    obj.set("score".into(), 7.to_variant());
+    assert_eq!(obj.bind().score, 4);
+
+    let usage = find_property(&obj, "health")
+        .unwrap()
+        .get_or_nil("usage")
+        .to::<u32>();
+    assert_eq!(
+        usage,
+        PropertyUsageFlags::PROPERTY_USAGE_DEFAULT.ord() as u32
+    );
+
+    obj.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers
+
+/// Returns the entry for the property `name` in the property list of `obj`.
+fn find_property<T>(obj: &Gd<T>, name: &str) -> Option<Dictionary>
+where
+    T: GodotClass + Inherits<Object>,
+{
+    obj.share()
+        .upcast::<Object>()
+        .get_property_list()
+        .iter_shared()
+        .find(|property| property.get_or_nil("name") == name.to_variant())
+}
@@ -5,8 +5,9 @@
  */
 
 use crate::framework::itest;
+use godot::bind::{godot_api, GodotClass};
 use godot::builtin::GodotString;
-use godot::engine::{Input, Os};
+use godot::engine::{Engine, EngineExt, Input, Os};
 use godot::obj::Gd;
 
 #[itest]
@@ -42,3 +43,48 @@ fn singleton_is_operational() {
     let read_value = os.get_environment(key);
     assert_eq!(read_value, value);
 }
+
+#[itest]
+fn singleton_get_typed() {
+    let engine = Engine::singleton();
+
+    let os = engine.get_singleton_typed::<Os>("OS");
+    assert_eq!(os, Some(Os::singleton()));
+
+    let wrong_type = engine.get_singleton_typed::<Input>("OS");
+    assert!(wrong_type.is_none());
+
+    let missing = engine.get_singleton_typed::<Os>("NonExistentSingleton");
+    assert!(missing.is_none());
+}
+
+#[derive(GodotClass)]
+#[class(init, singleton, base=Object)]
+struct RustSingleton {
+    #[init(default = 123)]
+    value: i64,
+}
+
+#[godot_api]
+impl RustSingleton {}
+
+#[itest]
+fn singleton_user_class_registered() {
+    let engine = Engine::singleton();
+    assert!(engine.has_singleton("RustSingleton".into()));
+
+    let mut singleton = engine
+        .get_singleton_typed::<RustSingleton>("RustSingleton")
+        .expect("#[class(singleton)] registered");
+    assert_eq!(singleton.bind().value, 123);
+
+    // Same instance on every access.
+    singleton.bind_mut().value = 456;
+    let again = engine
+        .get_singleton_typed::<RustSingleton>("RustSingleton")
+        .unwrap();
+    assert_eq!(again.bind().value, 456);
+    assert_eq!(again.instance_id(), singleton.instance_id());
+
+    singleton.bind_mut().value = 123;
+}
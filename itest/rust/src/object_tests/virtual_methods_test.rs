@@ -457,6 +457,25 @@ fn test_notifications() {
     obj.free();
 }
 
+#[itest]
+fn test_notifications_unknown() {
+    let obj = Gd::<NotificationTest>::new_default();
+    let mut node = obj.share().upcast::<Node>();
+
+    // Constants unknown to Godot are passed through as-is.
+    node.notify(NodeNotification::Unknown(12345));
+    node.notify_reversed(NodeNotification::from(-7));
+
+    assert_eq!(
+        obj.bind().sequence,
+        vec![
+            ReceivedEvent::Notification(NodeNotification::Unknown(12345)),
+            ReceivedEvent::Notification(NodeNotification::Unknown(-7)),
+        ]
+    );
+    obj.free();
+}
+
 // Used in `test_collision_object_2d_input_event` in `SpecialTests.gd`.
 #[derive(GodotClass)]
 #[class(init, base = RigidBody2D)]
@@ -0,0 +1,69 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::builtin::meta::ClassName;
+use godot::engine::{ClassDb, RefCounted};
+use godot::init::{
+    register_class_dynamic, unregister_class_dynamic, ClassRegistrationError,
+    DynamicClassDescriptor,
+};
+use godot::prelude::*;
+
+#[itest]
+fn dynamic_class_register_unregister() {
+    let base = ClassName::new_dynamic("DynamicModBase");
+    let child = ClassName::new_dynamic("DynamicModChild");
+
+    register_class_dynamic(DynamicClassDescriptor::new(base, RefCounted::class_name()))
+        .expect("register base");
+    register_class_dynamic(DynamicClassDescriptor::new(child, base)).expect("register child");
+
+    let class_db = ClassDb::singleton();
+    assert!(class_db.class_exists("DynamicModChild".into()));
+    assert_eq!(
+        class_db.get_parent_class("DynamicModChild".into()),
+        "DynamicModBase".into()
+    );
+
+    // Not instantiable without create function.
+    assert!(!class_db.can_instantiate("DynamicModBase".into()));
+
+    // Name collisions, both with dynamic and engine classes.
+    let err = register_class_dynamic(DynamicClassDescriptor::new(child, base));
+    assert_eq!(err, Err(ClassRegistrationError::NameCollision(child)));
+    let err = register_class_dynamic(DynamicClassDescriptor::new(
+        Node::class_name(),
+        RefCounted::class_name(),
+    ));
+    assert_eq!(
+        err,
+        Err(ClassRegistrationError::NameCollision(Node::class_name()))
+    );
+
+    // Base class must be unregistered last.
+    let err = unregister_class_dynamic(base);
+    assert_eq!(err, Err(ClassRegistrationError::HasSubclasses(base)));
+
+    unregister_class_dynamic(child).expect("unregister child");
+    unregister_class_dynamic(base).expect("unregister base");
+    assert!(!class_db.class_exists("DynamicModBase".into()));
+
+    let err = unregister_class_dynamic(base);
+    assert_eq!(err, Err(ClassRegistrationError::NotDynamic(base)));
+}
+
+#[itest]
+fn dynamic_class_unknown_base() {
+    let class = ClassName::new_dynamic("DynamicModOrphan");
+    let missing_base = ClassName::new_dynamic("DynamicModMissingBase");
+
+    let err = register_class_dynamic(DynamicClassDescriptor::new(class, missing_base));
+    assert_eq!(
+        err,
+        Err(ClassRegistrationError::UnknownBaseClass(missing_base))
+    );
+}
@@ -4,8 +4,13 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use godot::builtin::meta::registration::rpc::{RpcConfig, RpcMode, TransferMode};
+use godot::engine::global;
+use godot::obj::ScriptIterable;
 use godot::prelude::*;
 
+use crate::framework::{itest, TestContext};
+
 #[derive(GodotClass)]
 #[class(base=RefCounted)]
 struct FuncRename;
@@ -46,3 +51,273 @@ impl RefCountedVirtual for FuncRename {
         Self
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct GdSelfReference {
+    internal_value: i32,
+}
+
+#[godot_api]
+impl GdSelfReference {
+    #[func(gd_self)]
+    fn update_internal(mut this: Gd<Self>, new_value: i32) -> i32 {
+        this.bind_mut().internal_value = new_value;
+        this.bind().internal_value
+    }
+
+    #[func(gd_self)]
+    fn is_same(this: Gd<Self>, other: Gd<Self>) -> bool {
+        this == other
+    }
+
+    /// Calls back into this object through Godot; possible because the instance is not bound during a `gd_self` call.
+    #[func(gd_self)]
+    fn get_internal_via_godot(mut this: Gd<Self>) -> i32 {
+        this.call("get_internal".into(), &[]).to::<i32>()
+    }
+
+    #[func]
+    fn get_internal(&self) -> i32 {
+        self.internal_value
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncObjectParams;
+
+#[godot_api]
+impl FuncObjectParams {
+    /// Modifies the passed object; visible to the caller, since objects are passed as references.
+    #[func]
+    fn rename_node(&self, mut node: Gd<Node>, name: GodotString) {
+        node.set_name(name);
+    }
+
+    /// Stands in for an out-parameter: the replacement object is returned instead.
+    #[func]
+    fn replace_node(&self, node: Gd<Node>) -> Gd<Node> {
+        let mut replacement = Node::new_alloc();
+        replacement.set_name(GodotString::from(&node.get_name()));
+        replacement
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Base class whose `_describe()` is overridden by a GDScript subclass in the GDScript tests.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncVirtualBase {
+    #[init(default = 7)]
+    level: i64,
+}
+
+#[godot_api]
+impl FuncVirtualBase {
+    #[func(virtual)]
+    fn describe(&self, prefix: GodotString) -> GodotString {
+        format!("{prefix}rust {}", self.level).into()
+    }
+
+    #[func(virtual)]
+    fn reset(&mut self) {
+        self.level = 0;
+    }
+
+    #[func(gd_self)]
+    fn describe_dispatched(this: Gd<Self>, prefix: GodotString) -> GodotString {
+        Self::call_describe(&this, prefix)
+    }
+
+    #[func(gd_self)]
+    fn reset_dispatched(this: Gd<Self>) {
+        Self::call_reset(&this);
+    }
+
+    #[func]
+    fn get_level(&self) -> i64 {
+        self.level
+    }
+}
+
+#[itest]
+fn func_virtual_without_override() {
+    let obj = Gd::<FuncVirtualBase>::new_default();
+
+    let object = obj.share().upcast::<Object>();
+    assert!(object.has_method("_describe".into()));
+    assert!(!object.has_method("describe".into()));
+
+    // No script attached: dispatch falls back to the Rust function.
+    let description = FuncVirtualBase::call_describe(&obj, "level: ".into());
+    assert_eq!(description, GodotString::from("level: rust 7"));
+
+    FuncVirtualBase::call_reset(&obj);
+    assert_eq!(obj.bind().level, 0);
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncIterable {
+    items: Vec<i64>,
+}
+
+#[godot_api]
+impl FuncIterable {
+    #[func]
+    fn push(&mut self, value: i64) {
+        self.items.push(value);
+    }
+}
+
+#[godot_api]
+impl ScriptIterable for FuncIterable {
+    type Item = i64;
+
+    fn item_at(&self, index: usize) -> Option<i64> {
+        self.items.get(index).copied()
+    }
+}
+
+#[itest]
+fn func_script_iterable() {
+    let mut obj = Gd::<FuncIterable>::new_default();
+    obj.bind_mut().items = vec![3, 1];
+
+    let mut object = obj.upcast::<Object>();
+    assert!(object.has_method("_iter_init".into()));
+    assert!(object.has_method("_iter_next".into()));
+    assert!(object.has_method("_iter_get".into()));
+
+    // Drive the protocol like GDScript's `for` loop.
+    let iter = varray![Variant::nil()];
+    let mut items = Vec::new();
+    let mut valid = object.call("_iter_init".into(), &[iter.to_variant()]);
+    while valid.to::<bool>() {
+        items.push(object.call("_iter_get".into(), &[iter.get(0)]).to::<i64>());
+        valid = object.call("_iter_next".into(), &[iter.to_variant()]);
+    }
+    assert_eq!(items, vec![3, 1]);
+
+    // Empty collections end immediately.
+    let mut empty = Gd::<FuncIterable>::new_default().upcast::<Object>();
+    let valid = empty.call("_iter_init".into(), &[varray![Variant::nil()].to_variant()]);
+    assert!(!valid.to::<bool>());
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncDefaults;
+
+#[godot_api]
+impl FuncDefaults {
+    #[func]
+    fn sum(&self, a: i64, #[default(5)] b: i64, #[default(100)] c: i64) -> i64 {
+        a + b + c
+    }
+
+    #[func]
+    fn label(prefix: GodotString, #[default("none")] name: GodotString) -> GodotString {
+        format!("{prefix}{name}").into()
+    }
+}
+
+#[itest]
+fn func_default_params() {
+    let mut object = Gd::<FuncDefaults>::new_default().upcast::<Object>();
+
+    let sum =
+        |object: &mut Gd<Object>, args: &[Variant]| object.call("sum".into(), args).to::<i64>();
+    assert_eq!(sum(&mut object, &[1.to_variant()]), 106);
+    assert_eq!(sum(&mut object, &[1.to_variant(), 2.to_variant()]), 103);
+    assert_eq!(
+        sum(
+            &mut object,
+            &[1.to_variant(), 2.to_variant(), 3.to_variant()]
+        ),
+        6
+    );
+
+    let label = object.call("label".into(), &["id: ".to_variant()]);
+    assert_eq!(label, "id: none".to_variant());
+
+    // Defaults are visible to Godot's method metadata.
+    let method = object
+        .get_method_list()
+        .iter_shared()
+        .find(|method| method.get_or_nil("name") == "sum".to_variant())
+        .expect("method `sum` is registered");
+    assert_eq!(
+        method.get_or_nil("default_args"),
+        varray![5, 100].to_variant()
+    );
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct FuncRpc {
+    received: Vec<i64>,
+}
+
+#[godot_api]
+impl FuncRpc {
+    #[func]
+    #[rpc(any_peer, reliable, call_local, channel = 2)]
+    fn receive(&mut self, value: i64) {
+        self.received.push(value);
+    }
+
+    #[func]
+    #[rpc]
+    fn receive_remote(&mut self, value: i64) {
+        self.received.push(value);
+    }
+
+    #[func]
+    fn not_rpc(&mut self) {}
+}
+
+#[itest]
+fn func_rpc(ctx: &TestContext) {
+    let obj = Gd::<FuncRpc>::new_default();
+    let mut root = ctx.scene_tree.share();
+    root.add_child(obj.share().upcast());
+
+    // Without a connection, the scene tree's offline multiplayer peer only runs the calls that are `call_local`.
+    assert_eq!(FuncRpc::rpc_receive(&obj, 7), global::Error::OK);
+    FuncRpc::rpc_receive_remote(&obj, 8);
+    assert_eq!(obj.bind().received, vec![7]);
+
+    // Methods without #[rpc] have no configuration, so Godot refuses to call them remotely.
+    let mut node = obj.upcast::<Node>();
+    assert_ne!(node.rpc("not_rpc".into(), &[]), global::Error::OK);
+
+    root.remove_child(node.share());
+    node.free();
+}
+
+#[itest]
+fn func_rpc_config_dictionary() {
+    let config = RpcConfig {
+        rpc_mode: RpcMode::AnyPeer,
+        transfer_mode: TransferMode::UnreliableOrdered,
+        call_local: true,
+        channel: 3,
+    };
+
+    let expected = dict! {
+        "rpc_mode": 1,
+        "transfer_mode": 1,
+        "call_local": true,
+        "channel": 3,
+    };
+    assert_eq!(config.to_dictionary(), expected);
+}
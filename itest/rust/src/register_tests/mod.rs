@@ -6,6 +6,7 @@
 
 mod constant_test;
 mod derive_variant;
+mod dynamic_class_test;
 mod func_test;
 mod gdscript_ffi_test;
 mod option_ffi_test;